@@ -0,0 +1,243 @@
+/*
+    appellation: acyclicity <module>
+    authors: @FL03
+*/
+//! this module implements the alpha-, beta-, gamma- and Berge-acyclicity tests for hypergraphs
+use super::JoinTree;
+use crate::error::{Error, Result};
+use crate::utils::{EdgeSet, collect_edges};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
+use rshyper::idx::{HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIterEdge, RawLayout};
+
+/// The [`Acyclicity`] operator determines the degree to which a hypergraph is acyclic. Unlike
+/// graphs, hypergraphs admit several, increasingly restrictive, notions of acyclicity:
+///
+/// - [alpha](Acyclicity::is_alpha_acyclic): the hypergraph may be reduced to nothing using
+///   the GYO reduction; equivalently, the hypergraph admits a [`JoinTree`].
+/// - [beta](Acyclicity::is_beta_acyclic): every subset of the edges is alpha-acyclic.
+/// - [gamma](Acyclicity::is_gamma_acyclic): the hypergraph is beta-acyclic and contains no
+///   gamma-cycles, i.e. it may be reduced to nothing using Fagin's reduction.
+/// - [Berge](Acyclicity::is_berge_acyclic): the bipartite incidence graph of the hypergraph
+///   is a forest.
+///
+/// Each of the notions implies those listed before it, such that a Berge-acyclic hypergraph
+/// is also gamma-, beta- and alpha-acyclic. Vertices contained by no edges are ignored.
+pub struct Acyclicity<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIterEdge<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> Acyclicity<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIterEdge<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns true if the hypergraph is alpha-acyclic
+    pub fn is_alpha_acyclic(&self) -> bool {
+        gyo_reduction(collect_edges(self.graph)).is_ok()
+    }
+    /// returns true if the hypergraph is beta-acyclic, determined by repeatedly removing
+    /// _nest points_, i.e. vertices whose edges form a chain under inclusion.
+    pub fn is_beta_acyclic(&self) -> bool {
+        let mut edges = collect_edges(self.graph)
+            .into_iter()
+            .map(|(_, domain)| domain)
+            .collect::<Vec<_>>();
+        let mut vertices = edges.iter().flatten().copied().collect::<Vec<_>>();
+        vertices.sort();
+        vertices.dedup();
+        while !vertices.is_empty() {
+            let Some(pos) = vertices.iter().position(|v| is_nest_point(&edges, v)) else {
+                return false;
+            };
+            let v = vertices.remove(pos);
+            edges.iter_mut().for_each(|e| {
+                e.remove(&v);
+            });
+        }
+        true
+    }
+    /// returns true if the hypergraph is gamma-acyclic, determined using Fagin's reduction
+    pub fn is_gamma_acyclic(&self) -> bool {
+        let mut edges = collect_edges(self.graph)
+            .into_iter()
+            .map(|(_, domain)| domain)
+            .collect::<Vec<_>>();
+        while fagin_step(&mut edges) {}
+        edges.is_empty()
+    }
+    /// returns true if the hypergraph is Berge-acyclic, i.e. its incidence graph is a forest
+    pub fn is_berge_acyclic(&self) -> bool {
+        let edges = collect_edges(self.graph);
+        // the components of the incidence graph, identified by a representative vertex
+        let mut parent = HashMap::<VertexId<A::Ix>, VertexId<A::Ix>>::new();
+        for (_, domain) in &edges {
+            let mut members = domain.iter();
+            let Some(first) = members.next() else {
+                continue;
+            };
+            let root = find(&mut parent, *first);
+            for v in members {
+                // joining two vertices that are already connected closes a cycle
+                let other = find(&mut parent, *v);
+                if other == root {
+                    return false;
+                }
+                parent.insert(other, root);
+            }
+        }
+        true
+    }
+    /// returns the [`JoinTree`] of the hypergraph, constructed from the GYO reduction; a
+    /// [`NotAcyclic`](Error::NotAcyclic) error is returned if the hypergraph is not
+    /// alpha-acyclic.
+    pub fn join_tree(&self) -> Result<JoinTree<A::Ix>> {
+        gyo_reduction(collect_edges(self.graph))
+    }
+}
+
+/// performs the GYO (Graham-Yu-Ozsoyoglu) reduction, alternately removing the vertices
+/// contained by a single edge and the edges contained by another, linking each of the latter
+/// to the edge containing it.
+pub(crate) fn gyo_reduction<Ix>(mut edges: Vec<EdgeSet<Ix>>) -> Result<JoinTree<Ix>>
+where
+    Ix: HyperIndex,
+{
+    let mut tree = JoinTree::new();
+    loop {
+        // remove the vertices contained by a single edge
+        let mut counts = HashMap::<VertexId<Ix>, usize>::new();
+        for v in edges.iter().flat_map(|(_, domain)| domain) {
+            *counts.entry(*v).or_default() += 1;
+        }
+        edges
+            .iter_mut()
+            .for_each(|(_, domain)| domain.retain(|v| counts[v] > 1));
+        // remove an edge contained by another, if any
+        let Some((i, j)) = find_ear(&edges) else {
+            break;
+        };
+        let (ear, _) = edges.remove(i);
+        let (parent, _) = edges[if j > i { j - 1 } else { j }];
+        tree.parents.insert(ear, parent);
+        tree.order.push(ear);
+    }
+    match edges.as_slice() {
+        [] => Ok(tree),
+        [(root, _)] => {
+            tree.root = Some(*root);
+            tree.order.push(*root);
+            Ok(tree)
+        }
+        _ => Err(Error::NotAcyclic),
+    }
+}
+
+/// returns the positions of the first edge contained by another alongside the position of the
+/// latter
+fn find_ear<Ix>(edges: &[EdgeSet<Ix>]) -> Option<(usize, usize)>
+where
+    Ix: HyperIndex,
+{
+    edges.iter().enumerate().find_map(|(i, (_, a))| {
+        edges
+            .iter()
+            .enumerate()
+            .position(|(j, (_, b))| i != j && a.is_subset(b))
+            .map(|j| (i, j))
+    })
+}
+
+/// returns true if the edges containing the given vertex form a chain under inclusion
+fn is_nest_point<Ix>(edges: &[HashSet<VertexId<Ix>>], vertex: &VertexId<Ix>) -> bool
+where
+    Ix: HyperIndex,
+{
+    let mut chain = edges
+        .iter()
+        .filter(|e| e.contains(vertex))
+        .collect::<Vec<_>>();
+    chain.sort_by_key(|e| e.len());
+    chain.windows(2).all(|w| w[0].is_subset(w[1]))
+}
+
+/// applies a single step of Fagin's reduction, returning true if the edges were changed
+fn fagin_step<Ix>(edges: &mut Vec<HashSet<VertexId<Ix>>>) -> bool
+where
+    Ix: HyperIndex,
+{
+    // remove the edges containing, at most, a single vertex
+    let len = edges.len();
+    edges.retain(|e| e.len() > 1);
+    if edges.len() != len {
+        return true;
+    }
+    // remove any duplicate edges
+    if let Some(i) = (0..edges.len()).find(|&i| edges[..i].contains(&edges[i])) {
+        edges.remove(i);
+        return true;
+    }
+    // index the edges containing each vertex
+    let mut incidence = HashMap::<VertexId<Ix>, Vec<usize>>::new();
+    for (i, e) in edges.iter().enumerate() {
+        for v in e {
+            incidence.entry(*v).or_default().push(i);
+        }
+    }
+    let mut vertices = incidence.keys().copied().collect::<Vec<_>>();
+    vertices.sort();
+    // remove a vertex contained by a single edge
+    if let Some(v) = vertices.iter().find(|v| incidence[*v].len() == 1) {
+        edges[incidence[v][0]].remove(v);
+        return true;
+    }
+    // remove a vertex contained by exactly the same edges as another
+    let mut seen = HashSet::<&[usize]>::new();
+    if let Some(v) = vertices
+        .iter()
+        .find(|v| !seen.insert(incidence[*v].as_slice()))
+    {
+        edges.iter_mut().for_each(|e| {
+            e.remove(v);
+        });
+        return true;
+    }
+    false
+}
+
+/// returns the representative of the component containing the given vertex
+fn find<Ix>(parent: &mut HashMap<VertexId<Ix>, VertexId<Ix>>, vertex: VertexId<Ix>) -> VertexId<Ix>
+where
+    Ix: HyperIndex,
+{
+    let mut root = vertex;
+    while let Some(&next) = parent.get(&root) {
+        root = next;
+    }
+    // compress the path onto the representative
+    let mut current = vertex;
+    while current != root {
+        let next = parent.insert(current, root).unwrap_or(root);
+        current = next;
+    }
+    root
+}
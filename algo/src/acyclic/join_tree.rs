@@ -0,0 +1,87 @@
+/*
+    appellation: join_tree <module>
+    authors: @FL03
+*/
+//! this module implements the [`JoinTree`] of an alpha-acyclic hypergraph
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+use rshyper::idx::{EdgeId, RawIndex, Udx};
+
+/// A [`JoinTree`] arranges the edges of an alpha-acyclic hypergraph into a tree such that,
+/// for every vertex, the edges containing it form a connected subtree; this is also known as
+/// the _running intersection property_. Join trees are central to the evaluation of acyclic
+/// database queries and constraint satisfaction problems, where they enable the use of
+/// algorithms such as Yannakakis' algorithm.
+///
+/// The tree is rooted at the last edge remaining after the reduction of the hypergraph, with
+/// each of the remaining edges linked to its parent. Disconnected hypergraphs produce a single
+/// tree whose branches share no vertices.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct JoinTree<Ix = Udx>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// the root of the tree, if any
+    pub(crate) root: Option<EdgeId<Ix>>,
+    /// maps each edge, except for the root, onto its parent
+    pub(crate) parents: HashMap<EdgeId<Ix>, EdgeId<Ix>>,
+    /// the edges in the order they were removed, ending with the root
+    pub(crate) order: Vec<EdgeId<Ix>>,
+}
+
+impl<Ix> JoinTree<Ix>
+where
+    Ix: RawIndex + Copy + Eq + core::hash::Hash,
+{
+    /// returns a new, empty join tree
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            parents: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+    /// returns the root of the tree, if any
+    pub const fn root(&self) -> Option<&EdgeId<Ix>> {
+        self.root.as_ref()
+    }
+    /// returns an immutable reference to the map associating each edge with its parent
+    pub const fn parents(&self) -> &HashMap<EdgeId<Ix>, EdgeId<Ix>> {
+        &self.parents
+    }
+    /// returns the edges of the tree in a bottom-up order, where each edge precedes its
+    /// parent and the root comes last
+    pub fn order(&self) -> &[EdgeId<Ix>] {
+        &self.order
+    }
+    /// returns the parent of the given edge; [`None`] is returned for the root and any edge
+    /// missing from the tree
+    pub fn parent(&self, index: &EdgeId<Ix>) -> Option<&EdgeId<Ix>> {
+        self.parents.get(index)
+    }
+    /// returns the children of the given edge, in the order they were removed
+    pub fn children(&self, index: &EdgeId<Ix>) -> Vec<EdgeId<Ix>> {
+        self.order
+            .iter()
+            .filter(|e| self.parents.get(*e) == Some(index))
+            .copied()
+            .collect()
+    }
+    /// returns true if the tree contains the given edge
+    pub fn contains(&self, index: &EdgeId<Ix>) -> bool {
+        self.root.as_ref() == Some(index) || self.parents.contains_key(index)
+    }
+    /// returns true if the tree contains no edges
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+    /// returns the number of edges within the tree
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+}
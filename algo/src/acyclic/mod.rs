@@ -0,0 +1,17 @@
+/*
+    appellation: acyclic <module>
+    authors: @FL03
+*/
+//! this module implements tests for the various degrees of acyclicity of hypergraphs alongside
+//! the construction of join trees for those that are alpha-acyclic
+#[doc(inline)]
+pub use self::{acyclicity::*, join_tree::*};
+
+pub mod acyclicity;
+pub mod join_tree;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::acyclicity::Acyclicity;
+    pub use super::join_tree::JoinTree;
+}
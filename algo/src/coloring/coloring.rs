@@ -0,0 +1,188 @@
+/*
+    appellation: coloring <module>
+    authors: @FL03
+*/
+//! this module implements the [`Coloring`] operator, computing weak and strong vertex
+//! colorings of hypergraphs
+use super::VertexColoring;
+use crate::utils::{index_graph, primal_neighbors};
+use alloc::vec::Vec;
+use rshyper::idx::{HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIter, RawLayout};
+
+/// The [`Coloring`] operator computes vertex colorings of hypergraphs using either of two
+/// notions of a proper coloring:
+///
+/// - _weak_: no edge containing two or more vertices is monochromatic, i.e. every such edge
+///   contains at least two colors; edges containing a single vertex are ignored.
+/// - _strong_: the vertices of every edge are assigned distinct colors, which is equivalent
+///   to a proper coloring of the primal graph.
+///
+/// Each notion is served by two heuristics: a _greedy_ coloring visiting the vertices by
+/// decreasing degree (Welsh-Powell) and a _DSatur_ coloring, which repeatedly colors the
+/// vertex whose neighbors exhibit the most distinct colors. In either case, each vertex is
+/// assigned the smallest color respecting the constraints of its edges.
+pub struct Coloring<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> Coloring<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns a weak coloring of the hypergraph, visiting the vertices by decreasing degree
+    pub fn greedy_weak(&self) -> VertexColoring<A::Ix> {
+        self.greedy(false)
+    }
+    /// returns a strong coloring of the hypergraph, visiting the vertices by decreasing degree
+    pub fn greedy_strong(&self) -> VertexColoring<A::Ix> {
+        self.greedy(true)
+    }
+    /// returns a weak coloring of the hypergraph using the DSatur heuristic
+    pub fn dsatur_weak(&self) -> VertexColoring<A::Ix> {
+        self.dsatur(false)
+    }
+    /// returns a strong coloring of the hypergraph using the DSatur heuristic
+    pub fn dsatur_strong(&self) -> VertexColoring<A::Ix> {
+        self.dsatur(true)
+    }
+    /// returns true if the given coloring colors every vertex of the hypergraph such that no
+    /// edge containing two or more vertices is monochromatic
+    pub fn is_weak_coloring(&self, coloring: &VertexColoring<A::Ix>) -> bool {
+        let (vertices, edges) = index_graph(self.graph);
+        let Some(colors) = lookup(&vertices, coloring) else {
+            return false;
+        };
+        edges
+            .iter()
+            .filter(|e| e.len() > 1)
+            .all(|e| e.iter().any(|v| colors[*v] != colors[e[0]]))
+    }
+    /// returns true if the given coloring colors every vertex of the hypergraph such that the
+    /// vertices of every edge are assigned distinct colors
+    pub fn is_strong_coloring(&self, coloring: &VertexColoring<A::Ix>) -> bool {
+        let (vertices, edges) = index_graph(self.graph);
+        let Some(colors) = lookup(&vertices, coloring) else {
+            return false;
+        };
+        edges.iter().all(|e| {
+            let mut seen = e.iter().map(|v| colors[*v]).collect::<Vec<_>>();
+            seen.sort_unstable();
+            seen.windows(2).all(|w| w[0] != w[1])
+        })
+    }
+    /// colors the vertices by decreasing degree, breaking ties by index
+    fn greedy(&self, strong: bool) -> VertexColoring<A::Ix> {
+        let (vertices, edges) = index_graph(self.graph);
+        let incidence = incidence(vertices.len(), &edges);
+        let mut order = (0..vertices.len()).collect::<Vec<_>>();
+        order.sort_by_key(|v| core::cmp::Reverse(incidence[*v].len()));
+        let mut colors = alloc::vec![None; vertices.len()];
+        for v in order {
+            colors[v] = Some(smallest_color(v, &edges, &incidence, &colors, strong));
+        }
+        let colors = colors.into_iter().flatten().collect::<Vec<_>>();
+        VertexColoring::from_parts(&vertices, &colors)
+    }
+    /// colors the vertex whose neighbors exhibit the most distinct colors, breaking ties by
+    /// degree and then by index
+    fn dsatur(&self, strong: bool) -> VertexColoring<A::Ix> {
+        let (vertices, edges) = index_graph(self.graph);
+        let incidence = incidence(vertices.len(), &edges);
+        let neighbors = primal_neighbors(vertices.len(), &edges);
+        let mut colors = alloc::vec![None; vertices.len()];
+        // the distinct colors among the neighbors of each vertex
+        let mut saturation = alloc::vec![Vec::<usize>::new(); vertices.len()];
+        for _ in 0..vertices.len() {
+            let v = (0..vertices.len())
+                .filter(|v| colors[*v].is_none())
+                .max_by_key(|v| {
+                    (
+                        saturation[*v].len(),
+                        incidence[*v].len(),
+                        core::cmp::Reverse(*v),
+                    )
+                })
+                .expect("some vertex is yet to be colored");
+            let color = smallest_color(v, &edges, &incidence, &colors, strong);
+            colors[v] = Some(color);
+            for &u in &neighbors[v] {
+                if let Err(pos) = saturation[u].binary_search(&color) {
+                    saturation[u].insert(pos, color);
+                }
+            }
+        }
+        let colors = colors.into_iter().flatten().collect::<Vec<_>>();
+        VertexColoring::from_parts(&vertices, &colors)
+    }
+}
+
+/// returns the positions of the edges containing each of the vertices
+fn incidence(order: usize, edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut incidence = alloc::vec![Vec::new(); order];
+    for (i, edge) in edges.iter().enumerate() {
+        edge.iter().for_each(|v| incidence[*v].push(i));
+    }
+    incidence
+}
+
+/// returns the smallest color that may be assigned to the given vertex without violating the
+/// constraints of its edges
+fn smallest_color(
+    vertex: usize,
+    edges: &[Vec<usize>],
+    incidence: &[Vec<usize>],
+    colors: &[Option<usize>],
+    strong: bool,
+) -> usize {
+    let mut forbidden = Vec::new();
+    for edge in incidence[vertex].iter().map(|e| &edges[*e]) {
+        let others = edge.iter().filter(|u| **u != vertex).map(|u| colors[*u]);
+        if strong {
+            forbidden.extend(others.flatten());
+            continue;
+        }
+        // the edge would become monochromatic if its other vertices share a single color
+        let mut others = others.collect::<Option<Vec<_>>>().unwrap_or_default();
+        others.sort_unstable();
+        others.dedup();
+        if let [color] = others.as_slice() {
+            forbidden.push(*color);
+        }
+    }
+    forbidden.sort_unstable();
+    forbidden.dedup();
+    // the first gap within the forbidden colors
+    forbidden
+        .iter()
+        .enumerate()
+        .find(|(i, c)| *i != **c)
+        .map_or(forbidden.len(), |(i, _)| i)
+}
+
+/// returns the colors of the given vertices, or [`None`] if any of them is uncolored
+fn lookup<Ix>(vertices: &[VertexId<Ix>], coloring: &VertexColoring<Ix>) -> Option<Vec<usize>>
+where
+    Ix: HyperIndex,
+{
+    vertices.iter().map(|v| coloring.get(v)).collect()
+}
@@ -0,0 +1,197 @@
+/*
+    appellation: independent_sets <module>
+    authors: @FL03
+*/
+//! this module implements the [`IndependentSets`] operator, computing the maximal and maximum
+//! independent sets of hypergraphs
+use crate::transversal::transversals::minimum;
+use crate::utils::{index_graph, primal_neighbors};
+use alloc::vec::Vec;
+use rshyper::idx::{HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIter, RawLayout};
+
+/// The [`IndependentSets`] operator computes the independent sets of a hypergraph using either
+/// of two notions of independence:
+///
+/// - _weak_: the set contains no edge entirely, such that its complement is a transversal of
+///   the hypergraph; note that edges containing a single vertex exclude that vertex.
+/// - _strong_: the set contains, at most, a single vertex of every edge, which is equivalent
+///   to an independent set of the primal graph.
+///
+/// _Maximal_ sets, which may not be extended by any other vertex, are computed greedily by
+/// visiting the vertices by increasing degree, while _maximum_ sets, containing the most
+/// vertices possible, are found using a branch-and-bound search. Each set is returned as a
+/// list of vertices ordered by their index.
+pub struct IndependentSets<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> IndependentSets<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns a maximal weakly independent set of the hypergraph
+    pub fn maximal_weak(&self) -> Vec<VertexId<A::Ix>> {
+        let (vertices, edges) = index_graph(self.graph);
+        let set = maximal_weak(vertices.len(), &edges);
+        set.into_iter().map(|v| vertices[v]).collect()
+    }
+    /// returns a maximal strongly independent set of the hypergraph
+    pub fn maximal_strong(&self) -> Vec<VertexId<A::Ix>> {
+        let (vertices, edges) = index_graph(self.graph);
+        let neighbors = primal_neighbors(vertices.len(), &edges);
+        let set = maximal_strong(&neighbors);
+        set.into_iter().map(|v| vertices[v]).collect()
+    }
+    /// returns a maximum weakly independent set of the hypergraph, computed as the complement
+    /// of a minimum transversal; empty edges are ignored.
+    ///
+    /// **Note:** the problem is NP-hard, making the search exponential in the worst case.
+    pub fn maximum_weak(&self) -> Vec<VertexId<A::Ix>> {
+        let (vertices, mut edges) = index_graph(self.graph);
+        edges.retain(|e| !e.is_empty());
+        let transversal = minimum(vertices.len(), &edges).unwrap_or_default();
+        vertices
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| transversal.binary_search(i).is_err())
+            .map(|(_, v)| *v)
+            .collect()
+    }
+    /// returns a maximum strongly independent set of the hypergraph.
+    ///
+    /// **Note:** the problem is NP-hard, making the search exponential in the worst case.
+    pub fn maximum_strong(&self) -> Vec<VertexId<A::Ix>> {
+        let (vertices, edges) = index_graph(self.graph);
+        let neighbors = primal_neighbors(vertices.len(), &edges);
+        let mut best = maximal_strong(&neighbors);
+        let candidates = (0..vertices.len()).collect::<Vec<_>>();
+        branch(&neighbors, candidates, &mut Vec::new(), &mut best);
+        best.sort_unstable();
+        best.into_iter().map(|v| vertices[v]).collect()
+    }
+    /// returns true if the given vertices contain no edge of the hypergraph entirely
+    pub fn is_weakly_independent(&self, set: &[VertexId<A::Ix>]) -> bool {
+        let (vertices, edges) = index_graph(self.graph);
+        let Some(set) = positions(&vertices, set) else {
+            return false;
+        };
+        edges
+            .iter()
+            .all(|e| e.iter().any(|v| set.binary_search(v).is_err()))
+    }
+    /// returns true if the given vertices contain, at most, a single vertex of every edge
+    pub fn is_strongly_independent(&self, set: &[VertexId<A::Ix>]) -> bool {
+        let (vertices, edges) = index_graph(self.graph);
+        let Some(set) = positions(&vertices, set) else {
+            return false;
+        };
+        edges
+            .iter()
+            .all(|e| e.iter().filter(|v| set.binary_search(v).is_ok()).count() <= 1)
+    }
+}
+
+/// returns the vertices ordered by increasing degree, breaking ties by index
+fn by_degree(degrees: impl IntoIterator<Item = usize>) -> Vec<usize> {
+    let degrees = degrees.into_iter().collect::<Vec<_>>();
+    let mut order = (0..degrees.len()).collect::<Vec<_>>();
+    order.sort_by_key(|v| degrees[*v]);
+    order
+}
+
+/// greedily adds the vertices, by increasing degree, that do not complete any edge
+fn maximal_weak(order: usize, edges: &[Vec<usize>]) -> Vec<usize> {
+    let mut incidence = alloc::vec![Vec::new(); order];
+    for edge in edges {
+        edge.iter().for_each(|v| incidence[*v].push(edge));
+    }
+    let mut chosen = alloc::vec![false; order];
+    for v in by_degree(incidence.iter().map(Vec::len)) {
+        let completes = incidence[v]
+            .iter()
+            .any(|e| e.iter().all(|u| *u == v || chosen[*u]));
+        chosen[v] = !completes;
+    }
+    (0..order).filter(|v| chosen[*v]).collect()
+}
+
+/// greedily adds the vertices, by increasing degree, sharing no edge with a chosen vertex
+fn maximal_strong(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let mut chosen = alloc::vec![false; neighbors.len()];
+    for v in by_degree(neighbors.iter().map(Vec::len)) {
+        chosen[v] = neighbors[v].iter().all(|u| !chosen[*u]);
+    }
+    (0..neighbors.len()).filter(|v| chosen[*v]).collect()
+}
+
+/// extends the chosen vertices towards an independent set of the primal graph larger than
+/// the best found so far, branching on the candidate of largest remaining degree
+fn branch(
+    neighbors: &[Vec<usize>],
+    candidates: Vec<usize>,
+    chosen: &mut Vec<usize>,
+    best: &mut Vec<usize>,
+) {
+    if chosen.len() + candidates.len() <= best.len() {
+        return;
+    }
+    let degree = |v: &usize| {
+        neighbors[*v]
+            .iter()
+            .filter(|u| candidates.contains(u))
+            .count()
+    };
+    let Some(v) = candidates.iter().copied().max_by_key(degree) else {
+        best.clone_from(chosen);
+        return;
+    };
+    // the remaining candidates are pairwise independent
+    if degree(&v) == 0 {
+        best.clone_from(chosen);
+        best.extend(candidates);
+        return;
+    }
+    let rest = candidates
+        .iter()
+        .copied()
+        .filter(|u| *u != v && !neighbors[v].contains(u))
+        .collect();
+    chosen.push(v);
+    branch(neighbors, rest, chosen, best);
+    chosen.pop();
+    let rest = candidates.into_iter().filter(|u| *u != v).collect();
+    branch(neighbors, rest, chosen, best);
+}
+
+/// returns the (sorted) positions of the given vertices, or [`None`] if any are missing
+fn positions<Ix>(vertices: &[VertexId<Ix>], set: &[VertexId<Ix>]) -> Option<Vec<usize>>
+where
+    Ix: HyperIndex,
+{
+    let mut res = set
+        .iter()
+        .map(|v| vertices.binary_search(v).ok())
+        .collect::<Option<Vec<_>>>()?;
+    res.sort_unstable();
+    Some(res)
+}
@@ -0,0 +1,19 @@
+/*
+    appellation: coloring <module>
+    authors: @FL03
+*/
+//! this module implements weak and strong vertex colorings of hypergraphs alongside the
+//! computation of their maximal and maximum independent sets
+#[doc(inline)]
+pub use self::{coloring::*, independent_sets::*, vertex_coloring::*};
+
+pub mod coloring;
+pub mod independent_sets;
+pub mod vertex_coloring;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::coloring::Coloring;
+    pub use super::independent_sets::IndependentSets;
+    pub use super::vertex_coloring::VertexColoring;
+}
@@ -0,0 +1,87 @@
+/*
+    appellation: vertex_coloring <module>
+    authors: @FL03
+*/
+//! this module implements the [`VertexColoring`] assigning a color to each vertex of a
+//! hypergraph
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+use rshyper::idx::{RawIndex, Udx, VertexId};
+
+/// A [`VertexColoring`] assigns a color, represented by a number within `0..num_colors`, to
+/// each of the vertices of a hypergraph. Depending upon how it was computed, the coloring is
+/// either _weak_, leaving no edge with two or more vertices monochromatic, or _strong_,
+/// assigning distinct colors to the vertices of every edge.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct VertexColoring<Ix = Udx>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// maps each vertex onto its color
+    pub(crate) colors: HashMap<VertexId<Ix>, usize>,
+    /// the number of colors used by the coloring
+    pub(crate) num_colors: usize,
+}
+
+impl<Ix> VertexColoring<Ix>
+where
+    Ix: RawIndex + Copy + Ord + core::hash::Hash,
+{
+    /// returns a new, empty coloring
+    pub fn new() -> Self {
+        Self {
+            colors: HashMap::new(),
+            num_colors: 0,
+        }
+    }
+    /// create a new coloring from the given vertices and their respective colors
+    pub(crate) fn from_parts(vertices: &[VertexId<Ix>], colors: &[usize]) -> Self {
+        Self {
+            colors: vertices
+                .iter()
+                .copied()
+                .zip(colors.iter().copied())
+                .collect(),
+            num_colors: colors.iter().max().map_or(0, |c| c + 1),
+        }
+    }
+    /// returns an immutable reference to the map associating each vertex with its color
+    pub const fn colors(&self) -> &HashMap<VertexId<Ix>, usize> {
+        &self.colors
+    }
+    /// returns the number of colors used by the coloring
+    pub const fn num_colors(&self) -> usize {
+        self.num_colors
+    }
+    /// returns the color of the given vertex, if any
+    pub fn get(&self, vertex: &VertexId<Ix>) -> Option<usize> {
+        self.colors.get(vertex).copied()
+    }
+    /// returns the _color classes_ of the coloring, i.e. the vertices assigned each of the
+    /// colors, with each class ordered by index
+    pub fn classes(&self) -> Vec<Vec<VertexId<Ix>>> {
+        let mut classes = alloc::vec![Vec::new(); self.num_colors];
+        for (v, c) in &self.colors {
+            classes[*c].push(*v);
+        }
+        classes.iter_mut().for_each(|class| class.sort());
+        classes
+    }
+    /// returns true if the coloring assigns a color to the given vertex
+    pub fn contains(&self, vertex: &VertexId<Ix>) -> bool {
+        self.colors.contains_key(vertex)
+    }
+    /// returns true if the coloring contains no vertices
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+    /// returns the number of colored vertices
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+}
@@ -0,0 +1,70 @@
+/*
+    appellation: communities <module>
+    authors: @FL03
+*/
+//! this module implements the [`Communities`] partitioning the vertices of a hypergraph
+use alloc::vec::Vec;
+use rshyper::idx::{RawIndex, Udx, VertexId};
+
+/// The [`Communities`] of a hypergraph partition its vertices into disjoint sets, each scored
+/// by its contribution to the modularity of the partition. The communities are ordered by
+/// their smallest vertex, with the vertices of each community ordered by index.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Communities<Ix = Udx>
+where
+    Ix: RawIndex,
+{
+    /// the vertices of each community
+    pub(crate) partition: Vec<Vec<VertexId<Ix>>>,
+    /// the contribution of each community to the modularity of the partition
+    pub(crate) scores: Vec<f64>,
+    /// the modularity of the partition
+    pub(crate) modularity: f64,
+}
+
+impl<Ix> Communities<Ix>
+where
+    Ix: RawIndex + PartialEq,
+{
+    /// returns a new, empty partition
+    pub const fn new() -> Self {
+        Self {
+            partition: Vec::new(),
+            scores: Vec::new(),
+            modularity: 0.0,
+        }
+    }
+    /// returns the vertices of each community
+    pub fn partition(&self) -> &[Vec<VertexId<Ix>>] {
+        &self.partition
+    }
+    /// returns the contribution of each community to the modularity of the partition
+    pub fn scores(&self) -> &[f64] {
+        &self.scores
+    }
+    /// returns the modularity of the partition
+    pub const fn modularity(&self) -> f64 {
+        self.modularity
+    }
+    /// returns the vertices of the community at the given position, if any
+    pub fn get(&self, index: usize) -> Option<&[VertexId<Ix>]> {
+        self.partition.get(index).map(Vec::as_slice)
+    }
+    /// returns the position of the community containing the given vertex, if any
+    pub fn community_of(&self, vertex: &VertexId<Ix>) -> Option<usize> {
+        self.partition.iter().position(|c| c.contains(vertex))
+    }
+    /// returns true if the partition contains no communities
+    pub fn is_empty(&self) -> bool {
+        self.partition.is_empty()
+    }
+    /// returns the number of communities
+    pub fn len(&self) -> usize {
+        self.partition.len()
+    }
+}
@@ -0,0 +1,86 @@
+/*
+    appellation: label_propagation <module>
+    authors: @FL03
+*/
+//! this module implements the [`LabelPropagation`] operator, quickly clustering the vertices
+//! of a hypergraph by propagating labels over its edges
+use super::label_stream::DEFAULT_MAX_ITERATIONS;
+use super::{Communities, LabelStream};
+use crate::utils::index_graph;
+use rshyper::idx::{HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIter, RawLayout};
+
+/// The [`LabelPropagation`] operator computes an approximate clustering of a hypergraph in
+/// near-linear time, propagating labels from the vertices to the edges and back again until
+/// they settle; see [`LabelStream`] for the details of the propagation.
+///
+/// Unlike [`louvain`](super::Modularity::louvain), the propagation makes no attempt at
+/// maximizing the modularity, trading the quality of the communities for speed. The resulting
+/// [`LabelStream`] may be extended with further edges, updating the clustering incrementally.
+pub struct LabelPropagation<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) max_iterations: usize,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> LabelPropagation<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns the largest number of sweeps performed by the propagation
+    pub const fn max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+    /// consumes the current instance to create another performing, at most, the given number
+    /// of sweeps
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+    /// returns the communities found by propagating labels over the hypergraph, scored by
+    /// their strict modularity
+    pub fn communities(&self) -> Communities<A::Ix> {
+        self.stream().communities()
+    }
+    /// returns a [`LabelStream`] whose labels have been propagated over the hypergraph, which
+    /// may be extended with further vertices and edges
+    pub fn stream(&self) -> LabelStream<A::Ix> {
+        let (vertices, edges) = index_graph(self.graph);
+        let mut stream = LabelStream {
+            index: vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect(),
+            labels: (0..vertices.len()).collect(),
+            incidence: alloc::vec![alloc::vec::Vec::new(); vertices.len()],
+            vertices,
+            edges: alloc::vec::Vec::new(),
+            max_iterations: self.max_iterations,
+        };
+        for edge in edges.into_iter().filter(|e| !e.is_empty()) {
+            let id = stream.edges.len();
+            edge.iter().for_each(|v| stream.incidence[*v].push(id));
+            stream.edges.push(edge);
+        }
+        stream.propagate();
+        stream
+    }
+}
@@ -0,0 +1,256 @@
+/*
+    appellation: label_stream <module>
+    authors: @FL03
+*/
+//! this module implements the [`LabelStream`], clustering the vertices of a hypergraph by
+//! propagating labels as its edges arrive
+use super::Communities;
+use super::modularity::{Model, ModularityKind};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+use rshyper::idx::{HyperIndex, RawIndex, Udx, VertexId};
+
+/// the default number of sweeps performed by the label propagation
+pub const DEFAULT_MAX_ITERATIONS: usize = 100;
+
+/// A [`LabelStream`] clusters the vertices of a hypergraph by propagating labels from the
+/// vertices to the edges and back again: each edge adopts the most frequent label among its
+/// vertices before each vertex adopts the most frequent label among its edges, keeping its own
+/// label whenever it remains among the most frequent and otherwise breaking ties in favor of
+/// the smallest label.
+///
+/// The stream may be extended one edge at a time, with each insertion only revisiting the
+/// vertices reached by the labels it changes, making it suitable for hypergraphs too large for
+/// modularity optimization. A full [`propagate`](LabelStream::propagate) sweeps every vertex
+/// until the labels settle and, with the `rayon` feature enabled, updates the vertices in
+/// parallel, each reading the labels of the previous sweep so that the outcome does not
+/// depend upon the scheduling of the threads.
+#[derive(Clone, Debug)]
+pub struct LabelStream<Ix = Udx>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// maps each vertex onto its position
+    pub(crate) index: HashMap<VertexId<Ix>, usize>,
+    /// the vertices, ordered by their insertion
+    pub(crate) vertices: Vec<VertexId<Ix>>,
+    /// the members of each edge, given as positions
+    pub(crate) edges: Vec<Vec<usize>>,
+    /// the edges containing each vertex
+    pub(crate) incidence: Vec<Vec<usize>>,
+    /// the current label of each vertex
+    pub(crate) labels: Vec<usize>,
+    /// the largest number of sweeps performed by a propagation
+    pub(crate) max_iterations: usize,
+}
+
+impl<Ix> LabelStream<Ix>
+where
+    Ix: HyperIndex,
+{
+    /// returns a new, empty stream
+    pub fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            incidence: Vec::new(),
+            labels: Vec::new(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+    /// returns the largest number of sweeps performed by a propagation
+    pub const fn max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+    /// consumes the current instance to create another performing, at most, the given number
+    /// of sweeps per propagation
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+    /// returns the number of vertices observed by the stream
+    pub fn order(&self) -> usize {
+        self.vertices.len()
+    }
+    /// returns the number of edges observed by the stream
+    pub fn size(&self) -> usize {
+        self.edges.len()
+    }
+    /// returns true if the stream has observed the given vertex
+    pub fn contains(&self, vertex: &VertexId<Ix>) -> bool {
+        self.index.contains_key(vertex)
+    }
+    /// returns the current label of the given vertex, if any
+    pub fn label(&self, vertex: &VertexId<Ix>) -> Option<usize> {
+        self.index.get(vertex).map(|v| self.labels[*v])
+    }
+    /// inserts the given vertex as a community of its own, returning true if it was not
+    /// previously observed
+    pub fn insert_vertex(&mut self, vertex: VertexId<Ix>) -> bool {
+        if self.contains(&vertex) {
+            return false;
+        }
+        self.position(vertex);
+        true
+    }
+    /// inserts an edge containing the given vertices, observing any that are new, before
+    /// propagating the labels outwards from its members until they settle
+    pub fn insert_edge<I>(&mut self, edge: I)
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let mut members = edge
+            .into_iter()
+            .map(|v| self.position(v))
+            .collect::<Vec<_>>();
+        members.sort_unstable();
+        members.dedup();
+        if members.is_empty() {
+            return;
+        }
+        let id = self.edges.len();
+        members.iter().for_each(|v| self.incidence[*v].push(id));
+        self.edges.push(members.clone());
+        self.settle(members);
+    }
+    /// sweeps over every vertex until the labels settle or the maximum number of iterations
+    /// is reached, returning the number of sweeps performed
+    pub fn propagate(&mut self) -> usize {
+        let mut sweeps = 0;
+        while sweeps < self.max_iterations {
+            sweeps += 1;
+            if !self.sweep() {
+                break;
+            }
+        }
+        sweeps
+    }
+    /// returns the communities formed by the vertices sharing a label, scored by their strict
+    /// modularity
+    pub fn communities(&self) -> Communities<Ix> {
+        self.communities_with(ModularityKind::Strict)
+    }
+    /// returns the communities formed by the vertices sharing a label, scored using the given
+    /// kind of modularity
+    pub fn communities_with(&self, kind: ModularityKind) -> Communities<Ix> {
+        // reorder the vertices by index
+        let mut order = (0..self.order()).collect::<Vec<_>>();
+        order.sort_by_key(|v| self.vertices[*v]);
+        let mut rank = alloc::vec![0; self.order()];
+        order.iter().enumerate().for_each(|(i, v)| rank[*v] = i);
+        let vertices = order.iter().map(|v| self.vertices[*v]).collect::<Vec<_>>();
+        let labels = order.iter().map(|v| self.labels[*v]).collect::<Vec<_>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|e| e.iter().map(|v| rank[*v]).collect())
+            .collect();
+        Model::new(self.order(), edges, kind).communities(&vertices, &labels)
+    }
+    /// returns the position of the given vertex, observing it if necessary
+    fn position(&mut self, vertex: VertexId<Ix>) -> usize {
+        if let Some(&pos) = self.index.get(&vertex) {
+            return pos;
+        }
+        let pos = self.vertices.len();
+        self.index.insert(vertex, pos);
+        self.vertices.push(vertex);
+        self.incidence.push(Vec::new());
+        self.labels.push(pos);
+        pos
+    }
+    /// updates the given vertices, alongside the neighbors of any whose label changes, until
+    /// the labels settle or the budget of the propagation is exhausted
+    fn settle(&mut self, vertices: Vec<usize>) {
+        let mut queued = alloc::vec![false; self.order()];
+        vertices.iter().for_each(|v| queued[*v] = true);
+        let mut queue = VecDeque::from(vertices);
+        let mut budget = self.max_iterations.saturating_mul(self.order());
+        while let Some(v) = queue.pop_front() {
+            if budget == 0 {
+                break;
+            }
+            budget -= 1;
+            queued[v] = false;
+            let label = choose(&self.edges, &self.incidence, v, |u| self.labels[u]);
+            if label == self.labels[v] {
+                continue;
+            }
+            self.labels[v] = label;
+            for &e in &self.incidence[v] {
+                for &u in &self.edges[e] {
+                    if !queued[u] {
+                        queued[u] = true;
+                        queue.push_back(u);
+                    }
+                }
+            }
+        }
+    }
+    /// updates every vertex once, returning true if any label changed
+    #[cfg(not(feature = "rayon"))]
+    fn sweep(&mut self) -> bool {
+        let mut changed = false;
+        for v in 0..self.order() {
+            let label = choose(&self.edges, &self.incidence, v, |u| self.labels[u]);
+            changed |= label != self.labels[v];
+            self.labels[v] = label;
+        }
+        changed
+    }
+    /// updates every vertex once, in parallel, returning true if any label changed
+    #[cfg(feature = "rayon")]
+    fn sweep(&mut self) -> bool {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+        let (edges, incidence, prev) = (&self.edges, &self.incidence, &self.labels);
+        // each vertex observes the labels of its neighbors from the previous sweep
+        let labels = (0..self.order())
+            .into_par_iter()
+            .map(|v| choose(edges, incidence, v, |u| prev[u]))
+            .collect::<Vec<_>>();
+        let changed = labels != self.labels;
+        self.labels = labels;
+        changed
+    }
+}
+
+impl<Ix> Default for LabelStream<Ix>
+where
+    Ix: HyperIndex,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// returns the label adopted by the given vertex, where `label` reads the current label of
+/// each vertex
+fn choose<F>(edges: &[Vec<usize>], incidence: &[Vec<usize>], vertex: usize, label: F) -> usize
+where
+    F: Fn(usize) -> usize,
+{
+    let current = label(vertex);
+    let votes = incidence[vertex]
+        .iter()
+        .filter_map(|e| plurality(edges[*e].iter().map(|u| label(*u)).collect(), None))
+        .collect::<Vec<_>>();
+    plurality(votes, Some(current)).unwrap_or(current)
+}
+
+/// returns the most frequent of the given labels, preferring the given label, if any, and
+/// otherwise the smallest label among those tied
+fn plurality(mut labels: Vec<usize>, preferred: Option<usize>) -> Option<usize> {
+    labels.sort_unstable();
+    let mut best = None::<(usize, usize)>;
+    for run in labels.chunk_by(|a, b| a == b) {
+        let (count, label) = (run.len(), run[0]);
+        if best.is_none_or(|(c, _)| count > c || (count == c && Some(label) == preferred)) {
+            best = Some((count, label));
+        }
+    }
+    best.map(|(_, label)| label)
+}
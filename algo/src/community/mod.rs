@@ -0,0 +1,22 @@
+/*
+    appellation: community <module>
+    authors: @FL03
+*/
+//! this module implements the modularity of vertex partitions alongside the detection of
+//! communities within hypergraphs, either by optimizing the modularity or by streaming label
+//! propagation
+#[doc(inline)]
+pub use self::{communities::*, label_propagation::*, label_stream::*, modularity::*};
+
+pub mod communities;
+pub mod label_propagation;
+pub mod label_stream;
+pub mod modularity;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::communities::Communities;
+    pub use super::label_propagation::LabelPropagation;
+    pub use super::label_stream::LabelStream;
+    pub use super::modularity::{Modularity, ModularityKind};
+}
@@ -0,0 +1,496 @@
+/*
+    appellation: modularity <module>
+    authors: @FL03
+*/
+//! this module implements the [`Modularity`] operator, scoring vertex partitions of
+//! hypergraphs and detecting the communities maximizing it
+use super::Communities;
+use crate::error::Result;
+use crate::utils::index_graph;
+use alloc::vec::Vec;
+use rshyper::idx::{HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIter, RawLayout};
+
+/// the smallest improvement in modularity accepted by the optimization
+const TOLERANCE: f64 = 1e-12;
+
+/// [`ModularityKind`] enumerates the notions of an edge being contained by a community that
+/// are supported by the hypergraph modularity.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::VariantArray,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum ModularityKind {
+    /// an edge belongs to a community only if each of its vertices does
+    #[default]
+    Strict,
+    /// an edge belongs to the community containing more than half of its vertices, if any
+    Majority,
+}
+
+/// The [`Modularity`] operator measures how strongly the vertices of a hypergraph are
+/// partitioned into communities, comparing the number of edges contained by the communities
+/// against the number expected under a degree-preserving null model. Following Kamiński et
+/// al., the modularity of a partition `P` is
+///
+/// ```math
+/// q(P) = \frac{1}{|E|} \sum_{C \in P} \left( e(C) - \sum_{d} |E_d| \, f_d\left(\frac{vol(C)}{vol(V)}\right) \right)
+/// ```
+///
+/// where `e(C)` counts the edges belonging to the community `C`, `E_d` denotes the edges of
+/// size `d` and `vol` sums the degrees of the vertices. For [strict](ModularityKind::Strict)
+/// modularity, `f_d(p) = p^d` is the probability of an edge of size `d` falling entirely
+/// within a community, while the [majority](ModularityKind::Majority) variant uses the
+/// probability of more than half of its vertices doing so.
+///
+/// Communities are detected using a Louvain-style optimization, alternately moving single
+/// vertices and merging entire communities for as long as either improves the modularity,
+/// before splitting any disconnected community into its components in the spirit of Leiden.
+pub struct Modularity<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) kind: ModularityKind,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> Modularity<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph using the strict modularity
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            kind: ModularityKind::Strict,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns the kind of modularity used by the operator
+    pub const fn kind(&self) -> ModularityKind {
+        self.kind
+    }
+    /// consumes the current instance to create another using the given kind of modularity
+    pub fn with_kind(self, kind: ModularityKind) -> Self {
+        Self { kind, ..self }
+    }
+    /// returns the modularity of the given partition; vertices missing from the partition are
+    /// treated as singleton communities, while a vertex listed by several communities belongs
+    /// to the last of them.
+    pub fn score(&self, partition: &[Vec<VertexId<A::Ix>>]) -> Result<f64> {
+        Ok(self.evaluate(partition)?.modularity())
+    }
+    /// returns the given partition alongside its modularity and the contribution of each of
+    /// its communities; vertices missing from the partition are added as singleton
+    /// communities.
+    pub fn evaluate(&self, partition: &[Vec<VertexId<A::Ix>>]) -> Result<Communities<A::Ix>> {
+        let (vertices, edges) = index_graph(self.graph);
+        let order = vertices.len();
+        let mut assignment = (0..order).collect::<Vec<_>>();
+        for (i, community) in partition.iter().enumerate() {
+            for v in community {
+                let pos = vertices
+                    .binary_search(v)
+                    .map_err(|_| rshyper::Error::NodeNotFound)?;
+                assignment[pos] = order + i;
+            }
+        }
+        let model = Model::new(order, edges, self.kind);
+        Ok(model.communities(&vertices, &assignment))
+    }
+    /// detects the communities of the hypergraph by maximizing its modularity
+    pub fn louvain(&self) -> Communities<A::Ix> {
+        let (vertices, edges) = index_graph(self.graph);
+        let model = Model::new(vertices.len(), edges, self.kind);
+        let assignment = model.louvain();
+        model.communities(&vertices, &assignment)
+    }
+}
+
+/// the modularity of a hypergraph whose vertices are identified by their position
+pub(crate) struct Model {
+    /// the notion of an edge belonging to a community
+    kind: ModularityKind,
+    /// the (non-empty) edges of the hypergraph as lists of vertex positions
+    edges: Vec<Vec<usize>>,
+    /// the edges containing each of the vertices
+    incidence: Vec<Vec<usize>>,
+    /// the distinct sizes of the edges alongside the number of edges of each size
+    sizes: Vec<(usize, f64)>,
+    /// the total degree of the vertices
+    volume: f64,
+}
+
+impl Model {
+    /// create a new model of the given edges over `order` vertices
+    pub(crate) fn new(order: usize, mut edges: Vec<Vec<usize>>, kind: ModularityKind) -> Self {
+        edges.retain(|e| !e.is_empty());
+        let mut incidence = alloc::vec![Vec::new(); order];
+        for (i, edge) in edges.iter().enumerate() {
+            edge.iter().for_each(|v| incidence[*v].push(i));
+        }
+        let mut lens = edges.iter().map(Vec::len).collect::<Vec<_>>();
+        lens.sort_unstable();
+        let mut sizes = Vec::<(usize, f64)>::new();
+        for d in lens {
+            match sizes.last_mut() {
+                Some((last, count)) if *last == d => *count += 1.0,
+                _ => sizes.push((d, 1.0)),
+            }
+        }
+        let volume = edges.iter().map(|e| e.len() as f64).sum();
+        Self {
+            kind,
+            edges,
+            incidence,
+            sizes,
+            volume,
+        }
+    }
+    /// returns the number of vertices
+    pub(crate) fn order(&self) -> usize {
+        self.incidence.len()
+    }
+    /// returns the degree of the given vertex
+    fn degree(&self, vertex: usize) -> f64 {
+        self.incidence[vertex].len() as f64
+    }
+    /// returns the neighbors of the given vertex, i.e. the vertices sharing some edge with it
+    pub(crate) fn neighbors(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.incidence[vertex]
+            .iter()
+            .flat_map(|e| self.edges[*e].iter().copied())
+            .filter(move |u| *u != vertex)
+    }
+    /// returns the expected number of edges belonging to a community of the given volume
+    fn expected(&self, volume: f64) -> f64 {
+        if self.volume <= 0.0 || volume <= 0.0 {
+            return 0.0;
+        }
+        let p = (volume / self.volume).min(1.0);
+        self.sizes
+            .iter()
+            .map(|(d, count)| count * self.probability(*d, p))
+            .sum()
+    }
+    /// returns the probability of an edge of size `d` belonging to a community whose vertices
+    /// are each drawn with probability `p`
+    fn probability(&self, d: usize, p: f64) -> f64 {
+        match self.kind {
+            ModularityKind::Strict => p.powi(d as i32),
+            ModularityKind::Majority => {
+                // the upper tail of the binomial distribution beyond half of the vertices
+                let mut coeff = 1.0;
+                let mut res = 0.0;
+                for c in 0..=d {
+                    if 2 * c > d {
+                        res += coeff * p.powi(c as i32) * (1.0 - p).powi((d - c) as i32);
+                    }
+                    coeff = coeff * (d - c) as f64 / (c + 1) as f64;
+                }
+                res
+            }
+        }
+    }
+    /// returns the community the given edge belongs to, if any, where `label` assigns each
+    /// vertex to its community
+    fn owner<F>(&self, edge: usize, label: F) -> Option<usize>
+    where
+        F: Fn(usize) -> usize,
+    {
+        let members = &self.edges[edge];
+        match self.kind {
+            ModularityKind::Strict => {
+                let first = label(members[0]);
+                members.iter().all(|v| label(*v) == first).then_some(first)
+            }
+            ModularityKind::Majority => {
+                let mut labels = members.iter().map(|v| label(*v)).collect::<Vec<_>>();
+                labels.sort_unstable();
+                labels
+                    .chunk_by(|a, b| a == b)
+                    .find(|run| 2 * run.len() > members.len())
+                    .map(|run| run[0])
+            }
+        }
+    }
+    /// returns the number of the given edges belonging to some community
+    fn owned<F>(&self, edges: &[usize], label: F) -> f64
+    where
+        F: Fn(usize) -> usize,
+    {
+        edges
+            .iter()
+            .filter(|e| self.owner(**e, &label).is_some())
+            .count() as f64
+    }
+    /// returns the contribution of each community, identified by its label, to the modularity
+    /// of the given assignment
+    fn scores(&self, assignment: &[usize]) -> Vec<f64> {
+        let labels = assignment.iter().max().map_or(0, |l| l + 1);
+        let mut owned = alloc::vec![0.0; labels];
+        let mut volume = alloc::vec![0.0; labels];
+        for e in 0..self.edges.len() {
+            if let Some(c) = self.owner(e, |v| assignment[v]) {
+                owned[c] += 1.0;
+            }
+        }
+        for (v, c) in assignment.iter().enumerate() {
+            volume[*c] += self.degree(v);
+        }
+        let size = self.edges.len().max(1) as f64;
+        owned
+            .into_iter()
+            .zip(volume)
+            .map(|(owned, volume)| (owned - self.expected(volume)) / size)
+            .collect()
+    }
+    /// returns the modularity of the given assignment
+    pub(crate) fn modularity(&self, assignment: &[usize]) -> f64 {
+        self.scores(assignment).into_iter().sum()
+    }
+    /// collects the given assignment into [`Communities`] over the given vertices
+    pub(crate) fn communities<Ix>(
+        &self,
+        vertices: &[VertexId<Ix>],
+        assignment: &[usize],
+    ) -> Communities<Ix>
+    where
+        Ix: HyperIndex,
+    {
+        let assignment = normalize(assignment);
+        let scores = self.scores(&assignment);
+        let mut partition = alloc::vec![Vec::new(); scores.len()];
+        for (v, c) in assignment.iter().enumerate() {
+            partition[*c].push(vertices[v]);
+        }
+        Communities {
+            modularity: scores.iter().sum(),
+            partition,
+            scores,
+        }
+    }
+    /// maximizes the modularity starting from singleton communities, returning the label of
+    /// each vertex
+    pub(crate) fn louvain(&self) -> Vec<usize> {
+        let mut assignment = (0..self.order()).collect::<Vec<_>>();
+        let mut volume = (0..self.order())
+            .map(|v| self.degree(v))
+            .collect::<Vec<_>>();
+        while self.move_vertices(&mut assignment, &mut volume)
+            | self.merge_communities(&mut assignment, &mut volume)
+        {}
+        self.split_communities(assignment)
+    }
+    /// repeatedly moves single vertices into the neighboring community improving the
+    /// modularity the most, returning true if any vertex was moved
+    fn move_vertices(&self, assignment: &mut [usize], volume: &mut [f64]) -> bool {
+        let mut improved = false;
+        loop {
+            let mut moved = false;
+            for v in 0..self.order() {
+                let from = assignment[v];
+                let degree = self.degree(v);
+                let edges = &self.incidence[v];
+                let before = self.owned(edges, |u| assignment[u]);
+                let mut targets = self
+                    .neighbors(v)
+                    .map(|u| assignment[u])
+                    .filter(|c| *c != from)
+                    .collect::<Vec<_>>();
+                targets.sort_unstable();
+                targets.dedup();
+                let mut best = (TOLERANCE, None);
+                for to in targets {
+                    let after = self.owned(edges, |u| if u == v { to } else { assignment[u] });
+                    let expected = self.expected(volume[from] - degree)
+                        + self.expected(volume[to] + degree)
+                        - self.expected(volume[from])
+                        - self.expected(volume[to]);
+                    let gain = after - before - expected;
+                    if gain > best.0 {
+                        best = (gain, Some(to));
+                    }
+                }
+                if let (_, Some(to)) = best {
+                    assignment[v] = to;
+                    volume[from] -= degree;
+                    volume[to] += degree;
+                    moved = true;
+                }
+            }
+            if !moved {
+                return improved;
+            }
+            improved = true;
+        }
+    }
+    /// merges each community with the adjacent community improving the modularity the most
+    /// before merging the communities spanned by any edge whenever doing so improves the
+    /// modularity, returning true if any communities were merged
+    fn merge_communities(&self, assignment: &mut [usize], volume: &mut [f64]) -> bool {
+        let mut members = alloc::vec![Vec::new(); self.order()];
+        for (v, c) in assignment.iter().enumerate() {
+            members[*c].push(v);
+        }
+        let mut merged = false;
+        for a in 0..self.order() {
+            if members[a].is_empty() {
+                continue;
+            }
+            let mut targets = members[a]
+                .iter()
+                .flat_map(|v| self.neighbors(*v))
+                .map(|u| assignment[u])
+                .filter(|c| *c != a)
+                .collect::<Vec<_>>();
+            targets.sort_unstable();
+            targets.dedup();
+            let mut best = (TOLERANCE, None);
+            for b in targets {
+                let gain = self.gain(&[a, b], &members, assignment, volume);
+                if gain > best.0 {
+                    best = (gain, Some(b));
+                }
+            }
+            if let (_, Some(b)) = best {
+                merge(&[a, b], &mut members, assignment, volume);
+                merged = true;
+            }
+        }
+        // the vertices of larger edges may not be gathered by merging pairs of communities
+        for edge in &self.edges {
+            let mut group = edge.iter().map(|v| assignment[*v]).collect::<Vec<_>>();
+            group.sort_unstable();
+            group.dedup();
+            if group.len() > 1 && self.gain(&group, &members, assignment, volume) > TOLERANCE {
+                merge(&group, &mut members, assignment, volume);
+                merged = true;
+            }
+        }
+        merged
+    }
+    /// returns the change in modularity caused by merging the given group of communities
+    fn gain(
+        &self,
+        group: &[usize],
+        members: &[Vec<usize>],
+        assignment: &[usize],
+        volume: &[f64],
+    ) -> f64 {
+        // the edges touching any of the communities
+        let mut edges = group
+            .iter()
+            .flat_map(|c| &members[*c])
+            .flat_map(|v| self.incidence[*v].iter().copied())
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges.dedup();
+        let before = self.owned(&edges, |u| assignment[u]);
+        let after = self.owned(&edges, |u| match assignment[u] {
+            c if group.contains(&c) => group[0],
+            c => c,
+        });
+        let expected = self.expected(group.iter().map(|c| volume[*c]).sum())
+            - group.iter().map(|c| self.expected(volume[*c])).sum::<f64>();
+        after - before - expected
+    }
+    /// splits each community into the components connected by its edges, provided doing so
+    /// does not decrease the modularity
+    fn split_communities(&self, assignment: Vec<usize>) -> Vec<usize> {
+        // the components of each community, identified by a representative vertex
+        let mut parent = (0..self.order()).collect::<Vec<_>>();
+        fn find(parent: &mut [usize], v: usize) -> usize {
+            let mut root = v;
+            while parent[root] != root {
+                root = parent[root];
+            }
+            parent[v] = root;
+            root
+        }
+        for edge in &self.edges {
+            // join each member with the next member of the same community, which may be
+            // separated from it by members of others
+            for (i, &a) in edge.iter().enumerate() {
+                if let Some(&b) = edge[i + 1..]
+                    .iter()
+                    .find(|b| assignment[**b] == assignment[a])
+                {
+                    let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+                    parent[ra] = rb;
+                }
+            }
+        }
+        let split = (0..self.order())
+            .map(|v| find(&mut parent, v))
+            .collect::<Vec<_>>();
+        if self.modularity(&split) + TOLERANCE >= self.modularity(&assignment) {
+            split
+        } else {
+            assignment
+        }
+    }
+}
+
+/// merges the given group of communities into the first of them
+fn merge(
+    group: &[usize],
+    members: &mut [Vec<usize>],
+    assignment: &mut [usize],
+    volume: &mut [f64],
+) {
+    let (target, rest) = group.split_first().expect("the group is not empty");
+    for c in rest {
+        let moved = core::mem::take(&mut members[*c]);
+        moved.iter().for_each(|v| assignment[*v] = *target);
+        members[*target].extend(moved);
+        volume[*target] += core::mem::take(&mut volume[*c]);
+    }
+}
+
+/// relabels the communities of the given assignment by the order of their first vertex
+fn normalize(assignment: &[usize]) -> Vec<usize> {
+    let labels = assignment.iter().max().map_or(0, |l| l + 1);
+    let mut relabel = alloc::vec![usize::MAX; labels];
+    let mut next = 0;
+    assignment
+        .iter()
+        .map(|c| {
+            if relabel[*c] == usize::MAX {
+                relabel[*c] = next;
+                next += 1;
+            }
+            relabel[*c]
+        })
+        .collect()
+}
@@ -0,0 +1,229 @@
+/*
+    appellation: core_decomposition <module>
+    authors: @FL03
+*/
+//! this module implements the [`CoreDecomposition`] operator, peeling hypergraphs into their
+//! k-cores and (k, m)-cores
+use super::HyperCore;
+use crate::utils::collect_edges;
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use hashbrown::HashMap;
+use rshyper::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIter, RawLayout};
+
+/// The [`CoreDecomposition`] operator peels a hypergraph by repeatedly removing the vertices
+/// contained by fewer than `k` of the remaining edges:
+///
+/// - the _k-core_ is the largest sub-hypergraph whose vertices each belong to, at least, `k`
+///   of its edges, where removing a vertex removes every edge containing it.
+/// - the _(k, m)-core_ relaxes the k-core by allowing edges to survive the removal of their
+///   members for as long as, at least, `m` of them remain; the surviving edges are restricted
+///   to their surviving members.
+///
+/// The _core number_ of a vertex is the largest `k` for which it belongs to the respective
+/// core, with each decomposition computed by a single pass over the incidence structure that
+/// always removes a vertex of least remaining degree.
+pub struct CoreDecomposition<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> CoreDecomposition<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns the k-core number of every vertex of the hypergraph
+    pub fn core_numbers(&self) -> HashMap<VertexId<A::Ix>, usize> {
+        self.numbers(None)
+    }
+    /// returns the (k, m)-core number of every vertex of the hypergraph for the given `m`,
+    /// which is treated as, at least, one
+    pub fn km_core_numbers(&self, m: usize) -> HashMap<VertexId<A::Ix>, usize> {
+        self.numbers(Some(m))
+    }
+    /// returns the degeneracy of the hypergraph, i.e. the largest k-core number of any vertex
+    pub fn degeneracy(&self) -> usize {
+        self.core_numbers().into_values().max().unwrap_or_default()
+    }
+    /// returns the k-core of the hypergraph
+    pub fn k_core(&self, k: usize) -> HyperCore<A::Ix> {
+        self.peel(k, None)
+    }
+    /// returns the (k, m)-core of the hypergraph, where `m` is treated as, at least, one
+    pub fn km_core(&self, k: usize, m: usize) -> HyperCore<A::Ix> {
+        self.peel(k, Some(m))
+    }
+    /// returns the vertices of the hypergraph, ordered by index, alongside its edges
+    fn index(&self) -> Indexed<A::Ix> {
+        let mut vertices = self.graph.vertices().copied().collect::<Vec<_>>();
+        vertices.sort();
+        let (ids, edges) = collect_edges(self.graph)
+            .into_iter()
+            .map(|(id, domain)| {
+                let members = domain
+                    .iter()
+                    .filter_map(|v| vertices.binary_search(v).ok())
+                    .collect::<Vec<_>>();
+                (id, members)
+            })
+            .unzip();
+        (vertices, ids, edges)
+    }
+    /// computes the core number of each vertex by repeatedly removing a vertex of least
+    /// remaining degree
+    fn numbers(&self, m: Option<usize>) -> HashMap<VertexId<A::Ix>, usize> {
+        let (vertices, _, edges) = self.index();
+        let mut peeling = Peeling::new(vertices.len(), edges, m);
+        let mut heap = (0..vertices.len())
+            .map(|v| Reverse((peeling.degree[v], v)))
+            .collect::<BinaryHeap<_>>();
+        let mut numbers = HashMap::with_capacity(vertices.len());
+        let mut k = 0;
+        while let Some(Reverse((degree, v))) = heap.pop() {
+            // skip the entries outdated by the removal of a neighbor
+            if peeling.removed[v] || degree != peeling.degree[v] {
+                continue;
+            }
+            k = k.max(degree);
+            numbers.insert(vertices[v], k);
+            for u in peeling.remove(v) {
+                heap.push(Reverse((peeling.degree[u], u)));
+            }
+        }
+        numbers
+    }
+    /// removes the vertices whose remaining degree is less than `k` until none remain
+    fn peel(&self, k: usize, m: Option<usize>) -> HyperCore<A::Ix> {
+        let (vertices, ids, edges) = self.index();
+        let mut peeling = Peeling::new(vertices.len(), edges, m);
+        let mut stack = (0..vertices.len())
+            .filter(|v| peeling.degree[*v] < k)
+            .collect::<Vec<_>>();
+        while let Some(v) = stack.pop() {
+            if peeling.removed[v] {
+                continue;
+            }
+            let touched = peeling.remove(v);
+            stack.extend(touched.into_iter().filter(|u| peeling.degree[*u] < k));
+        }
+        let edges = ids
+            .into_iter()
+            .zip(&peeling.edges)
+            .zip(&peeling.live)
+            .filter(|(_, live)| **live)
+            .map(|((id, members), _)| {
+                let members = members
+                    .iter()
+                    .filter(|v| !peeling.removed[**v])
+                    .map(|v| vertices[*v])
+                    .collect();
+                (id, members)
+            })
+            .collect();
+        HyperCore {
+            vertices: (0..vertices.len())
+                .filter(|v| !peeling.removed[*v])
+                .map(|v| vertices[v])
+                .collect(),
+            edges,
+        }
+    }
+}
+
+/// the vertices of a hypergraph alongside the indices of its edges and their members, given
+/// as positions among the former
+type Indexed<Ix> = (Vec<VertexId<Ix>>, Vec<EdgeId<Ix>>, Vec<Vec<usize>>);
+
+/// the state of a peeling, tracking the surviving vertices and edges
+struct Peeling {
+    /// the members of each edge
+    edges: Vec<Vec<usize>>,
+    /// the edges containing each vertex
+    incidence: Vec<Vec<usize>>,
+    /// the number of members each edge requires to survive
+    need: Vec<usize>,
+    /// the number of surviving members of each edge
+    alive: Vec<usize>,
+    /// flags the surviving edges
+    live: Vec<bool>,
+    /// the number of surviving edges containing each vertex
+    degree: Vec<usize>,
+    /// flags the removed vertices
+    removed: Vec<bool>,
+}
+
+impl Peeling {
+    /// initialize the peeling of the given edges; without an `m`, edges are removed alongside
+    /// any of their members
+    fn new(order: usize, edges: Vec<Vec<usize>>, m: Option<usize>) -> Self {
+        let need = edges
+            .iter()
+            .map(|e| m.map_or(e.len(), |m| m.max(1)))
+            .collect::<Vec<_>>();
+        let alive = edges.iter().map(Vec::len).collect::<Vec<_>>();
+        let live = alive
+            .iter()
+            .zip(&need)
+            .map(|(alive, need)| alive >= need)
+            .collect::<Vec<_>>();
+        let mut incidence = alloc::vec![Vec::new(); order];
+        let mut degree = alloc::vec![0; order];
+        for (i, edge) in edges.iter().enumerate() {
+            for &v in edge {
+                incidence[v].push(i);
+                degree[v] += live[i] as usize;
+            }
+        }
+        Self {
+            edges,
+            incidence,
+            need,
+            alive,
+            live,
+            degree,
+            removed: alloc::vec![false; order],
+        }
+    }
+    /// removes the given vertex, returning the surviving vertices whose degree decreased
+    fn remove(&mut self, vertex: usize) -> Vec<usize> {
+        self.removed[vertex] = true;
+        let mut touched = Vec::new();
+        for &e in &self.incidence[vertex] {
+            if !self.live[e] {
+                continue;
+            }
+            self.alive[e] -= 1;
+            if self.alive[e] >= self.need[e] {
+                continue;
+            }
+            self.live[e] = false;
+            for &u in &self.edges[e] {
+                if !self.removed[u] {
+                    self.degree[u] -= 1;
+                    touched.push(u);
+                }
+            }
+        }
+        touched
+    }
+}
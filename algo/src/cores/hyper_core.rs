@@ -0,0 +1,72 @@
+/*
+    appellation: hyper_core <module>
+    authors: @FL03
+*/
+//! this module implements the [`HyperCore`], a sub-hypergraph remaining after peeling
+use hashbrown::{HashMap, HashSet};
+use rshyper::idx::{EdgeId, RawIndex, Udx, VertexId};
+
+/// A [`HyperCore`] describes the sub-hypergraph remaining after peeling a hypergraph, composed
+/// of the surviving vertices alongside the surviving edges, each restricted to its surviving
+/// members.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct HyperCore<Ix = Udx>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// the vertices of the core
+    pub(crate) vertices: HashSet<VertexId<Ix>>,
+    /// maps each edge of the core onto its surviving members
+    pub(crate) edges: HashMap<EdgeId<Ix>, HashSet<VertexId<Ix>>>,
+}
+
+impl<Ix> HyperCore<Ix>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// returns a new, empty core
+    pub fn new() -> Self {
+        Self {
+            vertices: HashSet::new(),
+            edges: HashMap::new(),
+        }
+    }
+    /// returns an immutable reference to the vertices of the core
+    pub const fn vertices(&self) -> &HashSet<VertexId<Ix>> {
+        &self.vertices
+    }
+    /// returns an immutable reference to the map associating each edge of the core with its
+    /// surviving members
+    pub const fn edges(&self) -> &HashMap<EdgeId<Ix>, HashSet<VertexId<Ix>>> {
+        &self.edges
+    }
+    /// returns the surviving members of the given edge, if it belongs to the core
+    pub fn get_edge_domain(&self, index: &EdgeId<Ix>) -> Option<&HashSet<VertexId<Ix>>> {
+        self.edges.get(index)
+    }
+    /// returns true if the core contains the given edge
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.edges.contains_key(index)
+    }
+    /// returns true if the core contains the given vertex
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.vertices.contains(index)
+    }
+    /// returns true if the core contains no vertices
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+    /// returns the number of vertices within the core
+    pub fn order(&self) -> usize {
+        self.vertices.len()
+    }
+    /// returns the number of edges within the core
+    pub fn size(&self) -> usize {
+        self.edges.len()
+    }
+}
@@ -0,0 +1,16 @@
+/*
+    appellation: cores <module>
+    authors: @FL03
+*/
+//! this module implements the k-core and (k, m)-core decompositions of hypergraphs
+#[doc(inline)]
+pub use self::{core_decomposition::*, hyper_core::*};
+
+pub mod core_decomposition;
+pub mod hyper_core;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::core_decomposition::CoreDecomposition;
+    pub use super::hyper_core::HyperCore;
+}
@@ -0,0 +1,507 @@
+/*
+    appellation: decomposer <module>
+    authors: @FL03
+*/
+//! this module implements the [`TreeDecomposer`] operator, computing tree decompositions and
+//! generalized hypertree decompositions of hypergraphs
+use super::{Bag, TreeDecomposition};
+use crate::acyclic::acyclicity::gyo_reduction;
+use crate::error::Result;
+use crate::utils::{EdgeSet, collect_edges};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
+use rshyper::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIterEdge, RawLayout};
+
+/// the default number of vertices up to which exact decompositions are computed
+pub const DEFAULT_EXACT_LIMIT: usize = 16;
+/// the largest number of vertices for which exact decompositions may be computed
+pub const MAX_EXACT_LIMIT: usize = 24;
+
+/// The [`TreeDecomposer`] computes [`TreeDecomposition`]s of the primal graph of a hypergraph,
+/// i.e. the graph connecting every pair of vertices sharing some edge, annotating each of the
+/// bags with the edges covering it.
+///
+/// Heuristic decompositions are computed using a _min-fill_ elimination ordering, while
+/// [generalized hypertree decompositions](TreeDecomposer::generalized_hypertree_decomposition)
+/// of bounded width are found by first checking for alpha-acyclicity, whose join trees have a
+/// width of one, before falling back onto the heuristic and, for hypergraphs with at most
+/// [`exact_limit`](TreeDecomposer::exact_limit) vertices, an exact search over the elimination
+/// orderings of the vertices. Vertices contained by no edges are ignored.
+pub struct TreeDecomposer<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIterEdge<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) exact_limit: usize,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> TreeDecomposer<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIterEdge<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            exact_limit: DEFAULT_EXACT_LIMIT,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns the number of vertices up to which exact decompositions are computed
+    pub const fn exact_limit(&self) -> usize {
+        self.exact_limit
+    }
+    /// consumes the current instance to create another using the given limit for exact
+    /// decompositions, which is capped at [`MAX_EXACT_LIMIT`]
+    pub fn with_exact_limit(self, limit: usize) -> Self {
+        Self {
+            exact_limit: limit.min(MAX_EXACT_LIMIT),
+            ..self
+        }
+    }
+    /// returns a heuristic tree decomposition of the hypergraph using a min-fill elimination
+    /// ordering of its vertices
+    pub fn tree_decomposition(&self) -> TreeDecomposition<A::Ix> {
+        let edges = collect_edges(self.graph);
+        let order = min_fill_order(&edges);
+        decompose(&edges, &order)
+    }
+    /// returns the tree decomposition of the hypergraph induced by eliminating its vertices in
+    /// the given order; any vertices missing from the order are eliminated last, in the order
+    /// of their indices.
+    pub fn tree_decomposition_from_order<I>(&self, order: I) -> Result<TreeDecomposition<A::Ix>>
+    where
+        I: IntoIterator<Item = VertexId<A::Ix>>,
+    {
+        let edges = collect_edges(self.graph);
+        let vertices = sorted_vertices(&edges);
+        let mut seen = HashSet::new();
+        let mut elimination = Vec::with_capacity(vertices.len());
+        for v in order {
+            if !self.graph.contains_node(&v) {
+                return Err(rshyper::Error::NodeNotFound.into());
+            }
+            if vertices.binary_search(&v).is_ok() && seen.insert(v) {
+                elimination.push(v);
+            }
+        }
+        elimination.extend(vertices.into_iter().filter(|v| !seen.contains(v)));
+        Ok(decompose(&edges, &elimination))
+    }
+    /// returns a generalized hypertree decomposition of the hypergraph whose bags are each
+    /// covered by, at most, `max_width` edges; [`None`] is returned when no such decomposition
+    /// could be found.
+    ///
+    /// **Note:** the search is only exhaustive for alpha-acyclic hypergraphs and those with,
+    /// at most, [`exact_limit`](TreeDecomposer::exact_limit) vertices; otherwise, [`None`]
+    /// only indicates that the heuristic failed to find a decomposition of the given width.
+    pub fn generalized_hypertree_decomposition(
+        &self,
+        max_width: usize,
+    ) -> Option<TreeDecomposition<A::Ix>> {
+        let edges = collect_edges(self.graph);
+        let vertices = sorted_vertices(&edges);
+        if vertices.is_empty() {
+            return Some(TreeDecomposition::new());
+        }
+        if max_width == 0 {
+            return None;
+        }
+        // alpha-acyclic hypergraphs are decomposed by their join trees
+        if let Ok(tree) = gyo_reduction(edges.clone()) {
+            return Some(from_join_tree(&edges, tree.order(), tree.parents()));
+        }
+        let heuristic = decompose(&edges, &min_fill_order(&edges));
+        if heuristic.cover_width() <= max_width {
+            return Some(heuristic);
+        }
+        if vertices.len() > self.exact_limit {
+            return None;
+        }
+        exact_order(&edges, &vertices, max_width).map(|order| decompose(&edges, &order))
+    }
+    /// returns the generalized hypertree width of the hypergraph, i.e. the least width of any
+    /// generalized hypertree decomposition; [`None`] is returned for cyclic hypergraphs with
+    /// more than [`exact_limit`](TreeDecomposer::exact_limit) vertices.
+    pub fn generalized_hypertree_width(&self) -> Option<usize> {
+        let edges = collect_edges(self.graph);
+        let vertices = sorted_vertices(&edges);
+        if vertices.is_empty() {
+            return Some(0);
+        }
+        if gyo_reduction(edges.clone()).is_ok() {
+            return Some(1);
+        }
+        if vertices.len() > self.exact_limit {
+            return None;
+        }
+        let upper = decompose(&edges, &min_fill_order(&edges)).cover_width();
+        (2..upper)
+            .find(|&k| exact_order(&edges, &vertices, k).is_some())
+            .or(Some(upper))
+    }
+}
+
+/// returns the distinct vertices contained by the given edges, ordered by their index
+fn sorted_vertices<Ix>(edges: &[EdgeSet<Ix>]) -> Vec<VertexId<Ix>>
+where
+    Ix: HyperIndex,
+{
+    let mut vertices = edges
+        .iter()
+        .flat_map(|(_, domain)| domain)
+        .copied()
+        .collect::<Vec<_>>();
+    vertices.sort();
+    vertices.dedup();
+    vertices
+}
+
+/// returns the adjacency of the primal graph of the given edges
+fn primal<Ix>(edges: &[EdgeSet<Ix>]) -> HashMap<VertexId<Ix>, HashSet<VertexId<Ix>>>
+where
+    Ix: HyperIndex,
+{
+    let mut adj = HashMap::<VertexId<Ix>, HashSet<VertexId<Ix>>>::new();
+    for (_, domain) in edges {
+        for v in domain {
+            let neighbors = adj.entry(*v).or_default();
+            neighbors.extend(domain.iter().filter(|u| *u != v));
+        }
+    }
+    adj
+}
+
+/// computes an elimination ordering of the vertices, repeatedly eliminating the vertex whose
+/// elimination introduces the fewest fill edges, breaking ties by degree and then by index
+fn min_fill_order<Ix>(edges: &[EdgeSet<Ix>]) -> Vec<VertexId<Ix>>
+where
+    Ix: HyperIndex,
+{
+    let mut adj = primal(edges);
+    let mut remaining = sorted_vertices(edges);
+    let mut order = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let fill = |v: &VertexId<Ix>| {
+            let neighbors = adj[v].iter().collect::<Vec<_>>();
+            let mut missing = 0;
+            for (i, a) in neighbors.iter().enumerate() {
+                missing += neighbors[i + 1..]
+                    .iter()
+                    .filter(|b| !adj[*a].contains(**b))
+                    .count();
+            }
+            (missing, neighbors.len(), *v)
+        };
+        let (pos, _) = remaining
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, v)| fill(v))
+            .expect("the remaining vertices are non-empty");
+        let v = remaining.remove(pos);
+        // connect the neighbors of the vertex before eliminating it
+        let neighbors = adj.remove(&v).unwrap_or_default();
+        for u in &neighbors {
+            let entry = adj.get_mut(u).expect("neighbors are present");
+            entry.remove(&v);
+            entry.extend(neighbors.iter().filter(|w| *w != u));
+        }
+        order.push(v);
+    }
+    order
+}
+
+/// computes the tree decomposition induced by eliminating the vertices in the given order,
+/// removing any bag contained by its parent and covering each of the remaining bags
+fn decompose<Ix>(edges: &[EdgeSet<Ix>], order: &[VertexId<Ix>]) -> TreeDecomposition<Ix>
+where
+    Ix: HyperIndex,
+{
+    let pos = order
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (*v, i))
+        .collect::<HashMap<_, _>>();
+    let mut adj = primal(edges);
+    let mut bags = Vec::<HashSet<VertexId<Ix>>>::with_capacity(order.len());
+    let mut parents = Vec::<Option<usize>>::with_capacity(order.len());
+    for (i, v) in order.iter().enumerate() {
+        let higher = adj
+            .get(v)
+            .into_iter()
+            .flatten()
+            .filter(|u| pos[*u] > i)
+            .copied()
+            .collect::<Vec<_>>();
+        // the remaining neighbors of the vertex form a clique once it has been eliminated
+        for a in &higher {
+            let entry = adj.entry(*a).or_default();
+            entry.extend(higher.iter().filter(|b| *b != a));
+        }
+        parents.push(higher.iter().map(|u| pos[u]).min());
+        bags.push(higher.into_iter().chain([*v]).collect());
+    }
+    // remove the bags contained by their parent, moving their children onto the parent
+    let mut keep = alloc::vec![true; bags.len()];
+    for i in 0..bags.len() {
+        if let Some(p) = parents[i] {
+            if bags[i].is_subset(&bags[p]) {
+                keep[i] = false;
+                parents[..i]
+                    .iter_mut()
+                    .filter(|q| **q == Some(i))
+                    .for_each(|q| *q = Some(p));
+            }
+        }
+    }
+    // join the roots of each component onto the last of them
+    let root = (0..bags.len()).rev().find(|&i| parents[i].is_none());
+    for (i, parent) in parents.iter_mut().enumerate() {
+        if parent.is_none() && Some(i) != root {
+            *parent = root;
+        }
+    }
+    // compact the positions of the remaining bags
+    let positions = keep
+        .iter()
+        .scan(0, |next, &kept| {
+            let pos = *next;
+            *next += kept as usize;
+            Some(pos)
+        })
+        .collect::<Vec<_>>();
+    let bags = bags
+        .into_iter()
+        .zip(parents)
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|((vertices, parent), _)| Bag {
+            cover: cover(&vertices, edges),
+            vertices,
+            parent: parent.map(|p| positions[p]),
+        })
+        .collect();
+    TreeDecomposition { bags }
+}
+
+/// builds the decomposition whose bags are the edges of the hypergraph, arranged according to
+/// the given join tree
+fn from_join_tree<Ix>(
+    edges: &[EdgeSet<Ix>],
+    order: &[EdgeId<Ix>],
+    parents: &HashMap<EdgeId<Ix>, EdgeId<Ix>>,
+) -> TreeDecomposition<Ix>
+where
+    Ix: HyperIndex,
+{
+    let pos = order
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (*e, i))
+        .collect::<HashMap<_, _>>();
+    let domains = edges.iter().cloned().collect::<HashMap<_, _>>();
+    let bags = order
+        .iter()
+        .map(|e| Bag {
+            vertices: domains[e].clone(),
+            cover: alloc::vec![*e],
+            parent: parents.get(e).map(|p| pos[p]),
+        })
+        .collect();
+    TreeDecomposition { bags }
+}
+
+/// returns a minimum set of edges covering the given vertices, falling back onto a greedy
+/// cover should the search fail to improve upon it
+fn cover<Ix>(bag: &HashSet<VertexId<Ix>>, edges: &[EdgeSet<Ix>]) -> Vec<EdgeId<Ix>>
+where
+    Ix: HyperIndex,
+{
+    // restrict the edges to the vertices of the bag
+    let candidates = edges
+        .iter()
+        .map(|(id, domain)| {
+            (
+                *id,
+                domain.intersection(bag).copied().collect::<HashSet<_>>(),
+            )
+        })
+        .filter(|(_, domain)| !domain.is_empty())
+        .collect::<Vec<_>>();
+    let greedy = greedy_cover(bag, &candidates);
+    (1..greedy.len())
+        .find_map(|limit| exact_cover(bag.clone(), &candidates, &mut Vec::new(), limit))
+        .unwrap_or(greedy)
+}
+
+/// returns a set of edges covering the given vertices, repeatedly selecting the edge covering
+/// the most uncovered vertices
+fn greedy_cover<Ix>(bag: &HashSet<VertexId<Ix>>, candidates: &[EdgeSet<Ix>]) -> Vec<EdgeId<Ix>>
+where
+    Ix: HyperIndex,
+{
+    let mut uncovered = bag.clone();
+    let mut cover = Vec::new();
+    while !uncovered.is_empty() {
+        let Some((id, domain)) = candidates
+            .iter()
+            .max_by_key(|(id, domain)| {
+                (
+                    domain.intersection(&uncovered).count(),
+                    core::cmp::Reverse(*id),
+                )
+            })
+            .filter(|(_, domain)| !domain.is_disjoint(&uncovered))
+        else {
+            break;
+        };
+        uncovered.retain(|v| !domain.contains(v));
+        cover.push(*id);
+    }
+    cover
+}
+
+/// searches for a set of, at most, `limit` edges covering the given vertices
+fn exact_cover<Ix>(
+    uncovered: HashSet<VertexId<Ix>>,
+    candidates: &[EdgeSet<Ix>],
+    chosen: &mut Vec<EdgeId<Ix>>,
+    limit: usize,
+) -> Option<Vec<EdgeId<Ix>>>
+where
+    Ix: HyperIndex,
+{
+    if uncovered.is_empty() {
+        return Some(chosen.clone());
+    }
+    if chosen.len() == limit {
+        return None;
+    }
+    // branch on the uncovered vertex contained by the fewest edges
+    let count = |v: &VertexId<Ix>| candidates.iter().filter(|(_, d)| d.contains(v)).count();
+    let v = *uncovered.iter().min_by_key(|v| (count(v), **v))?;
+    for (id, domain) in candidates.iter().filter(|(_, d)| d.contains(&v)) {
+        chosen.push(*id);
+        let rest = uncovered
+            .iter()
+            .filter(|u| !domain.contains(*u))
+            .copied()
+            .collect();
+        if let Some(cover) = exact_cover(rest, candidates, chosen, limit) {
+            return Some(cover);
+        }
+        chosen.pop();
+    }
+    None
+}
+
+/// searches for an elimination ordering of the vertices whose bags may each be covered by,
+/// at most, `k` edges using dynamic programming over the subsets of eliminated vertices
+fn exact_order<Ix>(
+    edges: &[EdgeSet<Ix>],
+    vertices: &[VertexId<Ix>],
+    k: usize,
+) -> Option<Vec<VertexId<Ix>>>
+where
+    Ix: HyperIndex,
+{
+    let n = vertices.len();
+    let bit = |v: &VertexId<Ix>| 1u32 << vertices.binary_search(v).expect("vertex is present");
+    let masks = edges
+        .iter()
+        .map(|(_, domain)| domain.iter().map(bit).fold(0, |acc, b| acc | b))
+        .collect::<Vec<u32>>();
+    let mut adj = alloc::vec![0u32; n];
+    for mask in &masks {
+        for (i, row) in adj.iter_mut().enumerate() {
+            if mask & (1 << i) != 0 {
+                *row |= mask & !(1 << i);
+            }
+        }
+    }
+    let full = if n == 32 { u32::MAX } else { (1u32 << n) - 1 };
+    // the vertex eliminated last to reach each subset, if it may be reached at all
+    let mut last = alloc::vec![u8::MAX; 1 << n];
+    let mut reachable = alloc::vec![false; 1 << n];
+    reachable[0] = true;
+    let mut covers = HashMap::<u32, bool>::new();
+    for set in 0..=full {
+        if !reachable[set as usize] {
+            continue;
+        }
+        for v in (0..n).filter(|v| set & (1 << v) == 0) {
+            let next = set | (1 << v);
+            if reachable[next as usize] {
+                continue;
+            }
+            // the bag of the vertex contains those reachable through the eliminated vertices
+            let bag = (1 << v) | frontier(&adj, set, v);
+            let coverable = *covers
+                .entry(bag)
+                .or_insert_with(|| covers_within(bag, &masks, k));
+            if coverable {
+                reachable[next as usize] = true;
+                last[next as usize] = v as u8;
+            }
+        }
+    }
+    if !reachable[full as usize] {
+        return None;
+    }
+    let mut order = Vec::with_capacity(n);
+    let mut set = full;
+    while set != 0 {
+        let v = last[set as usize] as usize;
+        order.push(vertices[v]);
+        set &= !(1 << v);
+    }
+    order.reverse();
+    Some(order)
+}
+
+/// returns the vertices outside of the eliminated set reachable from the given vertex using
+/// paths whose internal vertices have all been eliminated
+fn frontier(adj: &[u32], eliminated: u32, vertex: usize) -> u32 {
+    let mut visited = 1u32 << vertex;
+    let mut stack = alloc::vec![vertex];
+    let mut res = 0;
+    while let Some(u) = stack.pop() {
+        let mut next = adj[u] & !visited;
+        visited |= next;
+        while next != 0 {
+            let w = next.trailing_zeros() as usize;
+            next &= next - 1;
+            if eliminated & (1 << w) != 0 {
+                stack.push(w);
+            } else {
+                res |= 1 << w;
+            }
+        }
+    }
+    res
+}
+
+/// returns true if the given set of vertices may be covered by, at most, `k` of the edges
+fn covers_within(bag: u32, masks: &[u32], k: usize) -> bool {
+    if bag == 0 {
+        return true;
+    }
+    if k == 0 {
+        return false;
+    }
+    let v = bag.trailing_zeros();
+    masks
+        .iter()
+        .filter(|m| *m & (1 << v) != 0)
+        .any(|m| covers_within(bag & !m, masks, k - 1))
+}
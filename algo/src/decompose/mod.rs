@@ -0,0 +1,17 @@
+/*
+    appellation: decompose <module>
+    authors: @FL03
+*/
+//! this module implements tree decompositions of the primal graph of a hypergraph alongside
+//! generalized hypertree decompositions of bounded width
+#[doc(inline)]
+pub use self::{decomposer::*, tree_decomposition::*};
+
+pub mod decomposer;
+pub mod tree_decomposition;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::decomposer::TreeDecomposer;
+    pub use super::tree_decomposition::{Bag, TreeDecomposition};
+}
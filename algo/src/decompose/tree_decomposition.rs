@@ -0,0 +1,127 @@
+/*
+    appellation: tree_decomposition <module>
+    authors: @FL03
+*/
+//! this module implements the [`TreeDecomposition`] of a hypergraph alongside its [`Bag`]s
+use alloc::vec::Vec;
+use hashbrown::HashSet;
+use rshyper::idx::{EdgeId, RawIndex, Udx, VertexId};
+
+/// A [`Bag`] is a node of a [`TreeDecomposition`], composed of a set of vertices alongside
+/// the edges of the hypergraph _covering_ them, i.e. whose union contains each of the
+/// vertices within the bag.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bag<Ix = Udx>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// the vertices contained by the bag
+    pub(crate) vertices: HashSet<VertexId<Ix>>,
+    /// the edges whose union covers the vertices of the bag
+    pub(crate) cover: Vec<EdgeId<Ix>>,
+    /// the position of the parent of the bag within the decomposition, if any
+    pub(crate) parent: Option<usize>,
+}
+
+impl<Ix> Bag<Ix>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// returns an immutable reference to the vertices of the bag
+    pub const fn vertices(&self) -> &HashSet<VertexId<Ix>> {
+        &self.vertices
+    }
+    /// returns the edges covering the vertices of the bag
+    pub fn cover(&self) -> &[EdgeId<Ix>] {
+        &self.cover
+    }
+    /// returns the position of the parent of the bag, if any
+    pub const fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+    /// returns true if the bag contains the given vertex
+    pub fn contains(&self, vertex: &VertexId<Ix>) -> bool {
+        self.vertices.contains(vertex)
+    }
+    /// returns the number of vertices within the bag
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+    /// returns true if the bag contains no vertices
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+}
+
+/// A [`TreeDecomposition`] arranges the vertices of a hypergraph into a tree of [`Bag`]s such
+/// that the vertices of every edge are contained by some bag, and the bags containing any
+/// vertex form a connected subtree. Each of the bags is annotated with the edges covering it,
+/// making the decomposition a _generalized hypertree decomposition_ as well.
+///
+/// The bags are stored in a bottom-up order, where each bag precedes its parent and the root
+/// comes last. The [`width`](TreeDecomposition::width) of the decomposition, the size of its
+/// largest bag less one, bounds the treewidth of the primal graph, while its
+/// [`cover_width`](TreeDecomposition::cover_width), the size of its largest cover, bounds the
+/// generalized hypertree width of the hypergraph.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TreeDecomposition<Ix = Udx>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    pub(crate) bags: Vec<Bag<Ix>>,
+}
+
+impl<Ix> TreeDecomposition<Ix>
+where
+    Ix: RawIndex + Eq + core::hash::Hash,
+{
+    /// returns a new, empty decomposition
+    pub const fn new() -> Self {
+        Self { bags: Vec::new() }
+    }
+    /// returns the bags of the decomposition in a bottom-up order
+    pub fn bags(&self) -> &[Bag<Ix>] {
+        &self.bags
+    }
+    /// returns the bag at the given position, if any
+    pub fn get(&self, index: usize) -> Option<&Bag<Ix>> {
+        self.bags.get(index)
+    }
+    /// returns the position of the root of the decomposition, if any
+    pub fn root(&self) -> Option<usize> {
+        self.bags.iter().position(|bag| bag.parent.is_none())
+    }
+    /// returns the positions of the children of the bag at the given position
+    pub fn children(&self, index: usize) -> Vec<usize> {
+        self.bags
+            .iter()
+            .enumerate()
+            .filter(|(_, bag)| bag.parent == Some(index))
+            .map(|(i, _)| i)
+            .collect()
+    }
+    /// returns the number of bags within the decomposition
+    pub fn len(&self) -> usize {
+        self.bags.len()
+    }
+    /// returns true if the decomposition contains no bags
+    pub fn is_empty(&self) -> bool {
+        self.bags.is_empty()
+    }
+    /// returns the width of the decomposition, i.e. the size of its largest bag less one
+    pub fn width(&self) -> usize {
+        self.bags
+            .iter()
+            .map(|bag| bag.len().saturating_sub(1))
+            .max()
+            .unwrap_or_default()
+    }
+    /// returns the size of the largest cover among the bags of the decomposition
+    pub fn cover_width(&self) -> usize {
+        self.bags
+            .iter()
+            .map(|bag| bag.cover.len())
+            .max()
+            .unwrap_or_default()
+    }
+}
@@ -21,6 +21,10 @@ pub enum Error {
     NotFound(Box<dyn RawIndex>),
     #[error("No path found between the two points")]
     PathNotFound,
+    #[error("The hypergraph is not acyclic")]
+    NotAcyclic,
+    #[error("The hypergraph is not uniform")]
+    NotUniform,
     #[error(transparent)]
     CoreError(#[from] CoreError),
 }
@@ -2,10 +2,35 @@
 //!
 //! ## Features
 //!
+//! - [`acyclic`]: alpha-, beta-, gamma- and Berge-acyclicity tests alongside the construction
+//!   of join trees
 //! - [`astar`]: the A* search algorithm for hypergraphs
 //! - [`breadth_first`]: the breadth-first search algorithm for hypergraphs
+//! - [`coloring`]: weak and strong vertex colorings of hypergraphs alongside their maximal
+//!   and maximum independent sets
+//! - [`community`]: the modularity of vertex partitions alongside Louvain-style community
+//!   detection and streaming label propagation
+//! - [`cores`]: the k-core and (k, m)-core decompositions of hypergraphs
+//! - [`decompose`]: tree decompositions of the primal graph alongside generalized hypertree
+//!   decompositions of bounded width
 //! - [`depth_first`]: the depth-first search algorithm for hypergraphs
 //! - [`dijkstra`]: Dijkstra's algorithm for finding the shortest path in hypergraphs
+//! - [`matching`]: maximal, approximate maximum weight and perfect matchings of hypergraphs
+//! - [`multilayer_breadth_first`]: a layer-aware breadth-first traversal for multilayer
+//!   hypergraphs
+//! - [`nested_breadth_first`]: a breadth-first traversal descending into the edges of nested
+//!   hypergraphs
+//! - [`ordered_breadth_first`]: a breadth-first traversal following the vertex sequences of
+//!   ordered hypergraphs
+//! - [`oriented_breadth_first`]: a breadth-first traversal respecting the orientation of the
+//!   edges of hypergraphs whose directedness is decided at runtime
+//! - [`temporal_breadth_first`]: a time-respecting breadth-first traversal for temporal
+//!   hypergraphs
+//! - [`temporal_dijkstra`]: a time-respecting variant of Dijkstra's algorithm computing the
+//!   earliest arrival at each vertex of a temporal hypergraph
+//! - [`transversal`]: the lazy enumeration of the minimal transversals of hypergraphs alongside
+//!   greedy and exact minimum hitting set solvers
+//! - [`random_walk`](walk::random_walk): random walks honoring edge-dependent vertex weights
 //!
 #![crate_name = "rshyper_algo"]
 #![crate_type = "lib"]
@@ -39,9 +64,25 @@ mod macros {
     pub(crate) mod seal;
 }
 // modules
+#[cfg(feature = "alloc")]
+pub mod acyclic;
+#[cfg(feature = "alloc")]
+pub mod coloring;
+#[cfg(feature = "alloc")]
+pub mod community;
+#[cfg(feature = "alloc")]
+pub mod cores;
+#[cfg(feature = "alloc")]
+pub mod decompose;
 pub mod error;
 #[cfg(feature = "alloc")]
+pub mod matching;
+#[cfg(feature = "alloc")]
 pub mod search;
+#[cfg(feature = "alloc")]
+pub mod transversal;
+#[cfg(feature = "alloc")]
+pub mod walk;
 
 mod traits {
     #[doc(inline)]
@@ -59,11 +100,32 @@ mod types {
     mod priority_node;
     mod queue_node;
 }
+
+#[cfg(feature = "alloc")]
+pub(crate) mod utils;
 // re-exports
 #[cfg(feature = "alloc")]
+pub use self::acyclic::{Acyclicity, JoinTree};
+#[cfg(feature = "alloc")]
+pub use self::coloring::{Coloring, IndependentSets, VertexColoring};
+#[cfg(feature = "alloc")]
+pub use self::community::{Communities, LabelPropagation, LabelStream, Modularity, ModularityKind};
+#[cfg(feature = "alloc")]
+pub use self::cores::{CoreDecomposition, HyperCore};
+#[cfg(feature = "alloc")]
+pub use self::decompose::{Bag, TreeDecomposer, TreeDecomposition};
+#[cfg(feature = "alloc")]
+pub use self::matching::Matching;
+#[cfg(feature = "alloc")]
 pub use self::search::{
-    AStarSearch, BreadthFirstTraversal, DepthFirstTraversal, Dijkstra, Heuristic, Search,
+    AStarSearch, BreadthFirstTraversal, DepthFirstTraversal, Dijkstra, Heuristic,
+    MultiLayerBreadthFirst, NestedBreadthFirst, OrderedBreadthFirst, OrientedBreadthFirst, Search,
+    TemporalBreadthFirst, TemporalDijkstra,
 };
+#[cfg(feature = "alloc")]
+pub use self::transversal::{MinimalTransversals, Transversals};
+#[cfg(feature = "alloc")]
+pub use self::walk::RandomWalk;
 #[doc(inline)]
 pub use self::{error::*, traits::*, types::*};
 // prelude
@@ -72,6 +134,22 @@ pub mod prelude {
     pub use crate::traits::*;
     pub use crate::types::*;
 
+    #[cfg(feature = "alloc")]
+    pub use crate::acyclic::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::coloring::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::community::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::cores::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::decompose::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::matching::prelude::*;
     #[cfg(feature = "alloc")]
     pub use crate::search::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::transversal::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::walk::prelude::*;
 }
@@ -0,0 +1,245 @@
+/*
+    appellation: matching <module>
+    authors: @FL03
+*/
+//! this module implements the [`Matching`] operator, computing maximal, heavy and perfect
+//! matchings of hypergraphs
+use crate::error::{Error, Result};
+use crate::utils::{EdgeSet, collect_edges};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
+use num_traits::ToPrimitive;
+use rshyper::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIter, RawLayout};
+
+/// The [`Matching`] operator computes _matchings_ of a hypergraph, i.e. sets of pairwise
+/// disjoint edges. A matching is _maximal_ if no other edge may be added to it, and _perfect_
+/// if its edges cover every vertex of the hypergraph.
+///
+/// Weighted matchings use the weight of each edge as the objective, ignoring any edge whose
+/// weight is not positive. Since the problem is NP-hard for edges of three or more vertices,
+/// [`maximum_weight`](Matching::maximum_weight) approximates the optimum by greedily selecting
+/// the heaviest edges before improving the result through local search; for `k`-uniform
+/// hypergraphs, the greedy selection alone is within a factor of `k` of the optimum.
+///
+/// Each matching is returned as a list of edges ordered by their index.
+pub struct Matching<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> Matching<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns the number of vertices contained by each edge if the hypergraph is uniform;
+    /// [`None`] is returned for hypergraphs without edges or whose edges differ in size.
+    pub fn uniformity(&self) -> Option<usize> {
+        let edges = collect_edges(self.graph);
+        let (_, first) = edges.first()?;
+        let k = first.len();
+        edges
+            .iter()
+            .all(|(_, domain)| domain.len() == k)
+            .then_some(k)
+    }
+    /// returns a maximal matching of the hypergraph, greedily selecting the smallest edges,
+    /// breaking ties by index
+    pub fn maximal(&self) -> Vec<EdgeId<A::Ix>> {
+        let mut edges = collect_edges(self.graph);
+        edges.sort_by_key(|(id, domain)| (domain.len(), *id));
+        let mut res = extend(&edges, Vec::new(), HashSet::new());
+        res.sort();
+        res
+    }
+    /// returns a matching of the hypergraph approximately maximizing the total weight of its
+    /// edges; the heaviest edges are selected greedily before repeatedly inserting any edge
+    /// whose insertion, after removing the edges it conflicts with and greedily refilling the
+    /// freed vertices, increases the total weight.
+    pub fn maximum_weight(&self) -> Vec<EdgeId<A::Ix>>
+    where
+        E: ToPrimitive,
+    {
+        let mut edges = collect_edges(self.graph)
+            .into_iter()
+            .filter_map(|(id, domain)| {
+                let weight = self.graph.get_edge_weight(&id).ok()?.to_f64()?;
+                (weight > 0.0).then_some((id, domain, weight))
+            })
+            .collect::<Vec<_>>();
+        // visit the heaviest edges first, breaking ties by index
+        edges.sort_by(|(a, _, x), (b, _, y)| y.total_cmp(x).then(a.cmp(b)));
+        let weights = edges
+            .iter()
+            .map(|(id, _, w)| (*id, *w))
+            .collect::<HashMap<_, _>>();
+        let edges = edges
+            .into_iter()
+            .map(|(id, domain, _)| (id, domain))
+            .collect::<Vec<_>>();
+        let domains = edges.iter().cloned().collect::<HashMap<_, _>>();
+        let total = |matching: &[EdgeId<A::Ix>]| matching.iter().map(|e| weights[e]).sum::<f64>();
+        let mut matched = extend(&edges, Vec::new(), HashSet::new());
+        let mut best = total(&matched);
+        while let Some(candidate) = edges
+            .iter()
+            .filter(|(id, _)| !matched.contains(id))
+            .map(|(id, domain)| {
+                let mut candidate = matched
+                    .iter()
+                    .filter(|e| domains[*e].is_disjoint(domain))
+                    .copied()
+                    .collect::<Vec<_>>();
+                candidate.push(*id);
+                let covered = candidate
+                    .iter()
+                    .flat_map(|e| &domains[e])
+                    .copied()
+                    .collect();
+                extend(&edges, candidate, covered)
+            })
+            .find(|candidate| total(candidate) > best * (1.0 + f64::EPSILON))
+        {
+            best = total(&candidate);
+            matched = candidate;
+        }
+        matched.sort();
+        matched
+    }
+    /// returns true if the given edges exist and are pairwise disjoint
+    pub fn is_matching(&self, matching: &[EdgeId<A::Ix>]) -> bool {
+        self.covered(matching).is_some()
+    }
+    /// returns true if the given edges are pairwise disjoint and cover every vertex of the
+    /// hypergraph
+    pub fn is_perfect_matching(&self, matching: &[EdgeId<A::Ix>]) -> bool {
+        self.covered(matching)
+            .is_some_and(|covered| self.graph.vertices().all(|v| covered.contains(v)))
+    }
+    /// searches for a perfect matching of a `k`-uniform hypergraph, branching on the edges
+    /// containing the uncovered vertex with the fewest options; a
+    /// [`NotUniform`](Error::NotUniform) error is returned for hypergraphs that are not
+    /// uniform, while [`None`] indicates that no perfect matching exists.
+    ///
+    /// **Note:** deciding whether a perfect matching exists is NP-complete for `k >= 3`,
+    /// making the search exponential in the worst case.
+    pub fn perfect_matching(&self) -> Result<Option<Vec<EdgeId<A::Ix>>>> {
+        let mut vertices = self.graph.vertices().copied().collect::<Vec<_>>();
+        vertices.sort();
+        if vertices.is_empty() {
+            return Ok(Some(Vec::new()));
+        }
+        let edges = collect_edges(self.graph);
+        if edges.is_empty() {
+            return Ok(None);
+        }
+        let k = self.uniformity().ok_or(Error::NotUniform)?;
+        if k == 0 || vertices.len() % k != 0 {
+            return Ok(None);
+        }
+        let mut chosen = Vec::new();
+        let mut covered = HashSet::new();
+        if !search(&vertices, &edges, &mut covered, &mut chosen) {
+            return Ok(None);
+        }
+        chosen.sort();
+        Ok(Some(chosen))
+    }
+    /// returns the vertices covered by the given edges, or [`None`] if any of them is missing
+    /// or they are not pairwise disjoint
+    fn covered(&self, matching: &[EdgeId<A::Ix>]) -> Option<HashSet<VertexId<A::Ix>>> {
+        let mut covered = HashSet::new();
+        for id in matching {
+            let domain = self.graph.get_edge_domain(id).ok()?;
+            for v in domain {
+                if !covered.insert(*v) {
+                    return None;
+                }
+            }
+        }
+        Some(covered)
+    }
+}
+
+/// extends the matching with the given edges, in order, that are disjoint from the covered
+/// vertices
+fn extend<Ix>(
+    edges: &[EdgeSet<Ix>],
+    mut matched: Vec<EdgeId<Ix>>,
+    mut covered: HashSet<VertexId<Ix>>,
+) -> Vec<EdgeId<Ix>>
+where
+    Ix: HyperIndex,
+{
+    for (id, domain) in edges {
+        if !matched.contains(id) && domain.is_disjoint(&covered) {
+            covered.extend(domain.iter().copied());
+            matched.push(*id);
+        }
+    }
+    matched
+}
+
+/// extends the chosen edges towards a perfect matching, returning true upon success
+fn search<Ix>(
+    vertices: &[VertexId<Ix>],
+    edges: &[EdgeSet<Ix>],
+    covered: &mut HashSet<VertexId<Ix>>,
+    chosen: &mut Vec<EdgeId<Ix>>,
+) -> bool
+where
+    Ix: HyperIndex,
+{
+    let Some(v) = vertices
+        .iter()
+        .filter(|v| !covered.contains(*v))
+        .min_by_key(|v| options(edges, covered, v).count())
+    else {
+        return true;
+    };
+    let candidates = options(edges, covered, v).cloned().collect::<Vec<_>>();
+    for (id, domain) in candidates {
+        covered.extend(domain.iter().copied());
+        chosen.push(id);
+        if search(vertices, edges, covered, chosen) {
+            return true;
+        }
+        chosen.pop();
+        domain.iter().for_each(|u| {
+            covered.remove(u);
+        });
+    }
+    false
+}
+
+/// returns the edges containing the given vertex that are disjoint from the covered vertices
+fn options<'a, Ix>(
+    edges: &'a [EdgeSet<Ix>],
+    covered: &'a HashSet<VertexId<Ix>>,
+    vertex: &'a VertexId<Ix>,
+) -> impl Iterator<Item = &'a EdgeSet<Ix>>
+where
+    Ix: HyperIndex,
+{
+    edges
+        .iter()
+        .filter(move |(_, d)| d.contains(vertex) && d.is_disjoint(covered))
+}
@@ -0,0 +1,15 @@
+/*
+    appellation: matching <module>
+    authors: @FL03
+*/
+//! this module implements the computation of matchings, i.e. sets of pairwise disjoint edges,
+//! within hypergraphs
+#[doc(inline)]
+pub use self::matching::*;
+
+pub mod matching;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::matching::Matching;
+}
@@ -10,13 +10,14 @@ use crate::search::Search;
 use crate::traits::{PathFinder, Traversal};
 use crate::types::QueueNode;
 use alloc::collections::BinaryHeap;
+use core::cmp::Ordering;
 use core::hash::{BuildHasher, Hash};
 use hashbrown::HashSet;
 use num_traits::bounds::UpperBounded;
 use num_traits::{FromPrimitive, Num};
-use rshyper::idx::{HyperIndex, RawIndex, VertexId, VertexSet};
+use rshyper::idx::{EdgeId, HyperIndex, RawIndex, VertexId, VertexSet};
 use rshyper::rel::RawLayout;
-use rshyper::{GraphProps, HyperGraph, HyperGraphIter};
+use rshyper::{GraphProps, HyperGraph, HyperGraphIter, WeightedIncidence};
 
 impl<'a, N, E, A, H, S> Dijkstra<'a, N, E, A, H, S>
 where
@@ -144,7 +145,7 @@ where
     }
 }
 
-impl<'a, N, E, A, H, S> PathFinder<A::Ix> for Dijkstra<'a, N, E, A, H, S>
+impl<'a, N, E, A, H, S> Dijkstra<'a, N, E, A, H, S>
 where
     E: Copy + Default + PartialOrd + FromPrimitive + Num + UpperBounded,
     A: GraphProps,
@@ -153,9 +154,18 @@ where
     A::Ix: HyperIndex,
     <H::Edge<E> as RawLayout>::Store: Clone + IntoIterator<Item = VertexId<A::Ix>>,
 {
-    type Path = Vec<VertexId<A::Ix>>;
-
-    fn find_path(&mut self, src: VertexId<A::Ix>, dest: VertexId<A::Ix>) -> Result<Self::Path> {
+    /// find the shortest path from `src` to `dest` using the given cost function, which
+    /// computes the cost of moving between two vertices, `(edge, from, to)`, through the edge
+    /// with the given index; each cost is expected to be non-negative.
+    pub fn find_path_with<F>(
+        &mut self,
+        src: VertexId<A::Ix>,
+        dest: VertexId<A::Ix>,
+        mut cost: F,
+    ) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        F: FnMut(&EdgeId<A::Ix>, &VertexId<A::Ix>, &VertexId<A::Ix>) -> Result<E>,
+    {
         self.reset();
 
         if !self.graph.contains_node(&src) {
@@ -165,14 +175,14 @@ where
             return Err(rshyper::Error::NodeNotFound.into());
         }
 
-        let mut heap: BinaryHeap<QueueNode<A::Ix, E>> = BinaryHeap::new();
+        let mut heap: BinaryHeap<Frontier<A::Ix, E>> = BinaryHeap::new();
         self.add_distance(src, E::zero());
-        heap.push(QueueNode::from_vertex(src));
+        heap.push(Frontier(QueueNode::from_vertex(src)));
 
-        while let Some(QueueNode {
+        while let Some(Frontier(QueueNode {
             vertex: u,
             cost: u_cost,
-        }) = heap.pop()
+        })) = heap.pop()
         {
             // Only mark as visited when popping from the heap
             if self.has_visited(&u) {
@@ -186,32 +196,62 @@ where
 
             // For each neighbor via hyperedges
             for edge_id in self.graph.find_edges_with_node(&u) {
-                // load the weight of the edge
-                let weight = self
-                    .graph
-                    .get_edge_weight(edge_id)
-                    .expect("no weight for the edge")
-                    .view();
                 // visit each node within the hyperedge
-                for v in self
-                    .graph
-                    .get_edge_domain(edge_id)
-                    .expect("empty hyperedge")
-                    .clone()
-                {
+                for v in self.graph.get_edge_domain(edge_id)?.clone() {
                     if v == u {
                         continue;
                     }
-                    let alt = u_cost + **weight;
+                    let alt = u_cost + cost(edge_id, &u, &v)?;
                     if alt < *self.distances().get(&v).unwrap_or(&E::max_value()) {
                         self.add_distance(v, alt).add_previous(v, u);
-                        heap.push(QueueNode::new(alt, v));
+                        heap.push(Frontier(QueueNode::new(alt, v)));
                     }
                 }
             }
         }
         Err(Error::PathNotFound)
     }
+    /// find the shortest path from `src` to `dest` honoring the edge-dependent weights of the
+    /// vertices, where the cost of entering a vertex through some edge is the weight of the
+    /// edge scaled by the weight of the vertex within that edge.
+    pub fn find_incidence_path(
+        &mut self,
+        src: VertexId<A::Ix>,
+        dest: VertexId<A::Ix>,
+    ) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        H: WeightedIncidence<N, E, A, Incidence = E>,
+    {
+        let graph = self.graph;
+        self.find_path_with(src, dest, |edge_id, _, v| {
+            let weight = graph.get_edge_weight(edge_id)?;
+            let incidence = graph.get_incidence_weight(edge_id, v)?;
+            Ok(**weight * **incidence)
+        })
+    }
+}
+
+impl<'a, N, E, A, H, S> PathFinder<A::Ix> for Dijkstra<'a, N, E, A, H, S>
+where
+    E: Copy + Default + PartialOrd + FromPrimitive + Num + UpperBounded,
+    A: GraphProps,
+    H: HyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+    <H::Edge<E> as RawLayout>::Store: Clone + IntoIterator<Item = VertexId<A::Ix>>,
+{
+    type Path = Vec<VertexId<A::Ix>>;
+
+    fn find_path(&mut self, src: VertexId<A::Ix>, dest: VertexId<A::Ix>) -> Result<Self::Path> {
+        let graph = self.graph;
+        // the cost of traversing an edge is simply its weight
+        self.find_path_with(src, dest, |edge_id, _, _| {
+            graph
+                .get_edge_weight(edge_id)
+                .map(|weight| **weight)
+                .map_err(Into::into)
+        })
+    }
 
     fn reconstruct_path(&self, mut goal: VertexId<A::Ix>) -> Vec<VertexId<A::Ix>> {
         // initialize a new path buffer
@@ -275,3 +315,47 @@ where
         Ok(path)
     }
 }
+
+/// an entry of the frontier explored by the [`Dijkstra`] search; the entries are compared
+/// solely through the (reversed) [`Ord`] implementation of the [`QueueNode`], ensuring that
+/// the [`BinaryHeap`] always yields the cheapest vertex first.
+struct Frontier<Ix, T>(QueueNode<Ix, T>)
+where
+    Ix: RawIndex;
+
+impl<Ix, T> PartialEq for Frontier<Ix, T>
+where
+    Ix: RawIndex + PartialEq,
+    T: PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Ix, T> Eq for Frontier<Ix, T>
+where
+    Ix: RawIndex + PartialEq,
+    T: PartialOrd,
+{
+}
+
+impl<Ix, T> PartialOrd for Frontier<Ix, T>
+where
+    Ix: RawIndex + PartialEq,
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Ix, T> Ord for Frontier<Ix, T>
+where
+    Ix: RawIndex + PartialEq,
+    T: PartialOrd,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
@@ -0,0 +1,203 @@
+/*
+    appellation: impl_multilayer_breadth_first <module>
+    authors: @FL03
+*/
+use crate::search::multilayer_breadth_first::MultiLayerBreadthFirst;
+
+use crate::error::{Error, Result};
+use crate::search::Search;
+use crate::traits::Traversal;
+use alloc::collections::VecDeque;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::HashSet;
+use rshyper::idx::{HyperIndex, LayerId, NodeLayer};
+use rshyper::{GraphProps, MultiLayerHyperGraph, RawLayout, VertexId};
+
+impl<'a, N, E, A, H, S> MultiLayerBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: MultiLayerHyperGraph<N, E, A>,
+    S: BuildHasher,
+{
+    /// create a new instance from the given multilayer hypergraph
+    pub fn new(graph: &'a H) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            graph,
+            layers: None,
+            queue: Default::default(),
+            previous: Default::default(),
+            visited: Default::default(),
+            _marker: core::marker::PhantomData::<(N, E)>,
+        }
+    }
+    /// consumes the current instance to create another whose traversal is restricted to the
+    /// given layers
+    pub fn within<I>(self, layers: I) -> Self
+    where
+        I: IntoIterator<Item = LayerId>,
+    {
+        Self {
+            layers: Some(layers.into_iter().collect()),
+            ..self
+        }
+    }
+    /// returns true if the traversal is permitted to enter the given layer
+    pub fn allows(&self, layer: &LayerId) -> bool {
+        self.graph.contains_layer(layer)
+            && self
+                .layers
+                .as_ref()
+                .is_none_or(|layers| layers.contains(layer))
+    }
+    /// returns an immutable reference to the queue
+    pub const fn queue(&self) -> &VecDeque<NodeLayer<A::Ix>> {
+        &self.queue
+    }
+    /// returns an immutable reference to the visited node-layers
+    pub const fn visited(&self) -> &HashSet<NodeLayer<A::Ix>, S> {
+        &self.visited
+    }
+    /// returns true if the node-layer has been visited
+    pub fn has_visited(&self, point: &NodeLayer<A::Ix>) -> bool
+    where
+        A::Ix: Eq + Hash,
+    {
+        self.visited().contains(point)
+    }
+    /// reset the traversal state to allow reusing the instance
+    pub fn reset(&mut self) -> &mut Self {
+        self.queue.clear();
+        self.previous.clear();
+        self.visited.clear();
+        self
+    }
+    /// a convience method to perform a search
+    pub fn search(&mut self, start: NodeLayer<A::Ix>) -> Result<Vec<NodeLayer<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+        for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+    {
+        Search::search(self, start)
+    }
+    /// find the path between two node-layers using the fewest number of steps, returning
+    /// each of the node-layers along the way
+    pub fn find_path(
+        &mut self,
+        src: NodeLayer<A::Ix>,
+        dest: NodeLayer<A::Ix>,
+    ) -> Result<Vec<NodeLayer<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+        for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+    {
+        self.explore(src, Some(dest))?;
+        if !self.has_visited(&dest) {
+            return Err(Error::PathNotFound);
+        }
+        Ok(self.reconstruct_path(dest))
+    }
+    /// reconstruct the path from the source of the last search to the given node-layer
+    pub fn reconstruct_path(&self, tgt: NodeLayer<A::Ix>) -> Vec<NodeLayer<A::Ix>>
+    where
+        A::Ix: HyperIndex,
+    {
+        let mut path = vec![tgt];
+        let mut current = tgt;
+        while let Some(&prev) = self.previous.get(&current) {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        path
+    }
+    /// explore the graph starting from the given node-layer, stopping early once the
+    /// destination (if any) has been reached; returns the node-layers in the order they were
+    /// discovered.
+    fn explore(
+        &mut self,
+        src: NodeLayer<A::Ix>,
+        dest: Option<NodeLayer<A::Ix>>,
+    ) -> Result<Vec<NodeLayer<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+        for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+    {
+        self.reset();
+        let (layer, vertex) = src;
+        if !self.graph.contains_layer(&layer) {
+            return Err(rshyper::Error::LayerNotFound.into());
+        }
+        if !self.graph.contains_node(&vertex) {
+            return Err(rshyper::Error::NodeNotFound.into());
+        }
+        if !self.allows(&layer) {
+            return Ok(Vec::new());
+        }
+        self.visited.insert(src);
+        self.queue.push_back(src);
+        // the node-layers in the order they were discovered
+        let mut order = Vec::new();
+
+        while let Some(current @ (layer, vertex)) = self.queue.pop_front() {
+            order.push(current);
+            if dest == Some(current) {
+                break;
+            }
+            // move within the current layer
+            let mut next = Vec::new();
+            for edge_id in self.graph.find_edges_with_node(&vertex) {
+                if self.graph.get_edge_layer(edge_id)? != layer {
+                    continue;
+                }
+                for &v in self.graph.get_edge_domain(edge_id)? {
+                    next.push((layer, v));
+                }
+            }
+            // move across the couplings of the current node-layer
+            next.extend(self.graph.find_coupled(&layer, &vertex));
+            for point in next {
+                if self.allows(&point.0) && self.visited.insert(point) {
+                    self.previous.insert(point, current);
+                    self.queue.push_back(point);
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+impl<'a, N, E, A, H, S> Search<NodeLayer<A::Ix>> for MultiLayerBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: MultiLayerHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    type Output = Vec<NodeLayer<A::Ix>>;
+
+    fn search(&mut self, start: NodeLayer<A::Ix>) -> Result<Self::Output> {
+        self.explore(start, None)
+    }
+}
+
+impl<'a, N, E, A, H, S> Traversal<NodeLayer<A::Ix>> for MultiLayerBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: MultiLayerHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: Eq + Hash,
+{
+    type Store<I2> = HashSet<I2, S>;
+
+    fn has_visited(&self, point: &NodeLayer<A::Ix>) -> bool {
+        self.visited().contains(point)
+    }
+
+    fn visited(&self) -> &Self::Store<NodeLayer<A::Ix>> {
+        &self.visited
+    }
+}
@@ -0,0 +1,189 @@
+/*
+    appellation: impl_nested_breadth_first <module>
+    authors: @FL03
+*/
+use crate::search::nested_breadth_first::NestedBreadthFirst;
+
+use crate::error::{Error, Result};
+use crate::search::Search;
+use crate::traits::Traversal;
+use alloc::collections::VecDeque;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::HashSet;
+use rshyper::idx::{EdgeId, Grid, HyperIndex};
+use rshyper::{GraphProps, NestedHyperGraph};
+
+impl<'a, N, E, A, H, S> NestedBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: NestedHyperGraph<N, E, A>,
+    S: BuildHasher,
+{
+    /// create a new instance from the given nested hypergraph
+    pub fn new(graph: &'a H) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            graph,
+            max_depth: None,
+            queue: Default::default(),
+            previous: Default::default(),
+            depths: Default::default(),
+            visited: Default::default(),
+            _marker: core::marker::PhantomData::<(N, E)>,
+        }
+    }
+    /// consumes the current instance to create another with the given maximum depth
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+    /// returns the maximum depth of the traversal, if any
+    pub const fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+    /// returns an immutable reference to the queue
+    pub const fn queue(&self) -> &VecDeque<(Grid<A::Ix>, usize)> {
+        &self.queue
+    }
+    /// returns an immutable reference to the visited elements
+    pub const fn visited(&self) -> &HashSet<Grid<A::Ix>, S> {
+        &self.visited
+    }
+    /// returns true if the element has been visited
+    pub fn has_visited<Q>(&self, element: &Q) -> bool
+    where
+        A::Ix: Eq + Hash,
+        Q: ?Sized + Eq + Hash,
+        Grid<A::Ix>: core::borrow::Borrow<Q>,
+    {
+        self.visited().contains(element)
+    }
+    /// returns the depth at which the given element was discovered during the last search
+    pub fn depth_of(&self, element: &Grid<A::Ix>) -> Option<usize>
+    where
+        A::Ix: Eq + Hash,
+    {
+        self.depths.get(element).copied()
+    }
+    /// reset the traversal state to allow reusing the instance
+    pub fn reset(&mut self) -> &mut Self {
+        self.queue.clear();
+        self.previous.clear();
+        self.depths.clear();
+        self.visited.clear();
+        self
+    }
+    /// a convience method to perform a search
+    pub fn search(&mut self, start: EdgeId<A::Ix>) -> Result<Vec<Grid<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+    {
+        Search::search(self, start)
+    }
+    /// find the chain of nested edges leading from the given edge to the given element,
+    /// returning each of the elements along the way
+    pub fn find_path(&mut self, src: EdgeId<A::Ix>, dest: Grid<A::Ix>) -> Result<Vec<Grid<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+    {
+        self.explore(src, Some(&dest))?;
+        if !self.has_visited(&dest) {
+            return Err(Error::PathNotFound);
+        }
+        Ok(self.reconstruct_path(dest))
+    }
+    /// reconstruct the path from the edge of the last search to the given element
+    pub fn reconstruct_path(&self, tgt: Grid<A::Ix>) -> Vec<Grid<A::Ix>>
+    where
+        A::Ix: HyperIndex,
+    {
+        let mut path = vec![tgt];
+        let mut current = tgt;
+        while let Some(&prev) = self.previous.get(&current) {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        path
+    }
+    /// descend into the given edge, stopping early once the destination (if any) has been
+    /// reached; returns the elements in the order they were discovered, beginning with the
+    /// starting edge.
+    fn explore(
+        &mut self,
+        src: EdgeId<A::Ix>,
+        dest: Option<&Grid<A::Ix>>,
+    ) -> Result<Vec<Grid<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+    {
+        self.reset();
+        if !self.graph.contains_edge(&src) {
+            return Err(rshyper::Error::EdgeNotFound.into());
+        }
+        let root = Grid::Edge(src);
+        self.visited.insert(root);
+        self.depths.insert(root, 0);
+        self.queue.push_back((root, 0));
+        // the elements in the order they were discovered
+        let mut order = Vec::new();
+
+        while let Some((current, depth)) = self.queue.pop_front() {
+            order.push(current);
+            if dest == Some(&current) {
+                break;
+            }
+            // only edges have members to descend into
+            let Grid::Edge(edge) = current else {
+                continue;
+            };
+            if self.max_depth.is_some_and(|max| depth >= max) {
+                continue;
+            }
+            for member in self.graph.find_edge_members(&edge) {
+                if self.visited.insert(member) {
+                    self.previous.insert(member, current);
+                    self.depths.insert(member, depth + 1);
+                    self.queue.push_back((member, depth + 1));
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+impl<'a, N, E, A, H, S> Search<EdgeId<A::Ix>> for NestedBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: NestedHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+{
+    type Output = Vec<Grid<A::Ix>>;
+
+    fn search(&mut self, start: EdgeId<A::Ix>) -> Result<Self::Output> {
+        self.explore(start, None)
+    }
+}
+
+impl<'a, N, E, A, H, S> Traversal<Grid<A::Ix>> for NestedBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: NestedHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: Eq + Hash,
+{
+    type Store<I2> = HashSet<I2, S>;
+
+    fn has_visited(&self, element: &Grid<A::Ix>) -> bool {
+        self.visited().contains(element)
+    }
+
+    fn visited(&self) -> &Self::Store<Grid<A::Ix>> {
+        &self.visited
+    }
+}
@@ -0,0 +1,161 @@
+/*
+    appellation: impl_ordered_breadth_first <module>
+    authors: @FL03
+*/
+use crate::search::ordered_breadth_first::OrderedBreadthFirst;
+
+use crate::error::{Error, Result};
+use crate::search::Search;
+use crate::traits::Traversal;
+use alloc::collections::VecDeque;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::HashSet;
+use rshyper::idx::{HyperIndex, VertexId, VertexSet};
+use rshyper::{GraphProps, OrderedHyperGraph};
+
+impl<'a, N, E, A, H, S> OrderedBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: OrderedHyperGraph<N, E, A>,
+    S: BuildHasher,
+{
+    /// create a new instance from the given ordered hypergraph
+    pub fn new(graph: &'a H) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            graph,
+            queue: Default::default(),
+            previous: Default::default(),
+            visited: Default::default(),
+            _marker: core::marker::PhantomData::<(N, E)>,
+        }
+    }
+    /// returns an immutable reference to the queue
+    pub const fn queue(&self) -> &VecDeque<VertexId<A::Ix>> {
+        &self.queue
+    }
+    /// returns an immutable reference to the visited vertices
+    pub const fn visited(&self) -> &VertexSet<A::Ix, S> {
+        &self.visited
+    }
+    /// returns true if the vertex has been visited
+    pub fn has_visited<Q>(&self, vertex: &Q) -> bool
+    where
+        A::Ix: Eq + Hash,
+        Q: ?Sized + Eq + Hash,
+        VertexId<A::Ix>: core::borrow::Borrow<Q>,
+    {
+        self.visited().contains(vertex)
+    }
+    /// reset the traversal state to allow reusing the instance
+    pub fn reset(&mut self) -> &mut Self {
+        self.queue.clear();
+        self.previous.clear();
+        self.visited.clear();
+        self
+    }
+    /// a convience method to perform a search
+    pub fn search(&mut self, start: VertexId<A::Ix>) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+    {
+        Search::search(self, start)
+    }
+    /// find the path between two vertices using the fewest number of steps along the
+    /// sequences of the edges, returning each of the vertices along the way
+    pub fn find_path(
+        &mut self,
+        src: VertexId<A::Ix>,
+        dest: VertexId<A::Ix>,
+    ) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+    {
+        self.explore(src, Some(dest))?;
+        if !self.has_visited(&dest) {
+            return Err(Error::PathNotFound);
+        }
+        Ok(self.reconstruct_path(dest))
+    }
+    /// reconstruct the path from the source of the last search to the given vertex
+    pub fn reconstruct_path(&self, tgt: VertexId<A::Ix>) -> Vec<VertexId<A::Ix>>
+    where
+        A::Ix: HyperIndex,
+    {
+        let mut path = vec![tgt];
+        let mut current = tgt;
+        while let Some(&prev) = self.previous.get(&current) {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        path
+    }
+    /// explore the graph starting from the given vertex, stopping early once the destination
+    /// (if any) has been reached; returns the vertices in the order they were discovered.
+    fn explore(
+        &mut self,
+        src: VertexId<A::Ix>,
+        dest: Option<VertexId<A::Ix>>,
+    ) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+    {
+        self.reset();
+        if !self.graph.contains_node(&src) {
+            return Err(rshyper::Error::NodeNotFound.into());
+        }
+        self.visited.insert(src);
+        self.queue.push_back(src);
+        // the vertices in the order they were discovered
+        let mut order = Vec::new();
+
+        while let Some(current) = self.queue.pop_front() {
+            order.push(current);
+            if dest == Some(current) {
+                break;
+            }
+            for next in self.graph.find_successors(&current) {
+                if self.visited.insert(next) {
+                    self.previous.insert(next, current);
+                    self.queue.push_back(next);
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+impl<'a, N, E, A, H, S> Search<VertexId<A::Ix>> for OrderedBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: OrderedHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+{
+    type Output = Vec<VertexId<A::Ix>>;
+
+    fn search(&mut self, start: VertexId<A::Ix>) -> Result<Self::Output> {
+        self.explore(start, None)
+    }
+}
+
+impl<'a, N, E, A, H, S> Traversal<VertexId<A::Ix>> for OrderedBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: OrderedHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: Eq + Hash,
+{
+    type Store<I2> = HashSet<I2, S>;
+
+    fn has_visited(&self, vertex: &VertexId<A::Ix>) -> bool {
+        self.visited().contains(vertex)
+    }
+
+    fn visited(&self) -> &Self::Store<VertexId<A::Ix>> {
+        &self.visited
+    }
+}
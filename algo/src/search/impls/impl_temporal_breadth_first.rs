@@ -0,0 +1,159 @@
+/*
+    appellation: impl_temporal_breadth_first <module>
+    authors: @FL03
+*/
+use crate::search::temporal_breadth_first::TemporalBreadthFirst;
+use crate::search::temporal_dijkstra::{Arrivals, earliest_departure};
+
+use crate::error::Result;
+use crate::search::Search;
+use crate::traits::Traversal;
+use alloc::collections::VecDeque;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::HashSet;
+use rshyper::idx::{HyperIndex, VertexId, VertexSet};
+use rshyper::{GraphProps, RawLayout, TemporalHyperGraph};
+
+impl<'a, N, E, A, H, S> TemporalBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+{
+    /// create a new instance from the given temporal hypergraph
+    pub fn new(graph: &'a H) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            graph,
+            departure: None,
+            queue: Default::default(),
+            arrivals: Arrivals::default(),
+            visited: Default::default(),
+            _marker: core::marker::PhantomData::<(N, E)>,
+        }
+    }
+    /// consumes the current instance to create another that departs from the source at the
+    /// given time
+    pub fn depart_at(self, time: H::Time) -> Self {
+        Self {
+            departure: Some(time),
+            ..self
+        }
+    }
+    /// returns the time of departure, if specified
+    pub const fn departure(&self) -> Option<&H::Time> {
+        self.departure.as_ref()
+    }
+    /// returns an immutable reference to the queue
+    pub const fn queue(&self) -> &VecDeque<VertexId<A::Ix>> {
+        &self.queue
+    }
+    /// returns a reference to the earliest known arrival times
+    pub const fn arrivals(&self) -> &Arrivals<A::Ix, H::Time, S> {
+        &self.arrivals
+    }
+    /// returns an immutable reference to the visited vertices
+    pub const fn visited(&self) -> &VertexSet<A::Ix, S> {
+        &self.visited
+    }
+    /// returns true if the vertex has been visited
+    pub fn has_visited<Q>(&self, vertex: &Q) -> bool
+    where
+        A::Ix: Eq + Hash,
+        Q: ?Sized + Eq + Hash,
+        VertexId<A::Ix>: core::borrow::Borrow<Q>,
+    {
+        self.visited().contains(vertex)
+    }
+    /// reset the traversal state to allow reusing the instance
+    pub fn reset(&mut self) -> &mut Self {
+        self.queue.clear();
+        self.arrivals.clear();
+        self.visited.clear();
+        self
+    }
+    /// a convience method to perform a search
+    pub fn search(&mut self, start: VertexId<A::Ix>) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        A::Ix: HyperIndex,
+        for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+    {
+        Search::search(self, start)
+    }
+}
+
+impl<'a, N, E, A, H, S> Search<VertexId<A::Ix>> for TemporalBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    type Output = Vec<VertexId<A::Ix>>;
+
+    fn search(&mut self, start: VertexId<A::Ix>) -> Result<Self::Output> {
+        self.reset();
+        if !self.graph.contains_node(&start) {
+            return Err(rshyper::Error::NodeNotFound.into());
+        }
+        // the path to return (discovery order)
+        let mut path = vec![start];
+        self.visited.insert(start);
+        let Some(departure) = self
+            .departure
+            .or_else(|| earliest_departure(self.graph, &start))
+        else {
+            return Ok(path);
+        };
+        self.arrivals.insert(start, departure);
+        self.queue.push_back(start);
+
+        while let Some(current) = self.queue.pop_front() {
+            let time = self.arrivals[&current];
+            for edge_id in self.graph.find_edges_with_node(&current) {
+                let interval = self.graph.get_edge_interval(edge_id)?;
+                let depart = time.max(*interval.start());
+                if depart > *interval.end() {
+                    continue;
+                }
+                for &vertex in self.graph.get_edge_domain(edge_id)? {
+                    // only (re)visit a vertex if it may be reached earlier than before
+                    if self
+                        .arrivals
+                        .get(&vertex)
+                        .is_some_and(|&prev| prev <= depart)
+                    {
+                        continue;
+                    }
+                    self.arrivals.insert(vertex, depart);
+                    if self.visited.insert(vertex) {
+                        path.push(vertex);
+                    }
+                    self.queue.push_back(vertex);
+                }
+            }
+        }
+        Ok(path)
+    }
+}
+
+impl<'a, N, E, A, H, S> Traversal<VertexId<A::Ix>> for TemporalBreadthFirst<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: Eq + Hash,
+{
+    type Store<I2> = HashSet<I2, S>;
+
+    fn has_visited(&self, vertex: &VertexId<A::Ix>) -> bool {
+        self.visited().contains(vertex)
+    }
+
+    fn visited(&self) -> &Self::Store<VertexId<A::Ix>> {
+        &self.visited
+    }
+}
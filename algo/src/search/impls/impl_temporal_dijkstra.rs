@@ -0,0 +1,245 @@
+/*
+    appellation: impl_temporal_dijkstra <module>
+    authors: @FL03
+*/
+use crate::search::dijkstra::PreviousHistory;
+use crate::search::temporal_dijkstra::{Arrivals, TemporalDijkstra, earliest_departure};
+
+use crate::error::{Error, Result};
+use crate::search::Search;
+use crate::traits::{PathFinder, Traversal};
+use alloc::collections::BinaryHeap;
+use core::cmp::Reverse;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::HashSet;
+use rshyper::idx::{HyperIndex, RawIndex, VertexId, VertexSet};
+use rshyper::rel::RawLayout;
+use rshyper::{GraphProps, TemporalHyperGraph};
+
+impl<'a, N, E, A, H, S> TemporalDijkstra<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: RawIndex,
+{
+    /// create a new instance from the given temporal hypergraph
+    pub fn new(graph: &'a H) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            graph,
+            departure: None,
+            arrivals: Arrivals::default(),
+            previous: PreviousHistory::default(),
+            visited: VertexSet::default(),
+            _marker: core::marker::PhantomData::<(N, E)>,
+        }
+    }
+    /// consumes the current instance to create another that departs from the source at the
+    /// given time
+    pub fn depart_at(self, time: H::Time) -> Self {
+        Self {
+            departure: Some(time),
+            ..self
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns the time of departure, if specified
+    pub const fn departure(&self) -> Option<&H::Time> {
+        self.departure.as_ref()
+    }
+    /// returns a reference to the earliest known arrival times
+    pub const fn arrivals(&self) -> &Arrivals<A::Ix, H::Time, S> {
+        &self.arrivals
+    }
+    /// returns the earliest arrival time at the given vertex, if it was reached
+    pub fn arrival_time(&self, vertex: &VertexId<A::Ix>) -> Option<&H::Time>
+    where
+        A::Ix: Eq + Hash,
+    {
+        self.arrivals().get(vertex)
+    }
+    /// returns a reference to the previous history
+    pub const fn previous(&self) -> &PreviousHistory<A::Ix, S> {
+        &self.previous
+    }
+    /// returns a reference to the visited vertices
+    pub const fn visited(&self) -> &VertexSet<A::Ix, S> {
+        &self.visited
+    }
+    /// returns true if the vertex is visited
+    pub fn has_visited<Q>(&self, vertex: &Q) -> bool
+    where
+        Q: ?Sized + Eq + Hash,
+        A::Ix: Eq + Hash,
+        VertexId<A::Ix>: core::borrow::Borrow<Q>,
+    {
+        self.visited().contains(vertex)
+    }
+    /// find the time-respecting path from `src` to `dest` arriving as early as possible
+    pub fn find_path(
+        &mut self,
+        src: VertexId<A::Ix>,
+        dest: VertexId<A::Ix>,
+    ) -> Result<<Self as PathFinder<A::Ix>>::Path>
+    where
+        Self: PathFinder<A::Ix>,
+    {
+        PathFinder::find_path(self, src, dest)
+    }
+    /// search for every vertex reachable from `start`, returning them in order of arrival
+    pub fn search(
+        &mut self,
+        start: VertexId<A::Ix>,
+    ) -> Result<<Self as Search<VertexId<A::Ix>>>::Output>
+    where
+        Self: Search<VertexId<A::Ix>>,
+    {
+        Search::search(self, start)
+    }
+    /// reset the state for reuse
+    pub fn reset(&mut self) -> &mut Self {
+        self.arrivals.clear();
+        self.previous.clear();
+        self.visited.clear();
+        self
+    }
+}
+
+impl<'a, N, E, A, H, S> TemporalDijkstra<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// settles the vertices reachable from `src` in order of their arrival, stopping early
+    /// once the destination (if any) has been settled.
+    fn explore(
+        &mut self,
+        src: VertexId<A::Ix>,
+        dest: Option<&VertexId<A::Ix>>,
+    ) -> Result<Vec<VertexId<A::Ix>>> {
+        self.reset();
+        if !self.graph.contains_node(&src) {
+            return Err(rshyper::Error::NodeNotFound.into());
+        }
+        // the source is settled without departing
+        let Some(start) = self
+            .departure
+            .or_else(|| earliest_departure(self.graph, &src))
+        else {
+            self.visited.insert(src);
+            return Ok(vec![src]);
+        };
+        let mut order = Vec::new();
+        let mut heap = BinaryHeap::new();
+        self.arrivals.insert(src, start);
+        heap.push(Reverse((start, src)));
+
+        while let Some(Reverse((time, u))) = heap.pop() {
+            // skip any outdated entries
+            if !self.visited.insert(u) {
+                continue;
+            }
+            order.push(u);
+            if dest == Some(&u) {
+                break;
+            }
+            for edge_id in self.graph.find_edges_with_node(&u) {
+                let interval = self.graph.get_edge_interval(edge_id)?;
+                // the edge may only be traversed once both it and the traveller are present
+                let depart = time.max(*interval.start());
+                if depart > *interval.end() {
+                    continue;
+                }
+                for &v in self.graph.get_edge_domain(edge_id)? {
+                    if self.visited.contains(&v) {
+                        continue;
+                    }
+                    if self.arrivals.get(&v).is_none_or(|&prev| depart < prev) {
+                        self.arrivals.insert(v, depart);
+                        self.previous.insert(v, u);
+                        heap.push(Reverse((depart, v)));
+                    }
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+impl<'a, N, E, A, H, S> PathFinder<A::Ix> for TemporalDijkstra<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    type Path = Vec<VertexId<A::Ix>>;
+
+    fn find_path(&mut self, src: VertexId<A::Ix>, dest: VertexId<A::Ix>) -> Result<Self::Path> {
+        if !self.graph.contains_node(&dest) {
+            return Err(rshyper::Error::NodeNotFound.into());
+        }
+        self.explore(src, Some(&dest))?;
+        if self.has_visited(&dest) {
+            Ok(self.reconstruct_path(dest))
+        } else {
+            Err(Error::PathNotFound)
+        }
+    }
+
+    fn reconstruct_path(&self, mut goal: VertexId<A::Ix>) -> Self::Path {
+        let mut path = vec![goal];
+        while let Some(&prev) = self.previous().get(&goal) {
+            path.push(prev);
+            goal = prev;
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl<'a, N, E, A, H, S> Traversal<VertexId<A::Ix>> for TemporalDijkstra<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: Eq + Hash,
+{
+    type Store<I2> = HashSet<I2, S>;
+
+    fn has_visited(&self, vertex: &VertexId<A::Ix>) -> bool {
+        self.visited().contains(vertex)
+    }
+
+    fn visited(&self) -> &Self::Store<VertexId<A::Ix>> {
+        &self.visited
+    }
+}
+
+impl<'a, N, E, A, H, S> Search<VertexId<A::Ix>> for TemporalDijkstra<'a, N, E, A, H, S>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+    S: BuildHasher,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    type Output = Vec<VertexId<A::Ix>>;
+
+    fn search(&mut self, start: VertexId<A::Ix>) -> Result<Self::Output> {
+        let order = self.explore(start, None)?;
+        #[cfg(feature = "tracing")]
+        tracing::info!("reached {} vertices", order.len());
+        Ok(order)
+    }
+}
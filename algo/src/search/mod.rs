@@ -7,7 +7,10 @@
 #[doc(inline)]
 pub use self::{
     astar::AStarSearch, breadth_first::BreadthFirstTraversal, depth_first::DepthFirstTraversal,
-    dijkstra::Dijkstra, traits::*,
+    dijkstra::Dijkstra, multilayer_breadth_first::MultiLayerBreadthFirst,
+    nested_breadth_first::NestedBreadthFirst, ordered_breadth_first::OrderedBreadthFirst,
+    oriented_breadth_first::OrientedBreadthFirst, temporal_breadth_first::TemporalBreadthFirst,
+    temporal_dijkstra::TemporalDijkstra, traits::*,
 };
 
 mod impls {
@@ -15,12 +18,24 @@ mod impls {
     mod impl_breadth_first;
     mod impl_depth_first;
     mod impl_dijkstra;
+    mod impl_multilayer_breadth_first;
+    mod impl_nested_breadth_first;
+    mod impl_ordered_breadth_first;
+    mod impl_oriented_breadth_first;
+    mod impl_temporal_breadth_first;
+    mod impl_temporal_dijkstra;
 }
 
 pub mod astar;
 pub mod breadth_first;
 pub mod depth_first;
 pub mod dijkstra;
+pub mod multilayer_breadth_first;
+pub mod nested_breadth_first;
+pub mod ordered_breadth_first;
+pub mod oriented_breadth_first;
+pub mod temporal_breadth_first;
+pub mod temporal_dijkstra;
 
 mod traits {
     #[doc(inline)]
@@ -38,4 +53,10 @@ pub mod prelude {
     pub use super::breadth_first::BreadthFirstTraversal;
     pub use super::depth_first::DepthFirstTraversal;
     pub use super::dijkstra::Dijkstra;
+    pub use super::multilayer_breadth_first::MultiLayerBreadthFirst;
+    pub use super::nested_breadth_first::NestedBreadthFirst;
+    pub use super::ordered_breadth_first::OrderedBreadthFirst;
+    pub use super::oriented_breadth_first::OrientedBreadthFirst;
+    pub use super::temporal_breadth_first::TemporalBreadthFirst;
+    pub use super::temporal_dijkstra::TemporalDijkstra;
 }
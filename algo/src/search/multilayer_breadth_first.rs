@@ -0,0 +1,29 @@
+/*
+    appellation: multilayer_breadth_first <module>
+    authors: @FL03
+*/
+//! this module implements a layer-aware breadth-first traversal for multilayer hypergraphs
+use alloc::collections::VecDeque;
+use hashbrown::{DefaultHashBuilder, HashMap, HashSet};
+use rshyper::idx::{LayerId, NodeLayer};
+use rshyper::{GraphProps, MultiLayerHyperGraph};
+
+/// The [`MultiLayerBreadthFirst`] traversal visits the [node-layers](NodeLayer) of a
+/// [`MultiLayerHyperGraph`] reachable from some source, moving between the vertices of a
+/// layer using its (intralayer) edges and between layers using the interlayer couplings of the
+/// graph. Both kinds of moves are considered to be a single step.
+///
+/// The traversal may optionally be restricted to a subset of the layers, in which case any
+/// node-layer belonging to another layer is never visited.
+pub struct MultiLayerBreadthFirst<'a, N, E, A, H, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    H: MultiLayerHyperGraph<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) layers: Option<HashSet<LayerId>>,
+    pub(crate) queue: VecDeque<NodeLayer<A::Ix>>,
+    pub(crate) previous: HashMap<NodeLayer<A::Ix>, NodeLayer<A::Ix>, S>,
+    pub(crate) visited: HashSet<NodeLayer<A::Ix>, S>,
+    pub(crate) _marker: core::marker::PhantomData<(N, E)>,
+}
@@ -0,0 +1,28 @@
+/*
+    appellation: nested_breadth_first <module>
+    authors: @FL03
+*/
+//! this module implements a breadth-first traversal descending into the edges of nested
+//! hypergraphs
+use alloc::collections::VecDeque;
+use hashbrown::{DefaultHashBuilder, HashMap, HashSet};
+use rshyper::idx::Grid;
+use rshyper::{GraphProps, NestedHyperGraph};
+
+/// The [`NestedBreadthFirst`] traversal descends into an edge of a [`NestedHyperGraph`],
+/// visiting each of its members, vertices and edges alike, before descending into the members
+/// of the edges nested within it. The traversal may optionally be limited to some maximum
+/// depth, with the members of the starting edge residing at a depth of one.
+pub struct NestedBreadthFirst<'a, N, E, A, H, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    H: NestedHyperGraph<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) queue: VecDeque<(Grid<A::Ix>, usize)>,
+    pub(crate) previous: HashMap<Grid<A::Ix>, Grid<A::Ix>, S>,
+    pub(crate) depths: HashMap<Grid<A::Ix>, usize, S>,
+    pub(crate) visited: HashSet<Grid<A::Ix>, S>,
+    pub(crate) _marker: core::marker::PhantomData<(N, E)>,
+}
@@ -0,0 +1,26 @@
+/*
+    appellation: ordered_breadth_first <module>
+    authors: @FL03
+*/
+//! this module implements a sequence-respecting breadth-first traversal for ordered
+//! hypergraphs
+use alloc::collections::VecDeque;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper::idx::{VertexId, VertexSet};
+use rshyper::{GraphProps, OrderedHyperGraph};
+
+/// The [`OrderedBreadthFirst`] traversal visits the vertices of an [`OrderedHyperGraph`]
+/// reachable from some source, treating each edge as a path through its sequence of vertices.
+/// Consequently, a vertex may only move to the vertices immediately following it within the
+/// sequences of its edges, rather than to every other member of the edges.
+pub struct OrderedBreadthFirst<'a, N, E, A, H, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    H: OrderedHyperGraph<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) queue: VecDeque<VertexId<A::Ix>>,
+    pub(crate) previous: HashMap<VertexId<A::Ix>, VertexId<A::Ix>, S>,
+    pub(crate) visited: VertexSet<A::Ix, S>,
+    pub(crate) _marker: core::marker::PhantomData<(N, E)>,
+}
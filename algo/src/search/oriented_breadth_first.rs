@@ -0,0 +1,26 @@
+/*
+    appellation: oriented_breadth_first <module>
+    authors: @FL03
+*/
+//! this module implements an orientation-respecting breadth-first traversal for hypergraphs
+//! whose directedness is decided at runtime
+use alloc::collections::VecDeque;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper::idx::{VertexId, VertexSet};
+use rshyper::{GraphProps, OrientedHyperGraph};
+
+/// The [`OrientedBreadthFirst`] traversal visits the vertices of an [`OrientedHyperGraph`]
+/// reachable from some source while respecting the current mode of the graph. When directed,
+/// a vertex may only move through the edges whose tail contains it onto the members of their
+/// head; when undirected, the traversal behaves like a standard breadth-first traversal.
+pub struct OrientedBreadthFirst<'a, N, E, A, H, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    H: OrientedHyperGraph<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) queue: VecDeque<VertexId<A::Ix>>,
+    pub(crate) previous: HashMap<VertexId<A::Ix>, VertexId<A::Ix>, S>,
+    pub(crate) visited: VertexSet<A::Ix, S>,
+    pub(crate) _marker: core::marker::PhantomData<(N, E)>,
+}
@@ -0,0 +1,30 @@
+/*
+    appellation: temporal_breadth_first <module>
+    authors: @FL03
+*/
+//! this module implements a time-respecting breadth-first traversal for temporal hypergraphs
+use crate::search::temporal_dijkstra::Arrivals;
+use alloc::collections::VecDeque;
+use hashbrown::DefaultHashBuilder;
+use rshyper::idx::{VertexId, VertexSet};
+use rshyper::{GraphProps, TemporalHyperGraph};
+
+/// The [`TemporalBreadthFirst`] traversal visits the vertices of a [`TemporalHyperGraph`]
+/// reachable from some source using _time-respecting_ paths, i.e. paths whose edges are
+/// traversed during their intervals in a non-decreasing order of time.
+///
+/// Vertices are discovered level-by-level, much like the standard breadth-first traversal;
+/// however, a vertex is revisited whenever an earlier arrival is found, ensuring that every
+/// vertex reachable from the source is eventually discovered.
+pub struct TemporalBreadthFirst<'a, N, E, A, H, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) departure: Option<H::Time>,
+    pub(crate) queue: VecDeque<VertexId<A::Ix>>,
+    pub(crate) arrivals: Arrivals<A::Ix, H::Time, S>,
+    pub(crate) visited: VertexSet<A::Ix, S>,
+    pub(crate) _marker: core::marker::PhantomData<(N, E)>,
+}
@@ -0,0 +1,47 @@
+/*
+    appellation: temporal_dijkstra <module>
+    authors: @FL03
+*/
+//! this module implements a time-respecting variant of Dijkstra's algorithm, computing the
+//! earliest arrival time at each vertex of a temporal hypergraph.
+use crate::search::dijkstra::PreviousHistory;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper::idx::{VertexId, VertexSet};
+use rshyper::{GraphProps, TemporalHyperGraph};
+
+/// a type alias for a map associating vertices with their (earliest) time of arrival
+pub type Arrivals<K, T, S = DefaultHashBuilder> = HashMap<VertexId<K>, T, S>;
+
+/// The [`TemporalDijkstra`] operator finds _time-respecting_ paths within a
+/// [`TemporalHyperGraph`], minimizing the time at which the destination is reached.
+///
+/// A path is time-respecting if each of its edges is traversed at some point during its
+/// interval and no earlier than the arrival at the preceding vertex; traversing an edge is
+/// considered to be instantaneous. Unless a [departure](TemporalDijkstra::depart_at) is
+/// specified, the search leaves the source as early as its incident edges allow.
+pub struct TemporalDijkstra<'a, N, E, A, H, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) departure: Option<H::Time>,
+    pub(crate) arrivals: Arrivals<A::Ix, H::Time, S>,
+    pub(crate) previous: PreviousHistory<A::Ix, S>,
+    pub(crate) visited: VertexSet<A::Ix, S>,
+    pub(crate) _marker: core::marker::PhantomData<(N, E)>,
+}
+
+/// returns the time at which the source may depart when no departure is specified; i.e. the
+/// earliest start of any of its incident edges.
+pub(crate) fn earliest_departure<N, E, A, H>(graph: &H, src: &VertexId<A::Ix>) -> Option<H::Time>
+where
+    A: GraphProps,
+    H: TemporalHyperGraph<N, E, A>,
+{
+    graph
+        .find_edges_with_node(src)
+        .filter_map(|id| graph.get_edge_interval(id).ok())
+        .map(|interval| *interval.start())
+        .min()
+}
@@ -0,0 +1,147 @@
+/*
+    appellation: minimal_transversals <module>
+    authors: @FL03
+*/
+//! this module implements the [`MinimalTransversals`] iterator, lazily enumerating the minimal
+//! transversals of a hypergraph
+use alloc::vec::Vec;
+use rshyper::idx::{HyperIndex, RawIndex, Udx, VertexId};
+
+/// A [`MinimalTransversals`] iterator lazily enumerates the minimal transversals of a
+/// hypergraph using the MMCS algorithm of Murakami and Uno. Starting from the empty set, the
+/// search repeatedly selects the edge yet to be hit containing the fewest candidate vertices,
+/// branching on each of them while pruning any set containing a vertex without a _critical_
+/// edge, i.e. one it alone hits. Every minimal transversal is produced exactly once, with the
+/// search requiring memory polynomial in the size of the hypergraph.
+///
+/// The iterator is detached from the hypergraph, owning a snapshot of its edges; it is
+/// typically obtained using [`Transversals::iter`](super::Transversals::iter).
+#[derive(Clone, Debug)]
+pub struct MinimalTransversals<Ix = Udx>
+where
+    Ix: RawIndex,
+{
+    /// the distinct vertices of the hypergraph, ordered by their index
+    pub(crate) vertices: Vec<VertexId<Ix>>,
+    /// the edges of the hypergraph as sorted lists of positions within `vertices`
+    pub(crate) edges: Vec<Vec<usize>>,
+    /// the frames of the search, each extending the set of its parent
+    pub(crate) stack: Vec<Frame>,
+    /// set when the empty set, hitting a hypergraph without edges, is yet to be produced
+    pub(crate) pending: bool,
+}
+
+/// a frame of the search, branching on the candidates of the edge it selected
+#[derive(Clone, Debug)]
+pub(crate) struct Frame {
+    /// the (sorted) positions of the vertices within the current set
+    set: Vec<usize>,
+    /// flags the vertices that may still be added to the set
+    candidates: Vec<bool>,
+    /// the candidates of the selected edge yet to be branched on
+    branches: Vec<usize>,
+}
+
+impl<Ix> MinimalTransversals<Ix>
+where
+    Ix: HyperIndex,
+{
+    /// create a new iterator over the given vertices and edges, the latter given as sorted
+    /// lists of positions within the former
+    pub(crate) fn new(vertices: Vec<VertexId<Ix>>, edges: Vec<Vec<usize>>) -> Self {
+        let mut iter = Self {
+            pending: edges.is_empty(),
+            stack: Vec::new(),
+            vertices,
+            edges,
+        };
+        if !iter.pending {
+            let candidates = alloc::vec![true; iter.vertices.len()];
+            iter.push(Vec::new(), candidates);
+        }
+        iter
+    }
+    /// returns the distinct vertices contained by the edges of the hypergraph
+    pub fn vertices(&self) -> &[VertexId<Ix>] {
+        &self.vertices
+    }
+    /// returns true if the given set hits the edge
+    fn hits(set: &[usize], edge: &[usize]) -> bool {
+        set.iter().any(|v| edge.binary_search(v).is_ok())
+    }
+    /// returns true if every vertex of the set has a critical edge, i.e. one containing no
+    /// other vertex of the set
+    fn is_minimal(&self, set: &[usize]) -> bool {
+        let mut critical = alloc::vec![false; set.len()];
+        for edge in &self.edges {
+            let mut hitters = set
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| edge.binary_search(v).is_ok());
+            if let (Some((i, _)), None) = (hitters.next(), hitters.next()) {
+                critical[i] = true;
+            }
+        }
+        critical.into_iter().all(|c| c)
+    }
+    /// pushes a frame extending the given set, branching on the candidates of the edge yet to
+    /// be hit containing the fewest of them
+    fn push(&mut self, set: Vec<usize>, mut candidates: Vec<bool>) {
+        let Some(edge) = self
+            .edges
+            .iter()
+            .filter(|e| !Self::hits(&set, e))
+            .min_by_key(|e| e.iter().filter(|v| candidates[**v]).count())
+        else {
+            return;
+        };
+        let mut branches = edge
+            .iter()
+            .copied()
+            .filter(|v| candidates[*v])
+            .collect::<Vec<_>>();
+        branches.iter().for_each(|v| candidates[*v] = false);
+        // branches are popped from the back, visiting the vertices in order
+        branches.reverse();
+        self.stack.push(Frame {
+            set,
+            candidates,
+            branches,
+        });
+    }
+}
+
+impl<Ix> Iterator for MinimalTransversals<Ix>
+where
+    Ix: HyperIndex,
+{
+    type Item = Vec<VertexId<Ix>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if core::mem::take(&mut self.pending) {
+            return Some(Vec::new());
+        }
+        while let Some(frame) = self.stack.last_mut() {
+            let Some(v) = frame.branches.pop() else {
+                self.stack.pop();
+                continue;
+            };
+            let candidates = frame.candidates.clone();
+            // the vertex becomes a candidate for the remaining branches of the frame
+            frame.candidates[v] = true;
+            let mut set = frame.set.clone();
+            let pos = set.binary_search(&v).unwrap_err();
+            set.insert(pos, v);
+            if !self.is_minimal(&set) {
+                continue;
+            }
+            if self.edges.iter().all(|e| Self::hits(&set, e)) {
+                return Some(set.into_iter().map(|v| self.vertices[v]).collect());
+            }
+            self.push(set, candidates);
+        }
+        None
+    }
+}
+
+impl<Ix> core::iter::FusedIterator for MinimalTransversals<Ix> where Ix: HyperIndex {}
@@ -0,0 +1,17 @@
+/*
+    appellation: transversal <module>
+    authors: @FL03
+*/
+//! this module implements the enumeration of the minimal transversals, or hitting sets, of a
+//! hypergraph alongside greedy and exact solvers for its minimum hitting sets
+#[doc(inline)]
+pub use self::{minimal_transversals::*, transversals::*};
+
+pub mod minimal_transversals;
+pub mod transversals;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::minimal_transversals::MinimalTransversals;
+    pub use super::transversals::Transversals;
+}
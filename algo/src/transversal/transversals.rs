@@ -0,0 +1,236 @@
+/*
+    appellation: transversals <module>
+    authors: @FL03
+*/
+//! this module implements the [`Transversals`] operator, computing the minimal transversals
+//! and minimum hitting sets of hypergraphs
+use super::MinimalTransversals;
+use crate::utils::{EdgeSet, collect_edges};
+use alloc::vec::Vec;
+use rshyper::idx::{HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIterEdge, RawLayout};
+
+/// The [`Transversals`] operator computes the _transversals_, or _hitting sets_, of a
+/// hypergraph, i.e. the sets of vertices intersecting every edge. A transversal is _minimal_
+/// if none of its proper subsets are transversals, and _minimum_ if no transversal contains
+/// fewer vertices. The minimal transversals form the _transversal hypergraph_, or _blocker_,
+/// central to problems such as model-based diagnosis and the computation of minimal cut sets.
+///
+/// Each transversal is returned as a list of vertices ordered by their index. A hypergraph
+/// without any edges is hit by the empty set, while one containing an empty edge has no
+/// transversals at all.
+pub struct Transversals<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIterEdge<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> Transversals<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraphIterEdge<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns a lazy iterator over the minimal transversals of the hypergraph, enumerated
+    /// using the MMCS (minimal-to-minimal via critical edges) algorithm of Murakami and Uno.
+    pub fn iter(&self) -> MinimalTransversals<A::Ix> {
+        let (vertices, edges) = index_edges(collect_edges(self.graph));
+        MinimalTransversals::new(vertices, edges)
+    }
+    /// computes all of the minimal transversals of the hypergraph using Berge's algorithm,
+    /// which incrementally extends the transversals of the first `i` edges to those of the
+    /// first `i + 1`, removing any that are no longer minimal.
+    ///
+    /// **Note:** the intermediate results may grow far larger than the final output, making
+    /// the [lazy enumeration](Transversals::iter) preferable for larger hypergraphs.
+    pub fn berge(&self) -> Vec<Vec<VertexId<A::Ix>>> {
+        let (vertices, edges) = index_edges(collect_edges(self.graph));
+        let mut transversals = alloc::vec![Vec::<usize>::new()];
+        for edge in &edges {
+            let mut next = Vec::with_capacity(transversals.len());
+            for t in transversals {
+                if edge.iter().any(|v| t.binary_search(v).is_ok()) {
+                    next.push(t);
+                    continue;
+                }
+                for v in edge {
+                    let mut extended = t.clone();
+                    let pos = extended.binary_search(v).unwrap_err();
+                    extended.insert(pos, *v);
+                    next.push(extended);
+                }
+            }
+            // retain only the minimal sets, visiting the smaller sets first
+            next.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+            next.dedup();
+            transversals = Vec::with_capacity(next.len());
+            for t in next {
+                if !transversals.iter().any(|m| is_subset(m, &t)) {
+                    transversals.push(t);
+                }
+            }
+        }
+        let mut res = transversals
+            .into_iter()
+            .map(|t| t.into_iter().map(|v| vertices[v]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        res.sort();
+        res
+    }
+    /// returns a hitting set of the hypergraph found by repeatedly selecting the vertex hitting
+    /// the most remaining edges, breaking ties by index; [`None`] is returned if some edge is
+    /// empty. The result is within a factor of `H(d)`, the `d`-th harmonic number, of the
+    /// minimum where `d` is the largest degree of any vertex.
+    pub fn greedy_hitting_set(&self) -> Option<Vec<VertexId<A::Ix>>> {
+        let (vertices, edges) = index_edges(collect_edges(self.graph));
+        let set = greedy(vertices.len(), &edges)?;
+        Some(set.into_iter().map(|v| vertices[v]).collect())
+    }
+    /// returns a minimum hitting set of the hypergraph using a branch-and-bound search, seeded
+    /// with the [greedy](Transversals::greedy_hitting_set) solution; [`None`] is returned if
+    /// some edge is empty.
+    ///
+    /// **Note:** the problem is NP-hard, making the search exponential in the worst case.
+    pub fn minimum_hitting_set(&self) -> Option<Vec<VertexId<A::Ix>>> {
+        let (vertices, edges) = index_edges(collect_edges(self.graph));
+        let set = minimum(vertices.len(), &edges)?;
+        Some(set.into_iter().map(|v| vertices[v]).collect())
+    }
+}
+
+/// maps the vertices of the given edges onto their positions within the (sorted) list of
+/// distinct vertices, returning the latter alongside the edges as sorted lists of positions
+pub(crate) fn index_edges<Ix>(edges: Vec<EdgeSet<Ix>>) -> (Vec<VertexId<Ix>>, Vec<Vec<usize>>)
+where
+    Ix: HyperIndex,
+{
+    let mut vertices = edges
+        .iter()
+        .flat_map(|(_, domain)| domain)
+        .copied()
+        .collect::<Vec<_>>();
+    vertices.sort();
+    vertices.dedup();
+    let edges = edges
+        .into_iter()
+        .map(|(_, domain)| {
+            let mut edge = domain
+                .iter()
+                .map(|v| vertices.binary_search(v).expect("vertex is present"))
+                .collect::<Vec<_>>();
+            edge.sort_unstable();
+            edge
+        })
+        .collect();
+    (vertices, edges)
+}
+
+/// returns true if the sorted list `a` is a subset of the sorted list `b`
+fn is_subset(a: &[usize], b: &[usize]) -> bool {
+    let mut rest = b.iter();
+    a.iter().all(|x| rest.any(|y| y == x))
+}
+
+/// computes a hitting set by repeatedly selecting the vertex contained by the most edges that
+/// are yet to be hit, returning [`None`] if some edge is empty
+fn greedy(order: usize, edges: &[Vec<usize>]) -> Option<Vec<usize>> {
+    if edges.iter().any(Vec::is_empty) {
+        return None;
+    }
+    let mut hit = alloc::vec![false; edges.len()];
+    let mut set = Vec::new();
+    while hit.iter().any(|h| !h) {
+        let mut counts = alloc::vec![0usize; order];
+        for (edge, _) in edges.iter().zip(&hit).filter(|(_, h)| !**h) {
+            edge.iter().for_each(|v| counts[*v] += 1);
+        }
+        // the first vertex with the largest count, preferring lower indices
+        let (v, _) = counts
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, c)| **c)
+            .expect("some edge is yet to be hit");
+        for (edge, h) in edges.iter().zip(hit.iter_mut()) {
+            *h |= edge.binary_search(&v).is_ok();
+        }
+        set.push(v);
+    }
+    set.sort_unstable();
+    Some(set)
+}
+
+/// computes a minimum hitting set of the edges, given as lists of positions among `order`
+/// vertices, returning [`None`] if some edge is empty
+pub(crate) fn minimum(order: usize, edges: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut best = greedy(order, edges)?;
+    let mut hits = alloc::vec![0usize; edges.len()];
+    let mut chosen = Vec::new();
+    branch(edges, &mut hits, &mut chosen, &mut best);
+    best.sort_unstable();
+    Some(best)
+}
+
+/// extends the chosen vertices towards a hitting set smaller than the best found so far,
+/// branching on the vertices of the smallest edge yet to be hit
+fn branch(
+    edges: &[Vec<usize>],
+    hits: &mut [usize],
+    chosen: &mut Vec<usize>,
+    best: &mut Vec<usize>,
+) {
+    let Some(edge) = edges
+        .iter()
+        .zip(hits.iter())
+        .filter(|(_, h)| **h == 0)
+        .map(|(e, _)| e)
+        .min_by_key(|e| e.len())
+    else {
+        if chosen.len() < best.len() {
+            best.clone_from(chosen);
+        }
+        return;
+    };
+    // every pairwise disjoint edge yet to be hit requires a distinct vertex
+    let mut bound = 0;
+    let mut used = Vec::<usize>::new();
+    for (e, _) in edges.iter().zip(hits.iter()).filter(|(_, h)| **h == 0) {
+        if e.iter().all(|v| !used.contains(v)) {
+            used.extend(e);
+            bound += 1;
+        }
+    }
+    if chosen.len() + bound >= best.len() {
+        return;
+    }
+    for &v in edge {
+        for (e, h) in edges.iter().zip(hits.iter_mut()) {
+            if e.binary_search(&v).is_ok() {
+                *h += 1;
+            }
+        }
+        chosen.push(v);
+        branch(edges, hits, chosen, best);
+        chosen.pop();
+        for (e, h) in edges.iter().zip(hits.iter_mut()) {
+            if e.binary_search(&v).is_ok() {
+                *h -= 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+/*
+    appellation: utils <module>
+    authors: @FL03
+*/
+//! this module provides various utilities shared by the operators of the crate
+use alloc::vec::Vec;
+use hashbrown::HashSet;
+use rshyper::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraphIter, HyperGraphIterEdge, RawLayout};
+
+/// a type alias for an edge paired with the (owned) set of vertices it contains
+pub(crate) type EdgeSet<Ix> = (EdgeId<Ix>, HashSet<VertexId<Ix>>);
+
+/// collects each of the edges of the graph alongside their domain, ordered by their index
+pub(crate) fn collect_edges<N, E, A, H>(graph: &H) -> Vec<EdgeSet<A::Ix>>
+where
+    A: GraphProps,
+    H: HyperGraphIterEdge<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    let mut edges = graph
+        .edges()
+        .filter_map(|id| {
+            let domain = graph.get_edge_domain(id).ok()?;
+            Some((*id, domain.into_iter().copied().collect()))
+        })
+        .collect::<Vec<_>>();
+    edges.sort_by_key(|(id, _)| *id);
+    edges
+}
+
+/// returns the vertices of the graph, ordered by their index, alongside its edges given as
+/// sorted lists of positions within the former
+pub(crate) fn index_graph<N, E, A, H>(graph: &H) -> (Vec<VertexId<A::Ix>>, Vec<Vec<usize>>)
+where
+    A: GraphProps,
+    H: HyperGraphIter<N, E, A>,
+    A::Ix: HyperIndex,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    let mut vertices = graph.vertices().copied().collect::<Vec<_>>();
+    vertices.sort();
+    let edges = collect_edges(graph)
+        .into_iter()
+        .map(|(_, domain)| {
+            let mut edge = domain
+                .iter()
+                .filter_map(|v| vertices.binary_search(v).ok())
+                .collect::<Vec<_>>();
+            edge.sort_unstable();
+            edge
+        })
+        .collect();
+    (vertices, edges)
+}
+
+/// returns the neighbors of each of the vertices within the primal graph of the given edges,
+/// i.e. the vertices sharing some edge with it
+pub(crate) fn primal_neighbors(order: usize, edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut neighbors = alloc::vec![Vec::new(); order];
+    for edge in edges {
+        for &v in edge {
+            neighbors[v].extend(edge.iter().copied().filter(|u| *u != v));
+        }
+    }
+    neighbors.iter_mut().for_each(|n| {
+        n.sort_unstable();
+        n.dedup();
+    });
+    neighbors
+}
@@ -0,0 +1,14 @@
+/*
+    appellation: walk <module>
+    authors: @FL03
+*/
+//! this module implements various random walks on hypergraphs
+#[doc(inline)]
+pub use self::random_walk::*;
+
+pub mod random_walk;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use super::random_walk::RandomWalk;
+}
@@ -0,0 +1,232 @@
+/*
+    appellation: random_walk <module>
+    authors: @FL03
+*/
+//! this module implements a random walk on hypergraphs honoring edge-dependent vertex weights
+use crate::error::Result;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+use num_traits::ToPrimitive;
+use rshyper::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper::{GraphProps, HyperGraph, RawLayout, WeightedIncidence};
+
+/// The [`RandomWalk`] operator performs random walks on a hypergraph, where each step is taken
+/// by first selecting one of the edges containing the current vertex, with a probability
+/// proportional to the weight of the edge, before selecting one of the vertices within that
+/// edge.
+///
+/// By default, every vertex of the selected edge is equally likely to be chosen; however, the
+/// `incidence_*` methods honor the edge-dependent weights of a [`WeightedIncidence`] graph,
+/// choosing each vertex with a probability proportional to its weight within the edge. Note
+/// that, in either case, the walk may remain at the current vertex.
+///
+/// The operator is agnostic to the source of randomness, expecting a sampler producing values
+/// uniformly distributed within `[0, 1)`; the walk stops early upon reaching a vertex that is
+/// not contained by any (positively weighted) edge.
+pub struct RandomWalk<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraph<N, E, A>,
+{
+    pub(crate) graph: &'a H,
+    pub(crate) _marker: core::marker::PhantomData<(N, E, A)>,
+}
+
+impl<'a, N, E, A, H> RandomWalk<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: HyperGraph<N, E, A>,
+    A::Ix: HyperIndex,
+    E: ToPrimitive,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// create a new instance from the given hypergraph
+    pub const fn new(graph: &'a H) -> Self {
+        Self {
+            graph,
+            _marker: core::marker::PhantomData::<(N, E, A)>,
+        }
+    }
+    /// returns a reference to the graph
+    pub const fn graph(&self) -> &H {
+        self.graph
+    }
+    /// returns the probability of moving from the given vertex to each of its neighbors
+    /// (including itself), sorted by the index of the neighbors.
+    pub fn transitions(&self, vertex: &VertexId<A::Ix>) -> Result<Vec<(VertexId<A::Ix>, f64)>> {
+        self.transitions_by(vertex, |_, _| Ok(1.0))
+    }
+    /// take a single step from the given vertex using the given sampler, returning [`None`]
+    /// if the walk cannot continue
+    pub fn step<R>(&self, current: &VertexId<A::Ix>, rng: &mut R) -> Result<Option<VertexId<A::Ix>>>
+    where
+        R: FnMut() -> f64,
+    {
+        let transitions = self.transitions(current)?;
+        Ok(sample(&transitions, rng()))
+    }
+    /// perform a walk of (at most) the given number of steps, returning the vertices visited
+    /// along the way, starting with the given vertex
+    pub fn walk<R>(
+        &self,
+        start: VertexId<A::Ix>,
+        steps: usize,
+        rng: &mut R,
+    ) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        R: FnMut() -> f64,
+    {
+        self.walk_by(start, steps, rng, |_, _| Ok(1.0))
+    }
+    /// computes the transition probabilities from the given vertex, where `gamma` returns
+    /// the weight of a vertex within some edge
+    pub(crate) fn transitions_by<F>(
+        &self,
+        vertex: &VertexId<A::Ix>,
+        gamma: F,
+    ) -> Result<Vec<(VertexId<A::Ix>, f64)>>
+    where
+        F: Fn(&EdgeId<A::Ix>, &VertexId<A::Ix>) -> Result<f64>,
+    {
+        if !self.graph.contains_node(vertex) {
+            return Err(rshyper::Error::NodeNotFound.into());
+        }
+        let mut probs = HashMap::<VertexId<A::Ix>, f64>::new();
+        let mut total = 0.0;
+        for edge_id in self.graph.find_edges_with_node(vertex) {
+            let weight = self
+                .graph
+                .get_edge_weight(edge_id)?
+                .to_f64()
+                .unwrap_or_default();
+            let domain = self.graph.get_edge_domain(edge_id)?;
+            // the total weight of the vertices within the edge
+            let mut delta = 0.0;
+            for v in domain {
+                delta += gamma(edge_id, v)?;
+            }
+            if weight <= 0.0 || delta <= 0.0 {
+                continue;
+            }
+            total += weight;
+            for v in domain {
+                *probs.entry(*v).or_default() += weight * gamma(edge_id, v)? / delta;
+            }
+        }
+        let mut transitions = probs
+            .into_iter()
+            .map(|(v, p)| (v, p / total))
+            .filter(|(_, p)| *p > 0.0)
+            .collect::<Vec<_>>();
+        transitions.sort_by(|a, b| Ord::cmp(&a.0, &b.0));
+        Ok(transitions)
+    }
+    /// perform a walk, where `gamma` returns the weight of a vertex within some edge
+    pub(crate) fn walk_by<R, F>(
+        &self,
+        start: VertexId<A::Ix>,
+        steps: usize,
+        rng: &mut R,
+        gamma: F,
+    ) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        R: FnMut() -> f64,
+        F: Fn(&EdgeId<A::Ix>, &VertexId<A::Ix>) -> Result<f64>,
+    {
+        if !self.graph.contains_node(&start) {
+            return Err(rshyper::Error::NodeNotFound.into());
+        }
+        let mut path = Vec::with_capacity(steps + 1);
+        path.push(start);
+        let mut current = start;
+        for _ in 0..steps {
+            let transitions = self.transitions_by(&current, &gamma)?;
+            match sample(&transitions, rng()) {
+                Some(next) => {
+                    path.push(next);
+                    current = next;
+                }
+                None => break,
+            }
+        }
+        Ok(path)
+    }
+}
+
+impl<'a, N, E, A, H> RandomWalk<'a, N, E, A, H>
+where
+    A: GraphProps,
+    H: WeightedIncidence<N, E, A>,
+    H::Incidence: ToPrimitive,
+    A::Ix: HyperIndex,
+    E: ToPrimitive,
+    for<'b> &'b <H::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+{
+    /// returns the probability of moving from the given vertex to each of its neighbors
+    /// (including itself), honoring the edge-dependent weights of the vertices.
+    pub fn incidence_transitions(
+        &self,
+        vertex: &VertexId<A::Ix>,
+    ) -> Result<Vec<(VertexId<A::Ix>, f64)>> {
+        let graph = self.graph;
+        self.transitions_by(vertex, |e, v| incidence_weight(graph, e, v))
+    }
+    /// take a single step from the given vertex, honoring the edge-dependent weights of the
+    /// vertices
+    pub fn incidence_step<R>(
+        &self,
+        current: &VertexId<A::Ix>,
+        rng: &mut R,
+    ) -> Result<Option<VertexId<A::Ix>>>
+    where
+        R: FnMut() -> f64,
+    {
+        let transitions = self.incidence_transitions(current)?;
+        Ok(sample(&transitions, rng()))
+    }
+    /// perform a walk of (at most) the given number of steps, honoring the edge-dependent
+    /// weights of the vertices
+    pub fn incidence_walk<R>(
+        &self,
+        start: VertexId<A::Ix>,
+        steps: usize,
+        rng: &mut R,
+    ) -> Result<Vec<VertexId<A::Ix>>>
+    where
+        R: FnMut() -> f64,
+    {
+        let graph = self.graph;
+        self.walk_by(start, steps, rng, |e, v| incidence_weight(graph, e, v))
+    }
+}
+
+/// returns the weight of the vertex within the given edge as a floating-point value
+fn incidence_weight<N, E, A, H>(
+    graph: &H,
+    edge: &EdgeId<A::Ix>,
+    vertex: &VertexId<A::Ix>,
+) -> Result<f64>
+where
+    A: GraphProps,
+    H: WeightedIncidence<N, E, A>,
+    H::Incidence: ToPrimitive,
+{
+    let weight = graph.get_incidence_weight(edge, vertex)?;
+    Ok(weight.to_f64().unwrap_or_default())
+}
+
+/// select one of the given transitions using a value sampled uniformly within `[0, 1)`
+fn sample<Ix>(transitions: &[(VertexId<Ix>, f64)], u: f64) -> Option<VertexId<Ix>>
+where
+    Ix: HyperIndex,
+{
+    let mut acc = 0.0;
+    for &(vertex, p) in transitions {
+        acc += p;
+        if u < acc {
+            return Some(vertex);
+        }
+    }
+    // account for any rounding errors by falling back onto the last transition
+    transitions.last().map(|&(vertex, _)| vertex)
+}
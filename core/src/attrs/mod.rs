@@ -16,7 +16,7 @@ pub(crate) mod prelude {
     #[doc(inline)]
     pub use super::attributes::Attrs;
     #[doc(inline)]
-    pub use super::{DiAttrs, GraphProps, UnAttrs};
+    pub use super::{DiAttrs, DynAttrs, GraphProps, UnAttrs};
 }
 use crate::{Directed, GraphType, Mode, RawIndex, Undirected};
 
@@ -24,6 +24,8 @@ use crate::{Directed, GraphType, Mode, RawIndex, Undirected};
 pub type DiAttrs<Idx> = Attrs<Idx, Directed>;
 /// a type alias for graph [`Attrs`] configured with an [`Undirected`] graph type.
 pub type UnAttrs<Idx> = Attrs<Idx, Undirected>;
+/// a type alias for graph [`Attrs`] whose graph type is decided at runtime by some [`Mode`].
+pub type DynAttrs<Idx> = Attrs<Idx, Mode>;
 
 /// The [`GraphProps`] trait abstracts several generic types used to define a hyper graph
 /// into a single entity.
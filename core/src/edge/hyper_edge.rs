@@ -144,6 +144,11 @@ where
             weight,
         }
     }
+    /// consumes the edge to return its id, nodes, and weight; the inverse of
+    /// [`from_parts`](Edge::from_parts)
+    pub fn into_parts(self) -> (EdgeId<Idx>, S, Weight<T>) {
+        (self.link.id, self.link.domain, self.weight)
+    }
     /// returns true if the edge contains the given vertex
     pub fn contains<Q>(&self, index: &Q) -> bool
     where
@@ -38,10 +38,15 @@ mod types {
     pub use self::prelude::*;
 
     mod aliases;
+    #[cfg(feature = "hashbrown")]
+    mod incidence_weights;
 
     pub(crate) mod prelude {
         #[doc(inline)]
         pub use super::aliases::*;
+        #[doc(inline)]
+        #[cfg(feature = "hashbrown")]
+        pub use super::incidence_weights::*;
     }
 }
 
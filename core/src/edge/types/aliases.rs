@@ -19,6 +19,10 @@ pub type UnEdge<T, S, Idx = usize> = Edge<T, S, Undirected, Idx>;
 
 /// a type alias for an [`Edge`] whose _vertices_ are stored in a [`VertexSet`]
 pub type HashEdge<T, K, Ix, S> = Edge<T, VertexSet<Ix, S>, K, Ix>;
+/// a type alias for an [`Edge`] whose _vertices_ are each mapped to a weight specific to the
+/// edge using [`IncidenceWeights`](crate::edge::IncidenceWeights)
+#[cfg(feature = "hashbrown")]
+pub type WeightedEdge<T, V, K, Ix, S> = Edge<T, super::IncidenceWeights<Ix, V, S>, K, Ix>;
 
 /// a type alias for a [`Edge`] whose _vertices_ are stored in an array of fixed
 /// size with the size defined by the generic parameter `N`.
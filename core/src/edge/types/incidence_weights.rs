@@ -0,0 +1,204 @@
+/*
+    appellation: incidence_weights <module>
+    authors: @FL03
+*/
+use crate::idx::{HashIndex, RawIndex, VertexId};
+use crate::{RawDomain, Weight};
+use core::hash::BuildHasher;
+use hashbrown::DefaultHashBuilder;
+use hashbrown::hash_map::{self, HashMap};
+
+/// The [`IncidenceWeights`] is an edge domain associating each of its vertices with a weight
+/// specific to its incidence with the edge, i.e. an _edge-dependent vertex weight_.
+///
+/// Iterating over the domain yields its vertices, just like any other domain, so edges using
+/// it remain compatible with the algorithms of the [`rshyper`](https://docs.rs/rshyper)
+/// ecosystem; the weights are accessed through the [`get`](IncidenceWeights::get) and
+/// [`iter`](IncidenceWeights::iter) methods.
+#[derive(Clone, Default)]
+#[repr(transparent)]
+pub struct IncidenceWeights<Ix, V, S = DefaultHashBuilder> {
+    pub(crate) store: HashMap<VertexId<Ix>, Weight<V>, S>,
+}
+
+impl<Ix, V, S> IncidenceWeights<Ix, V, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    /// returns a new, empty domain
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self {
+            store: HashMap::default(),
+        }
+    }
+    /// returns a new, empty domain using the given hasher
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            store: HashMap::with_hasher(hasher),
+        }
+    }
+    /// returns a new domain from the given map of vertices onto their weights
+    pub const fn from_map(store: HashMap<VertexId<Ix>, Weight<V>, S>) -> Self {
+        Self { store }
+    }
+    /// returns an immutable reference to the map of vertices onto their weights
+    pub const fn as_map(&self) -> &HashMap<VertexId<Ix>, Weight<V>, S> {
+        &self.store
+    }
+    /// consumes the domain to return the map of vertices onto their weights
+    pub fn into_map(self) -> HashMap<VertexId<Ix>, Weight<V>, S> {
+        self.store
+    }
+    /// returns the number of vertices within the domain
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+    /// returns true if the domain contains no vertices
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+    /// returns an iterator over the vertices of the domain
+    pub fn vertices(&self) -> hash_map::Keys<'_, VertexId<Ix>, Weight<V>> {
+        self.store.keys()
+    }
+    /// returns an iterator over the vertices of the domain paired with their weights
+    pub fn iter(&self) -> hash_map::Iter<'_, VertexId<Ix>, Weight<V>> {
+        self.store.iter()
+    }
+    /// returns an iterator over the vertices of the domain paired with a mutable reference
+    /// to their weights
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, VertexId<Ix>, Weight<V>> {
+        self.store.iter_mut()
+    }
+}
+
+impl<Ix, V, S> IncidenceWeights<Ix, V, S>
+where
+    Ix: HashIndex,
+    S: BuildHasher,
+{
+    /// returns true if the domain contains the given vertex
+    pub fn contains(&self, vertex: &VertexId<Ix>) -> bool {
+        self.store.contains_key(vertex)
+    }
+    /// returns the weight of the given vertex within the edge, if any
+    pub fn get(&self, vertex: &VertexId<Ix>) -> Option<&Weight<V>> {
+        self.store.get(vertex)
+    }
+    /// returns a mutable reference to the weight of the given vertex within the edge, if any
+    pub fn get_mut(&mut self, vertex: &VertexId<Ix>) -> Option<&mut Weight<V>> {
+        self.store.get_mut(vertex)
+    }
+    /// insert the given vertex with its weight, returning the previous weight of the vertex,
+    /// if any
+    pub fn insert(&mut self, vertex: VertexId<Ix>, weight: Weight<V>) -> Option<Weight<V>> {
+        self.store.insert(vertex, weight)
+    }
+    /// removes the given vertex from the domain, returning its weight, if any
+    pub fn remove(&mut self, vertex: &VertexId<Ix>) -> Option<Weight<V>> {
+        self.store.remove(vertex)
+    }
+}
+
+impl<Ix, V, S> RawDomain for IncidenceWeights<Ix, V, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    type Key = VertexId<Ix>;
+
+    seal!();
+
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+}
+
+impl<Ix, V, S> core::fmt::Debug for IncidenceWeights<Ix, V, S>
+where
+    Ix: core::fmt::Debug,
+    V: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.store.iter()).finish()
+    }
+}
+
+impl<Ix, V, S> PartialEq for IncidenceWeights<Ix, V, S>
+where
+    Ix: HashIndex,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.store == other.store
+    }
+}
+
+impl<Ix, V, S> Eq for IncidenceWeights<Ix, V, S>
+where
+    Ix: HashIndex,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<Ix, V, S> Extend<(VertexId<Ix>, Weight<V>)> for IncidenceWeights<Ix, V, S>
+where
+    Ix: HashIndex,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (VertexId<Ix>, Weight<V>)>,
+    {
+        self.store.extend(iter)
+    }
+}
+
+impl<Ix, V, S> FromIterator<(VertexId<Ix>, Weight<V>)> for IncidenceWeights<Ix, V, S>
+where
+    Ix: HashIndex,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (VertexId<Ix>, Weight<V>)>,
+    {
+        Self {
+            store: HashMap::from_iter(iter),
+        }
+    }
+}
+
+impl<Ix, V, S> From<HashMap<VertexId<Ix>, Weight<V>, S>> for IncidenceWeights<Ix, V, S> {
+    fn from(store: HashMap<VertexId<Ix>, Weight<V>, S>) -> Self {
+        Self { store }
+    }
+}
+
+impl<Ix, V, S> IntoIterator for IncidenceWeights<Ix, V, S> {
+    type Item = VertexId<Ix>;
+    type IntoIter = hash_map::IntoKeys<VertexId<Ix>, Weight<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.store.into_keys()
+    }
+}
+
+impl<'a, Ix, V, S> IntoIterator for &'a IncidenceWeights<Ix, V, S> {
+    type Item = &'a VertexId<Ix>;
+    type IntoIter = hash_map::Keys<'a, VertexId<Ix>, Weight<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.store.keys()
+    }
+}
@@ -26,6 +26,22 @@ pub enum Error {
     EdgeNotFound,
     #[error("The node with the given id does not exist")]
     NodeNotFound,
+    #[error("The layer with the given id does not exist")]
+    LayerNotFound,
+    #[error("The property with the given name does not exist")]
+    PropertyNotFound,
+    #[error("The property with the given name is of a different kind")]
+    PropertyKindMismatch,
+    #[error("The secondary index with the given name does not exist")]
+    SecondaryIndexNotFound,
+    #[error("The secondary index with the given name is keyed by a different type")]
+    SecondaryIndexKeyMismatch,
+    #[error("The tail and head of an edge must be non-empty and cover its domain")]
+    InvalidOrientation,
+    #[error("The domain of the edge is maintained by the graph and cannot be modified directly")]
+    ImmutableDomain,
+    #[error("The computation overflowed the range of its integer type")]
+    ArithmeticOverflow,
     #[error("No edges contain the given vertex")]
     NoEdgesWithDomain,
     #[error("Cannot create an empty hyperedge")]
@@ -74,6 +90,38 @@ impl Error {
     pub fn node_not_found() -> Self {
         Error::NodeNotFound
     }
+    /// returns a new [`LayerNotFound`](Error::LayerNotFound) variant
+    pub fn layer_not_found() -> Self {
+        Error::LayerNotFound
+    }
+    /// returns a new [`PropertyNotFound`](Error::PropertyNotFound) variant
+    pub fn property_not_found() -> Self {
+        Error::PropertyNotFound
+    }
+    /// returns a new [`PropertyKindMismatch`](Error::PropertyKindMismatch) variant
+    pub fn property_kind_mismatch() -> Self {
+        Error::PropertyKindMismatch
+    }
+    /// returns a new [`SecondaryIndexNotFound`](Error::SecondaryIndexNotFound) variant
+    pub fn secondary_index_not_found() -> Self {
+        Error::SecondaryIndexNotFound
+    }
+    /// returns a new [`SecondaryIndexKeyMismatch`](Error::SecondaryIndexKeyMismatch) variant
+    pub fn secondary_index_key_mismatch() -> Self {
+        Error::SecondaryIndexKeyMismatch
+    }
+    /// returns a new [`InvalidOrientation`](Error::InvalidOrientation) variant
+    pub fn invalid_orientation() -> Self {
+        Error::InvalidOrientation
+    }
+    /// returns a new [`ImmutableDomain`](Error::ImmutableDomain) variant
+    pub fn immutable_domain() -> Self {
+        Error::ImmutableDomain
+    }
+    /// returns a new [`ArithmeticOverflow`](Error::ArithmeticOverflow) variant
+    pub fn arithmetic_overflow() -> Self {
+        Error::ArithmeticOverflow
+    }
     #[cfg(feature = "alloc")]
     /// returns a new [`Unknown`](Error::Unknown) variant initialized with the given value
     pub fn unknown<S: ToString>(s: S) -> Self {
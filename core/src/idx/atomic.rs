@@ -0,0 +1,189 @@
+/*
+    appellation: atomic <module>
+    authors: @FL03
+*/
+use crate::idx::{EdgeId, IndexError, IndexFrame, IndexResult, RawIndex, Udx, VertexId};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use num_traits::{FromPrimitive, ToPrimitive};
+
+/// The [`AtomicIndexFrame`] is a thread-safe counterpart to the [`IndexFrame`], allowing
+/// indices to be allocated through a shared reference. Each call to
+/// [`next_edge`](AtomicIndexFrame::next_edge) or [`next_node`](AtomicIndexFrame::next_node)
+/// reserves a unique index using a single atomic operation, making the allocator suitable for
+/// hypergraphs that are modified by many threads at once.
+///
+/// Internally, the positions are stored as [`usize`] values and converted into the index type
+/// `T` upon allocation. The positions never advance beyond the largest index representable by
+/// `T`; once that index has been handed out, the position is flagged as exhausted and any
+/// further allocation returns an [`IndexOutOfBounds`](IndexError::IndexOutOfBounds) error.
+pub struct AtomicIndexFrame<T = Udx>
+where
+    T: RawIndex,
+{
+    /// the position of the next edge index
+    pub(crate) edge: AtomicUsize,
+    /// the position of the next node index
+    pub(crate) node: AtomicUsize,
+    /// flags the edge position as exhausted after handing out the last index
+    pub(crate) edge_exhausted: AtomicBool,
+    /// flags the node position as exhausted after handing out the last index
+    pub(crate) node_exhausted: AtomicBool,
+    pub(crate) _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> AtomicIndexFrame<T>
+where
+    T: RawIndex,
+{
+    /// returns a new instance starting from the given edge and node positions
+    pub const fn new(edge: usize, node: usize) -> Self {
+        Self {
+            edge: AtomicUsize::new(edge),
+            node: AtomicUsize::new(node),
+            edge_exhausted: AtomicBool::new(false),
+            node_exhausted: AtomicBool::new(false),
+            _marker: PhantomData,
+        }
+    }
+    /// returns a new instance starting from zero for both edges and nodes
+    pub const fn zero() -> Self {
+        Self::new(0, 0)
+    }
+    /// initialize a new instance from the given [`IndexFrame`]; returns an error if either
+    /// of its indices cannot be represented as a [`usize`].
+    pub fn from_frame(frame: IndexFrame<T>) -> IndexResult<Self>
+    where
+        T: ToPrimitive,
+    {
+        let edge = frame
+            .edge()
+            .to_usize()
+            .ok_or(IndexError::IndexOutOfBounds)?;
+        let node = frame
+            .node()
+            .to_usize()
+            .ok_or(IndexError::IndexOutOfBounds)?;
+        Ok(Self::new(edge, node))
+    }
+    /// returns true if the last edge index has been handed out
+    pub fn is_edge_exhausted(&self) -> bool {
+        self.edge_exhausted.load(Ordering::Acquire)
+    }
+    /// returns true if the last node index has been handed out
+    pub fn is_node_exhausted(&self) -> bool {
+        self.node_exhausted.load(Ordering::Acquire)
+    }
+    /// returns a snapshot of the next edge and node indices as an [`IndexFrame`]; an
+    /// [`IndexOutOfBounds`](IndexError::IndexOutOfBounds) error is returned if either position
+    /// is exhausted, since no further index remains.
+    pub fn load(&self) -> IndexResult<IndexFrame<T>>
+    where
+        T: FromPrimitive,
+    {
+        if self.is_edge_exhausted() || self.is_node_exhausted() {
+            return Err(IndexError::IndexOutOfBounds);
+        }
+        self.position()
+    }
+    /// returns the current positions as an [`IndexFrame`], regardless of whether they are
+    /// exhausted
+    fn position(&self) -> IndexResult<IndexFrame<T>>
+    where
+        T: FromPrimitive,
+    {
+        let edge =
+            T::from_usize(self.edge.load(Ordering::Acquire)).ok_or(IndexError::IndexOutOfBounds)?;
+        let node =
+            T::from_usize(self.node.load(Ordering::Acquire)).ok_or(IndexError::IndexOutOfBounds)?;
+        Ok(IndexFrame::new(EdgeId::new(edge), VertexId::new(node)))
+    }
+    /// overwrite the current position with the given [`IndexFrame`]
+    pub fn store(&self, frame: IndexFrame<T>) -> IndexResult<()>
+    where
+        T: ToPrimitive,
+    {
+        let edge = frame
+            .edge()
+            .to_usize()
+            .ok_or(IndexError::IndexOutOfBounds)?;
+        let node = frame
+            .node()
+            .to_usize()
+            .ok_or(IndexError::IndexOutOfBounds)?;
+        self.edge.store(edge, Ordering::Release);
+        self.node.store(node, Ordering::Release);
+        self.edge_exhausted.store(false, Ordering::Release);
+        self.node_exhausted.store(false, Ordering::Release);
+        Ok(())
+    }
+    /// reserves the next edge index, returning it
+    pub fn next_edge(&self) -> IndexResult<EdgeId<T>>
+    where
+        T: FromPrimitive,
+    {
+        Self::fetch_next(&self.edge, &self.edge_exhausted).map(EdgeId::new)
+    }
+    /// reserves the next node index, returning it
+    pub fn next_node(&self) -> IndexResult<VertexId<T>>
+    where
+        T: FromPrimitive,
+    {
+        Self::fetch_next(&self.node, &self.node_exhausted).map(VertexId::new)
+    }
+    /// consumes the allocator to return the final position as an [`IndexFrame`]; an exhausted
+    /// position rests on the last index, which has already been handed out (see
+    /// [`is_edge_exhausted`](AtomicIndexFrame::is_edge_exhausted) and
+    /// [`is_node_exhausted`](AtomicIndexFrame::is_node_exhausted)).
+    pub fn into_frame(self) -> IndexResult<IndexFrame<T>>
+    where
+        T: FromPrimitive,
+    {
+        self.position()
+    }
+    /// atomically increments the given position, returning the previous value converted into
+    /// the index type; the last representable index is handed out once without advancing the
+    /// position, which is flagged as exhausted instead.
+    fn fetch_next(position: &AtomicUsize, exhausted: &AtomicBool) -> IndexResult<T>
+    where
+        T: FromPrimitive,
+    {
+        let advanced = position.fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+            current
+                .checked_add(1)
+                .filter(|next| T::from_usize(*next).is_some())
+        });
+        match advanced {
+            Ok(current) => T::from_usize(current).ok_or(IndexError::IndexOutOfBounds),
+            Err(current) => {
+                // the last index cannot be advanced past, so it is flagged instead
+                let last = T::from_usize(current).ok_or(IndexError::IndexOutOfBounds)?;
+                exhausted
+                    .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                    .map(|_| last)
+                    .map_err(|_| IndexError::IndexOutOfBounds)
+            }
+        }
+    }
+}
+
+impl<T> Default for AtomicIndexFrame<T>
+where
+    T: RawIndex,
+{
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T> core::fmt::Debug for AtomicIndexFrame<T>
+where
+    T: RawIndex,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AtomicIndexFrame")
+            .field("edge", &self.edge.load(Ordering::Relaxed))
+            .field("node", &self.node.load(Ordering::Relaxed))
+            .finish()
+    }
+}
@@ -37,6 +37,19 @@ where
     pub const fn new(edge: EdgeId<T>, node: VertexId<T>) -> Self {
         Self { edge, node }
     }
+    /// attempts to convert the cursor into one using another index type `U`, returning an
+    /// [`IndexOutOfBounds`](crate::idx::IndexError::IndexOutOfBounds) error if either of the
+    /// indices cannot be represented by the target type.
+    pub fn try_cast<U>(self) -> IndexResult<IndexFrame<U>>
+    where
+        T: num_traits::ToPrimitive,
+        U: RawIndex + num_traits::NumCast,
+    {
+        Ok(IndexFrame::new(
+            self.edge.try_cast()?,
+            self.node.try_cast()?,
+        ))
+    }
     #[allow(clippy::should_implement_trait)]
     /// initializes a new instance using the lgoical defaults for both the edge and vertex
     /// indices.
@@ -94,6 +94,18 @@ where
     {
         IndexBase::new(f(self.value()))
     }
+    /// attempts to convert the inner value into another index type `U`, returning an
+    /// [`IndexOutOfBounds`](crate::idx::IndexError::IndexOutOfBounds) error if the value cannot
+    /// be represented by the target type.
+    pub fn try_cast<U>(self) -> IndexResult<IndexBase<U, K>>
+    where
+        T: num_traits::ToPrimitive,
+        U: RawIndex + num_traits::NumCast,
+    {
+        U::from(self.value())
+            .map(IndexBase::new)
+            .ok_or(crate::idx::IndexError::IndexOutOfBounds)
+    }
     /// [`replace`](core::mem::replace) and return the old value after replacing it with the
     /// given value
     pub const fn replace(&mut self, index: T) -> T {
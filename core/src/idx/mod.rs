@@ -7,11 +7,18 @@
 //! [`EdgeId`] and [`VertexId`], are provided for convenience, reducing the need to continually
 //! specify the index type when working with hypergraphs.
 #[doc(inline)]
+#[cfg(target_has_atomic = "ptr")]
+pub use self::atomic::AtomicIndexFrame;
+#[doc(inline)]
 #[cfg(feature = "alloc")]
 pub use self::tracker::IndexTracker;
 #[doc(inline)]
 pub use self::{error::*, frame::*, index::*, iter::*, traits::*, types::*};
 
+#[cfg(target_has_atomic = "ptr")]
+/// this module implements the [`AtomicIndexFrame`], a thread-safe allocator for edge and
+/// vertex indices.
+pub mod atomic;
 /// this module defines the [`IndexError`] type, establishing the various errors encountered by
 /// indices in a hypergraph.
 pub mod error;
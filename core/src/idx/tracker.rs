@@ -114,6 +114,29 @@ where
     pub fn with_nodes(self, nodes: Vec<VertexId<Ix>>) -> Self {
         Self { nodes, ..self }
     }
+    /// attempts to convert the tracker into one using another index type `U`, converting the
+    /// cursor alongside each of the recorded indices; an
+    /// [`IndexOutOfBounds`](IndexError::IndexOutOfBounds) error is returned if any of them
+    /// cannot be represented by the target type.
+    pub fn try_cast<U>(self) -> IndexResult<IndexTracker<U>>
+    where
+        Ix: num_traits::ToPrimitive,
+        U: RawIndex + num_traits::NumCast,
+    {
+        Ok(IndexTracker {
+            cursor: self.cursor.try_cast()?,
+            edges: self
+                .edges
+                .into_iter()
+                .map(EdgeId::try_cast)
+                .collect::<IndexResult<_>>()?,
+            nodes: self
+                .nodes
+                .into_iter()
+                .map(VertexId::try_cast)
+                .collect::<IndexResult<_>>()?,
+        })
+    }
     /// add a new edge index to the history.
     #[inline]
     pub fn add_edge(&mut self, index: EdgeId<Ix>) -> &mut Self {
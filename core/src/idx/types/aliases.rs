@@ -5,9 +5,9 @@
 //! this module provides various type aliases for the core components of a hypergraph, such as:
 //!
 //! - [`Udx`], [`EdgeId`], and [`VertexId`]
-//! - collections: [`VertexSet`], [`VertexVec`], [`VertexVecDeque`], and others
+//! - collections: [`VertexSet`], [`EdgeSet`], [`VertexVec`], [`VertexVecDeque`], and others
 //!
-use crate::idx::{EdgeIndex, IndexBase, VertexIndex};
+use crate::idx::{EdgeIndex, IndexBase, LayerIndex, VertexIndex};
 
 #[cfg(feature = "alloc")]
 pub use self::use_alloc::*;
@@ -25,6 +25,12 @@ pub type Udx = usize;
 pub type EdgeId<T = Udx> = IndexBase<T, EdgeIndex>;
 /// a type alias for an [`Index`] whose _kind_ is [`VertexIndex`]
 pub type VertexId<T = Udx> = IndexBase<T, VertexIndex>;
+/// a type alias for an [`Index`] whose _kind_ is [`LayerIndex`]
+pub type LayerId<T = Udx> = IndexBase<T, LayerIndex>;
+/// a type alias for a vertex within a particular layer of a multilayer graph, often referred
+/// to as a _node-layer_ tuple. Only the vertex uses the index type `T`; the layers of a graph
+/// are positional, numbering no more than the graph holds, so their ids are always [`Udx`].
+pub type NodeLayer<T = Udx> = (LayerId, VertexId<T>);
 /// a type alias for a fixed sized array of [`IndexBase`]
 pub type IndexArray<T, const N: usize, K = VertexIndex> = [IndexBase<T, K>; N];
 /// a type alias for a slice of [`IndexBase`]
@@ -47,6 +53,8 @@ pub type VertexSliceMut<'a, T> = IndexSliceMut<'a, T, VertexIndex>;
 pub type VertexSliceRef<'a, T> = IndexSliceRef<'a, T, VertexIndex>;
 /// a type alias for a [`HashSet`] of [`VertexId`] that is generic over the index type `I`
 pub type VertexSet<I, S = DefaultHashBuilder> = IndexSet<I, VertexIndex, S>;
+/// a type alias for a [`HashSet`] of [`EdgeId`] that is generic over the index type `I`
+pub type EdgeSet<I, S = DefaultHashBuilder> = IndexSet<I, EdgeIndex, S>;
 
 #[cfg(all(feature = "alloc", not(feature = "nightly")))]
 mod use_alloc {
@@ -93,6 +93,60 @@ where
     Raw(Idx),
 }
 
+impl<Idx> Grid<Idx>
+where
+    Idx: RawIndex,
+{
+    /// returns a new [`Edge`](Grid::Edge) variant from the given edge index
+    pub const fn from_edge(index: IndexBase<Idx, EdgeIndex>) -> Self {
+        Self::Edge(index)
+    }
+    /// returns a new [`Vertex`](Grid::Vertex) variant from the given vertex index
+    pub const fn from_vertex(index: IndexBase<Idx, VertexIndex>) -> Self {
+        Self::Vertex(index)
+    }
+    /// returns a reference to the edge index, if the instance is an edge
+    pub const fn as_edge(&self) -> Option<&IndexBase<Idx, EdgeIndex>> {
+        match self {
+            Self::Edge(index) => Some(index),
+            _ => None,
+        }
+    }
+    /// returns a reference to the vertex index, if the instance is a vertex
+    pub const fn as_vertex(&self) -> Option<&IndexBase<Idx, VertexIndex>> {
+        match self {
+            Self::Vertex(index) => Some(index),
+            _ => None,
+        }
+    }
+    /// returns a reference to the raw value of the index, regardless of its kind
+    pub const fn get(&self) -> &Idx {
+        match self {
+            Self::Edge(index) => index.get(),
+            Self::Vertex(index) => index.get(),
+            Self::Raw(index) => index,
+        }
+    }
+}
+
+impl<Idx> From<IndexBase<Idx, EdgeIndex>> for Grid<Idx>
+where
+    Idx: RawIndex,
+{
+    fn from(index: IndexBase<Idx, EdgeIndex>) -> Self {
+        Self::Edge(index)
+    }
+}
+
+impl<Idx> From<IndexBase<Idx, VertexIndex>> for Grid<Idx>
+where
+    Idx: RawIndex,
+{
+    fn from(index: IndexBase<Idx, VertexIndex>) -> Self {
+        Self::Vertex(index)
+    }
+}
+
 macro_rules! impl_type_kind {
     (@impl $(#[doc $($doc:tt)*])? $vis:vis $i:ident $kind:ident) => {
         // create the implementation for the kind
@@ -148,6 +202,8 @@ macro_rules! impl_type_kind {
 impl_type_kind! {
     #[doc = "A kind of index for edges in a graph"]
     pub struct EdgeIndex;
+    #[doc = "A kind of index for the layers of a multilayer graph"]
+    pub struct LayerIndex;
     #[doc = "A kind of index for vertices in a graph"]
     pub struct VertexIndex;
 }
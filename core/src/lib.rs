@@ -51,7 +51,8 @@ pub mod traits {
     //! behaviors
     #[doc(inline)]
     pub use self::{
-        contains::*, domain::*, hyper_graph::*, merge::*, step::*, transform::*, weighted::*,
+        contains::*, domain::*, hyper_graph::*, incidence::*, merge::*, multilayer::*, nested::*,
+        ordered::*, oriented::*, step::*, temporal::*, transform::*, weighted::*,
     };
     /// the [`Contains`] trait provides a way to check if a graph contains a specific component
     mod contains;
@@ -62,10 +63,34 @@ pub mod traits {
     /// generalization of algorithms constructors, and graphical operators.
     #[cfg(feature = "alloc")]
     mod hyper_graph;
+    /// the [`WeightedIncidence`] trait extends the [`HyperGraph`] for graphs whose vertices
+    /// are weighted according to the edges containing them
+    #[cfg(feature = "alloc")]
+    mod incidence;
     /// the [`Merge`] trait provides a way to combine two graphs into one
     mod merge;
+    /// the [`MultiLayerHyperGraph`] trait extends the [`HyperGraph`] for graphs composed of
+    /// several layers of edges defined over a shared set of vertices
+    #[cfg(feature = "alloc")]
+    mod multilayer;
+    /// the [`NestedHyperGraph`] trait extends the [`HyperGraph`] for graphs whose edges may
+    /// contain other edges alongside their vertices
+    #[cfg(feature = "alloc")]
+    mod nested;
+    /// the [`OrderedHyperGraph`] trait extends the [`HyperGraph`] for graphs whose edges
+    /// preserve the order and multiplicity of their vertices
+    #[cfg(feature = "alloc")]
+    mod ordered;
+    /// the [`OrientedHyperGraph`] trait extends the [`HyperGraph`] for graphs whose
+    /// directedness is decided at runtime, orienting their edges from a tail towards a head
+    #[cfg(feature = "alloc")]
+    mod oriented;
     /// this module defines sequential step generators
     mod step;
+    /// the [`TemporalHyperGraph`] trait extends the [`HyperGraph`] for graphs whose edges are
+    /// only active during some interval of time
+    #[cfg(feature = "alloc")]
+    mod temporal;
     /// traits for transformative operations on hypergraphs, such as mapping, are implemented
     /// within this module
     mod transform;
@@ -76,9 +101,11 @@ pub mod traits {
 pub mod types {
     //! this module provides various types used throughout the library
     #[doc(inline)]
-    pub use self::graph_kind::*;
+    pub use self::{graph_kind::*, interval::*};
     /// this module defines the two types of graph kinds: [`Directed`] and [`Undirected`]
     mod graph_kind;
+    /// this module defines the [`Interval`] type used to describe spans of time
+    mod interval;
 }
 // re-exports
 #[doc(inline)]
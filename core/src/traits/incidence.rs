@@ -0,0 +1,27 @@
+/*
+    appellation: incidence <module>
+    authors: @FL03
+*/
+use crate::error::Result;
+use crate::idx::{EdgeId, VertexId};
+use crate::{GraphProps, HyperGraph, Weight};
+
+/// The [`WeightedIncidence`] trait extends the [`HyperGraph`] trait for hypergraphs whose
+/// vertices are weighted according to the edges containing them, i.e. _edge-dependent vertex
+/// weights_. Each incidence between an edge and one of its vertices carries its own weight,
+/// allowing a vertex to play a different role within each of its edges (e.g. the position of
+/// an author on a paper).
+pub trait WeightedIncidence<N, E, A>: HyperGraph<N, E, A>
+where
+    A: GraphProps,
+{
+    /// the type of weight associated with each incidence
+    type Incidence;
+
+    /// returns the weight of the given vertex within the edge with the given index
+    fn get_incidence_weight(
+        &self,
+        edge: &EdgeId<A::Ix>,
+        vertex: &VertexId<A::Ix>,
+    ) -> Result<&Weight<Self::Incidence>>;
+}
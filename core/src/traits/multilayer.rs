@@ -0,0 +1,36 @@
+/*
+    appellation: multilayer <module>
+    authors: @FL03
+*/
+use crate::error::Result;
+use crate::idx::{EdgeId, LayerId, NodeLayer, VertexId};
+use crate::{GraphProps, HyperGraph};
+
+/// The [`MultiLayerHyperGraph`] trait extends the [`HyperGraph`] trait for hypergraphs whose
+/// edges are partitioned into several _layers_ defined over a shared set of vertices. Each
+/// vertex may then be considered within the context of a particular layer, forming a
+/// [`NodeLayer`] tuple, with the layers being joined together by _interlayer_ (coupling)
+/// edges.
+///
+/// Note that the methods inherited from the [`HyperGraph`] trait operate on the _aggregate_
+/// of the layers, i.e. the hypergraph containing the edges of every layer.
+pub trait MultiLayerHyperGraph<N, E, A>: HyperGraph<N, E, A>
+where
+    A: GraphProps,
+{
+    /// returns the number of layers within the graph
+    fn layer_count(&self) -> usize;
+    /// returns true if the graph contains a layer with the given index
+    fn contains_layer(&self, layer: &LayerId) -> bool {
+        *layer.get() < self.layer_count()
+    }
+    /// returns the index of the layer containing the (intralayer) edge with the given index
+    fn get_edge_layer(&self, index: &EdgeId<A::Ix>) -> Result<LayerId>;
+    /// returns an iterator over the node-layers that are coupled to the given vertex within
+    /// the given layer by some interlayer edge
+    fn find_coupled(
+        &self,
+        layer: &LayerId,
+        vertex: &VertexId<A::Ix>,
+    ) -> impl Iterator<Item = NodeLayer<A::Ix>>;
+}
@@ -0,0 +1,50 @@
+/*
+    appellation: nested <module>
+    authors: @FL03
+*/
+use crate::idx::{EdgeId, Grid, VertexId};
+use crate::{GraphProps, HyperGraph};
+
+/// The [`NestedHyperGraph`] trait extends the [`HyperGraph`] trait for _recursive_ hypergraphs
+/// (ubergraphs) whose edges may contain other edges alongside their vertices. The members of
+/// an edge are described using the [`Grid`], a unified index covering both vertices and
+/// edges, enabling relations about relations to be expressed.
+///
+/// Note that the domain returned by [`get_edge_domain`](HyperGraph::get_edge_domain) only
+/// contains the vertices that are _directly_ contained by the edge.
+pub trait NestedHyperGraph<N, E, A>: HyperGraph<N, E, A>
+where
+    A: GraphProps,
+{
+    /// returns an iterator over the vertices directly contained by the edge with the given
+    /// index
+    fn find_edge_vertices(&self, index: &EdgeId<A::Ix>) -> impl Iterator<Item = &VertexId<A::Ix>>;
+    /// returns an iterator over the edges directly contained by the edge with the given index
+    fn find_edge_children(&self, index: &EdgeId<A::Ix>) -> impl Iterator<Item = &EdgeId<A::Ix>>;
+    /// returns an iterator over the edges directly containing the edge with the given index
+    fn find_edge_parents(&self, index: &EdgeId<A::Ix>) -> impl Iterator<Item = &EdgeId<A::Ix>>;
+    /// returns true if the graph contains the element with the given index
+    fn contains_element(&self, index: &Grid<A::Ix>) -> bool {
+        match index {
+            Grid::Edge(edge) => self.contains_edge(edge),
+            Grid::Vertex(vertex) => self.contains_node(vertex),
+            Grid::Raw(_) => false,
+        }
+    }
+    /// returns an iterator over each of the members, vertices and edges alike, directly
+    /// contained by the edge with the given index
+    fn find_edge_members<'a>(
+        &'a self,
+        index: &'a EdgeId<A::Ix>,
+    ) -> impl Iterator<Item = Grid<A::Ix>> + 'a
+    where
+        A::Ix: 'a + Clone,
+        N: 'a,
+        E: 'a,
+    {
+        self.find_edge_vertices(index)
+            .cloned()
+            .map(Grid::Vertex)
+            .chain(self.find_edge_children(index).cloned().map(Grid::Edge))
+    }
+}
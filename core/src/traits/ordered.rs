@@ -0,0 +1,46 @@
+/*
+    appellation: ordered <module>
+    authors: @FL03
+*/
+use crate::error::Result;
+use crate::idx::{EdgeId, RawIndex, VertexId};
+use crate::{GraphProps, HyperGraph};
+
+/// The [`OrderedHyperGraph`] trait extends the [`HyperGraph`] trait for hypergraphs whose
+/// edges are _ordered_, preserving both the sequence and the multiplicity of their vertices.
+/// Ordered edges may then be treated as tuples, i.e. n-ary relations whose members are
+/// distinguished by their position, or as paths through their vertices.
+///
+/// Note that the domain returned by [`get_edge_domain`](HyperGraph::get_edge_domain) remains
+/// the (unordered) set of vertices contained by the edge.
+pub trait OrderedHyperGraph<N, E, A>: HyperGraph<N, E, A>
+where
+    A: GraphProps,
+{
+    /// returns the vertices of the edge with the given index in the order they were given
+    fn get_edge_sequence(&self, index: &EdgeId<A::Ix>) -> Result<&[VertexId<A::Ix>]>;
+    /// returns the number of positions within the edge with the given index, counting any
+    /// repeated vertices
+    fn get_edge_arity(&self, index: &EdgeId<A::Ix>) -> Result<usize> {
+        self.get_edge_sequence(index).map(<[_]>::len)
+    }
+    /// returns an iterator over the vertices immediately following the given vertex within
+    /// the sequences of the edges containing it
+    fn find_successors<'a>(
+        &'a self,
+        index: &'a VertexId<A::Ix>,
+    ) -> impl Iterator<Item = VertexId<A::Ix>> + 'a
+    where
+        A::Ix: 'a + RawIndex + Copy + PartialEq,
+        N: 'a,
+        E: 'a,
+    {
+        self.find_edges_with_node(index)
+            .filter_map(|edge_id| self.get_edge_sequence(edge_id).ok())
+            .flat_map(move |seq| {
+                seq.windows(2)
+                    .filter(move |pair| pair[0] == *index)
+                    .map(|pair| pair[1])
+            })
+    }
+}
@@ -0,0 +1,88 @@
+/*
+    appellation: oriented <module>
+    authors: @FL03
+*/
+use crate::idx::{EdgeId, RawIndex, VertexId};
+use crate::{GraphProps, HyperGraph, Mode, RawLayout};
+
+/// The [`OrientedHyperGraph`] trait extends the [`HyperGraph`] trait for hypergraphs whose
+/// directedness is decided at runtime by some [`Mode`] rather than by the kind of the graph.
+/// When [directed](Mode::Directed), each edge may be _oriented_ from its tail towards its
+/// head, meaning the edge may only be used to move from a member of its tail onto a member of
+/// its head; edges without an orientation are treated as though both of their ends were the
+/// entire domain. When [undirected](Mode::Undirected), every edge is treated as such.
+pub trait OrientedHyperGraph<N, E, A>: HyperGraph<N, E, A>
+where
+    A: GraphProps,
+{
+    /// returns the mode used to interpret the edges of the graph
+    fn mode(&self) -> Mode;
+    /// returns true if the edge with the given index may be used to leave the given vertex
+    fn is_edge_tail(&self, index: &EdgeId<A::Ix>, vertex: &VertexId<A::Ix>) -> bool;
+    /// returns true if the edge with the given index may be used to reach the given vertex
+    fn is_edge_head(&self, index: &EdgeId<A::Ix>, vertex: &VertexId<A::Ix>) -> bool;
+    /// returns an iterator over the edges that may be used to leave the given vertex
+    fn find_out_edges<'a>(
+        &'a self,
+        index: &'a VertexId<A::Ix>,
+    ) -> impl Iterator<Item = &'a EdgeId<A::Ix>> + 'a
+    where
+        N: 'a,
+        E: 'a,
+    {
+        self.find_edges_with_node(index)
+            .filter(move |edge_id| self.is_edge_tail(edge_id, index))
+    }
+    /// returns an iterator over the edges that may be used to reach the given vertex
+    fn find_in_edges<'a>(
+        &'a self,
+        index: &'a VertexId<A::Ix>,
+    ) -> impl Iterator<Item = &'a EdgeId<A::Ix>> + 'a
+    where
+        N: 'a,
+        E: 'a,
+    {
+        self.find_edges_with_node(index)
+            .filter(move |edge_id| self.is_edge_head(edge_id, index))
+    }
+    /// returns an iterator over the vertices reachable from the given vertex using a single
+    /// edge; vertices reachable through several edges are yielded once for each of them.
+    fn find_successors<'a>(
+        &'a self,
+        index: &'a VertexId<A::Ix>,
+    ) -> impl Iterator<Item = VertexId<A::Ix>> + 'a
+    where
+        A::Ix: 'a + RawIndex + Copy + PartialEq,
+        N: 'a,
+        E: 'a,
+        for<'b> &'b <Self::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+    {
+        self.find_out_edges(index).flat_map(move |edge_id| {
+            self.get_edge_domain(edge_id)
+                .into_iter()
+                .flatten()
+                .filter(move |v| *v != index && self.is_edge_head(edge_id, v))
+                .copied()
+        })
+    }
+    /// returns an iterator over the vertices capable of reaching the given vertex using a
+    /// single edge; vertices reaching it through several edges are yielded once for each.
+    fn find_predecessors<'a>(
+        &'a self,
+        index: &'a VertexId<A::Ix>,
+    ) -> impl Iterator<Item = VertexId<A::Ix>> + 'a
+    where
+        A::Ix: 'a + RawIndex + Copy + PartialEq,
+        N: 'a,
+        E: 'a,
+        for<'b> &'b <Self::Edge<E> as RawLayout>::Store: IntoIterator<Item = &'b VertexId<A::Ix>>,
+    {
+        self.find_in_edges(index).flat_map(move |edge_id| {
+            self.get_edge_domain(edge_id)
+                .into_iter()
+                .flatten()
+                .filter(move |v| *v != index && self.is_edge_tail(edge_id, v))
+                .copied()
+        })
+    }
+}
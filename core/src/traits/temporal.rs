@@ -0,0 +1,27 @@
+/*
+    appellation: temporal <module>
+    authors: @FL03
+*/
+use crate::error::Result;
+use crate::idx::EdgeId;
+use crate::types::Interval;
+use crate::{GraphProps, HyperGraph};
+
+/// The [`TemporalHyperGraph`] trait extends the [`HyperGraph`] trait for hypergraphs whose
+/// edges are only active during some [`Interval`] of time, enabling the design of algorithms
+/// that respect the chronology of the graph (e.g. time-respecting paths).
+pub trait TemporalHyperGraph<N, E, A>: HyperGraph<N, E, A>
+where
+    A: GraphProps,
+{
+    /// the type used to represent points in time
+    type Time: Copy + Ord;
+
+    /// returns the interval during which the edge with the given index is active
+    fn get_edge_interval(&self, index: &EdgeId<A::Ix>) -> Result<&Interval<Self::Time>>;
+    /// returns true if the edge with the given index is active at the given time
+    fn is_edge_active(&self, index: &EdgeId<A::Ix>, time: &Self::Time) -> bool {
+        self.get_edge_interval(index)
+            .is_ok_and(|interval| interval.contains(time))
+    }
+}
@@ -0,0 +1,107 @@
+/*
+    appellation: interval <module>
+    authors: @FL03
+*/
+
+/// The [`Interval`] represents a closed span of time `[start, end]`, used to describe when a
+/// component of a temporal hypergraph is active; an _instant_ is an interval whose start and
+/// end coincide.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Interval<T> {
+    pub(crate) start: T,
+    pub(crate) end: T,
+}
+
+impl<T> Interval<T> {
+    /// returns a new interval spanning the given bounds; the bounds are reordered if the
+    /// `start` is greater than the `end`.
+    pub fn new(start: T, end: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        if start > end {
+            Self {
+                start: end,
+                end: start,
+            }
+        } else {
+            Self { start, end }
+        }
+    }
+    /// returns a new interval representing the given instant in time
+    pub fn instant(time: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            start: time.clone(),
+            end: time,
+        }
+    }
+    /// returns an interval spanning every representable point in time
+    pub fn always() -> Self
+    where
+        T: num_traits::Bounded,
+    {
+        Self {
+            start: T::min_value(),
+            end: T::max_value(),
+        }
+    }
+    /// returns an immutable reference to the start of the interval
+    pub const fn start(&self) -> &T {
+        &self.start
+    }
+    /// returns an immutable reference to the end of the interval
+    pub const fn end(&self) -> &T {
+        &self.end
+    }
+    /// consumes the interval to return its bounds as a `(start, end)` tuple
+    pub fn into_tuple(self) -> (T, T) {
+        (self.start, self.end)
+    }
+    /// returns true if the start and end of the interval coincide
+    pub fn is_instant(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.start == self.end
+    }
+    /// returns true if the given point in time lies within the interval
+    pub fn contains(&self, time: &T) -> bool
+    where
+        T: PartialOrd,
+    {
+        &self.start <= time && time <= &self.end
+    }
+    /// returns true if the interval shares at least one point in time with the other
+    pub fn overlaps(&self, other: &Self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl<T> From<(T, T)> for Interval<T>
+where
+    T: PartialOrd,
+{
+    fn from((start, end): (T, T)) -> Self {
+        Self::new(start, end)
+    }
+}
+
+impl<T> core::fmt::Display for Interval<T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{}, {}]", self.start, self.end)
+    }
+}
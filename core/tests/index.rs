@@ -72,3 +72,21 @@ fn test_tracker() -> rshyper::Result<()> {
     assert_eq!(history.nodes(), &[v0, v1, v2]);
     Ok(())
 }
+
+#[test]
+fn test_index_cast() -> rshyper::Result<()> {
+    let mut history = IndexTracker::<usize>::zero();
+    history.next_edge()?;
+    history.next_vertex()?;
+    // narrowing the indices preserves their values
+    let narrow = history.clone().try_cast::<u32>()?;
+    assert_eq!(narrow.cursor().edge(), &1);
+    assert_eq!(narrow.edges(), &[EdgeId::new(0u32)]);
+    // values that overflow the target type are rejected
+    let overflow = VertexId::new(usize::MAX).try_cast::<u32>();
+    assert!(matches!(
+        overflow,
+        Err(rshyper::idx::IndexError::IndexOutOfBounds)
+    ));
+    Ok(())
+}
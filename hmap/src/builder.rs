@@ -0,0 +1,282 @@
+/*
+    appellation: builder <module>
+    authors: @FL03
+*/
+//! this module implements the [`HyperMapBuilder`], a utility for constructing a [`HyperMap`]
+//! in bulk from a collection of `(vertices, weight)` records.
+use crate::{EdgeMap, HyperMap, NodeMap};
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{GraphProps, UnAttrs};
+use rshyper_core::edge::Edge;
+use rshyper_core::idx::{EdgeId, HyperIndex, IndexError, IndexFrame, IndexTracker, Udx, VertexId};
+use rshyper_core::{Error, GraphType, Node, Result, VertexSet, Weight};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// a type alias for a [`HashMap`] associating the user-defined vertex keys of the records
+/// with the [`VertexId`] assigned to them by the [`HyperMapBuilder`]
+pub type VertexKeys<V, Ix, S = DefaultHashBuilder> = HashMap<V, VertexId<Ix>, S>;
+/// a type alias for the output of the [`HyperMapBuilder`]; a tuple containing the graph
+/// alongside the [`VertexKeys`] of its vertices
+pub type BuildOutput<N, E, A, V, S = DefaultHashBuilder> = (
+    HyperMap<N, E, A, S>,
+    VertexKeys<V, <A as GraphProps>::Ix, S>,
+);
+
+/// The [`HyperMapBuilder`] constructs a [`HyperMap`] in bulk from a collection of records,
+/// each composed of a list of vertices and the weight of the corresponding hyperedge.
+///
+/// ## Overview
+///
+/// Rather than adding the edges one-by-one, the builder pre-sizes the underlying stores
+/// before inserting every entry at once. The vertices of the records are identified by some
+/// user-defined key `V`, which is deduplicated before being assigned a [`VertexId`] according
+/// to its first appearance; likewise, each edge is assigned the [`EdgeId`] corresponding to
+/// the position of its record. As a result, the ids of the resulting graph are fully
+/// determined by the order of the records, meaning that the sequential
+/// [`build`](HyperMapBuilder::build) and the parallel [`par_build`](HyperMapBuilder::par_build)
+/// produce identical graphs regardless of the number of threads used.
+///
+/// Each node is created using the logical default of its weight.
+pub struct HyperMapBuilder<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the expected number of edges
+    pub(crate) edges: usize,
+    /// the expected number of nodes
+    pub(crate) nodes: usize,
+    /// the hasher used to create the stores of the graph
+    pub(crate) hash_builder: S,
+    pub(crate) _marker: PhantomData<(N, E, A)>,
+}
+
+impl<N, E, A, K, Ix, S> HyperMapBuilder<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// returns a new builder using the default [`BuildHasher`]
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self::with_hasher(S::default())
+    }
+    /// returns a new builder configured with the given [`BuildHasher`]
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            edges: 0,
+            nodes: 0,
+            hash_builder,
+            _marker: PhantomData,
+        }
+    }
+    /// consumes the current instance to create another with the given capacity hints for
+    /// the edges and nodes of the graph
+    pub fn with_capacity(self, edges: usize, nodes: usize) -> Self {
+        Self {
+            edges,
+            nodes,
+            ..self
+        }
+    }
+    /// returns an immutable reference to the [`BuildHasher`] used by the builder
+    pub const fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+    /// sequentially constructs a [`HyperMap`] from the given records, returning the graph
+    /// alongside the [`VertexId`] assigned to each of the vertex keys.
+    pub fn build<V, D, I>(self, records: I) -> Result<BuildOutput<N, E, A, V, S>>
+    where
+        I: IntoIterator<Item = (D, E)>,
+        D: IntoIterator<Item = V>,
+        V: Eq + Hash,
+        N: Default,
+    {
+        let records = records.into_iter();
+        let capacity = self.edges.max(records.size_hint().0);
+        let mut keys = VertexKeys::with_capacity_and_hasher(self.nodes, self.hasher().clone());
+        let mut edges = EdgeMap::with_capacity_and_hasher(capacity, self.hasher().clone());
+        for (pos, (vertices, weight)) in records.enumerate() {
+            let vertices = vertices.into_iter();
+            let mut domain =
+                VertexSet::with_capacity_and_hasher(vertices.size_hint().0, self.hasher().clone());
+            for key in vertices {
+                let next = keys.len();
+                let vertex = match keys.entry(key) {
+                    hashbrown::hash_map::Entry::Occupied(entry) => *entry.get(),
+                    hashbrown::hash_map::Entry::Vacant(entry) => {
+                        *entry.insert(VertexId::new(index_from_usize(next)?))
+                    }
+                };
+                domain.insert(vertex);
+            }
+            if domain.is_empty() {
+                return Err(Error::EmptyHyperedge);
+            }
+            let id = EdgeId::new(index_from_usize(pos)?);
+            edges.insert(id, Edge::from_parts(id, domain, Weight(weight)));
+        }
+        let mut nodes = NodeMap::with_capacity_and_hasher(keys.len(), self.hasher().clone());
+        nodes.extend(
+            keys.values()
+                .map(|&id| (id, Node::new(id, Default::default()))),
+        );
+        let graph = self.finish(nodes, edges)?;
+        Ok((graph, keys))
+    }
+    /// constructs a [`HyperMap`] from the given records in parallel, returning the graph
+    /// alongside the [`VertexId`] assigned to each of the vertex keys; the result is identical
+    /// to that of [`build`](HyperMapBuilder::build).
+    #[cfg(feature = "rayon")]
+    pub fn par_build<V, D, I>(self, records: I) -> Result<BuildOutput<N, E, A, V, S>>
+    where
+        I: rayon::iter::IntoParallelIterator<Item = (D, E)>,
+        I::Iter: rayon::iter::IndexedParallelIterator,
+        D: IntoIterator<Item = V>,
+        V: Eq + Hash + Clone + Send + Sync,
+        N: Default + Send,
+        E: Send,
+        S: Send + Sync,
+    {
+        use rayon::prelude::*;
+        let hasher = self.hasher();
+        // materialize the records while preserving their order
+        let (domains, weights): (Vec<Vec<V>>, Vec<E>) = records
+            .into_par_iter()
+            .map(|(vertices, weight)| (vertices.into_iter().collect(), weight))
+            .unzip();
+        // find the first appearance of each vertex key
+        let first_seen = domains
+            .par_iter()
+            .enumerate()
+            .fold(
+                || HashMap::with_hasher(hasher.clone()),
+                |mut acc, (i, vertices)| {
+                    for (j, key) in vertices.iter().enumerate() {
+                        acc.entry(key).or_insert((i, j));
+                    }
+                    acc
+                },
+            )
+            .reduce(
+                || HashMap::with_hasher(hasher.clone()),
+                |mut lhs, mut rhs| {
+                    if lhs.len() < rhs.len() {
+                        core::mem::swap(&mut lhs, &mut rhs);
+                    }
+                    for (key, pos) in rhs {
+                        lhs.entry(key)
+                            .and_modify(|prev: &mut (usize, usize)| *prev = (*prev).min(pos))
+                            .or_insert(pos);
+                    }
+                    lhs
+                },
+            );
+        // order the keys by their first appearance before assigning their ids
+        let mut order = first_seen.into_iter().collect::<Vec<_>>();
+        order.par_sort_unstable_by_key(|(_, pos)| *pos);
+        let mut keys = VertexKeys::with_capacity_and_hasher(order.len(), hasher.clone());
+        keys.par_extend(
+            order
+                .into_par_iter()
+                .enumerate()
+                .map(|(i, (key, _))| Ok((key.clone(), VertexId::new(index_from_usize(i)?))))
+                .collect::<Result<Vec<_>>>()?,
+        );
+        // construct the edges
+        let entries = domains
+            .par_iter()
+            .zip(weights)
+            .enumerate()
+            .map(|(pos, (vertices, weight))| {
+                if vertices.is_empty() {
+                    return Err(Error::EmptyHyperedge);
+                }
+                let mut domain =
+                    VertexSet::with_capacity_and_hasher(vertices.len(), hasher.clone());
+                domain.extend(vertices.iter().map(|key| keys[key]));
+                let id = EdgeId::new(index_from_usize(pos)?);
+                Ok((id, Edge::from_parts(id, domain, Weight(weight))))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut edges = EdgeMap::with_capacity_and_hasher(entries.len(), hasher.clone());
+        edges.par_extend(entries);
+        // construct the nodes
+        let mut nodes = NodeMap::with_capacity_and_hasher(keys.len(), hasher.clone());
+        nodes.par_extend(
+            keys.par_values()
+                .map(|&id| (id, Node::new(id, Default::default()))),
+        );
+        let graph = self.finish(nodes, edges)?;
+        Ok((graph, keys))
+    }
+    /// assembles the graph from the given stores, recording the history of the dense indices
+    fn finish(
+        self,
+        nodes: NodeMap<N, Ix, S>,
+        edges: EdgeMap<E, K, Ix, S>,
+    ) -> Result<HyperMap<N, E, A, S>> {
+        let (order, size) = (nodes.len(), edges.len());
+        let history = IndexTracker::from_cursor(IndexFrame::new(
+            EdgeId::new(index_from_usize(size)?),
+            VertexId::new(index_from_usize(order)?),
+        ))
+        .with_nodes(
+            (0..order)
+                .map(|i| index_from_usize(i).map(VertexId::new))
+                .collect::<Result<Vec<_>>>()?,
+        )
+        .with_edges(
+            (0..size)
+                .map(|i| index_from_usize(i).map(EdgeId::new))
+                .collect::<Result<Vec<_>>>()?,
+        );
+        let mut graph = HyperMap::with_hasher(self.hash_builder);
+        graph
+            .set_nodes(nodes)
+            .set_edges(edges)
+            .set_history(history)
+            .reindex();
+        Ok(graph)
+    }
+}
+
+impl<N, E, A, S> Default for HyperMapBuilder<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    A::Ix: HyperIndex,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, K, Ix, S> HyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// returns a new [`HyperMapBuilder`] for constructing the graph in bulk
+    pub fn builder() -> HyperMapBuilder<N, E, A, S> {
+        HyperMapBuilder::new()
+    }
+}
+
+/// converts the given position into an index, returning an error if it cannot be represented
+fn index_from_usize<Ix>(value: usize) -> Result<Ix>
+where
+    Ix: HyperIndex,
+{
+    Ix::from_usize(value).ok_or(IndexError::IndexOutOfBounds.into())
+}
@@ -0,0 +1,373 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use super::Orientation;
+use crate::HyperMap;
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{DynAttrs, GraphProps};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, RawIndex, Udx, VertexId};
+use rshyper_core::{Error, Mode, Node, OrientedHyperGraph, Result, VertexSet, Weight};
+
+/// a type alias for a map associating each oriented edge with its [`Orientation`]
+pub type OrientationMap<Ix, S = DefaultHashBuilder> = HashMap<EdgeId<Ix>, Orientation<Ix, S>, S>;
+
+/// The [`DynamicHyperMap`] is a hypergraph whose directedness is decided at runtime by some
+/// [`Mode`], rather than by the kind of the graph, making it well-suited for applications
+/// whose configuration determines whether the graph is directed.
+///
+/// ## Overview
+///
+/// The graph wraps a standard [`HyperMap`], recording an [`Orientation`] for each of the
+/// edges given a tail and head. When [directed](Mode::Directed), an oriented edge may only be
+/// used to move from its tail onto its head while any other edge may be used to move between
+/// each of its members. When [undirected](Mode::Undirected), every edge is treated as such,
+/// although the orientations are retained, allowing the mode to be switched freely. Use the
+/// [`OrientedHyperGraph`] trait to write algorithms that respect the current mode; of the
+/// provided algorithms, only the oriented breadth-first traversal does so, while the others
+/// treat every edge as undirected.
+pub struct DynamicHyperMap<N = (), E = (), A = DynAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the underlying hypergraph
+    pub(crate) graph: HyperMap<N, E, A, S>,
+    /// the mode used to interpret the edges of the graph
+    pub(crate) mode: Mode,
+    /// the orientation of each oriented edge
+    pub(crate) orientations: OrientationMap<A::Ix, S>,
+}
+
+impl<N, E, A, Ix, S> DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = Mode>,
+    S: BuildHasher,
+    Ix: RawIndex,
+{
+    /// returns a new, empty graph using the given mode
+    pub fn new(mode: Mode) -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self::from_graph(HyperMap::new(), mode)
+    }
+    /// returns a new, empty [directed](Mode::Directed) graph
+    pub fn directed() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self::new(Mode::Directed)
+    }
+    /// returns a new, empty [undirected](Mode::Undirected) graph
+    pub fn undirected() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self::new(Mode::Undirected)
+    }
+    /// returns a new graph from the given [`HyperMap`] using the given mode; none of the
+    /// edges are oriented.
+    pub fn from_graph(graph: HyperMap<N, E, A, S>, mode: Mode) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            graph,
+            mode,
+            orientations: OrientationMap::default(),
+        }
+    }
+    /// returns an immutable reference to the underlying [`HyperMap`]
+    pub const fn graph(&self) -> &HyperMap<N, E, A, S> {
+        &self.graph
+    }
+    /// returns the mode currently used to interpret the edges of the graph
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+    /// returns an immutable reference to the orientations of the edges
+    pub const fn orientations(&self) -> &OrientationMap<Ix, S> {
+        &self.orientations
+    }
+    /// consumes the instance to return the underlying [`HyperMap`], discarding the
+    /// orientation of each edge
+    pub fn into_graph(self) -> HyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// update the mode used to interpret the edges of the graph; the orientation of each edge
+    /// is retained, so switching back restores the previous behaviour.
+    pub fn set_mode(&mut self, mode: Mode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+    /// returns true if the graph is currently [directed](Mode::Directed)
+    pub const fn is_directed(&self) -> bool {
+        matches!(self.mode, Mode::Directed)
+    }
+    /// returns true if the graph is currently [undirected](Mode::Undirected)
+    pub const fn is_undirected(&self) -> bool {
+        matches!(self.mode, Mode::Undirected)
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.graph.order()
+    }
+    /// returns the number of edges within the graph
+    pub fn size(&self) -> usize {
+        self.graph.size()
+    }
+}
+
+impl<N, E, A, Ix, S> DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = Mode>,
+    S: BuildHasher + Clone + Default,
+    Ix: HyperIndex,
+{
+    /// add a new node with the given weight to the graph
+    pub fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        self.graph.add_node(weight)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.graph.add_vertex()
+    }
+    /// add a new, unoriented edge composed of the given vertices
+    pub fn add_edge<I>(&mut self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        self.graph.add_edge(vertices, weight)
+    }
+    /// add a new, unoriented edge composed of the given vertices using the logical default
+    /// for its weight
+    pub fn add_link<I>(&mut self, vertices: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        E: Default,
+    {
+        self.add_edge(vertices, Default::default())
+    }
+    /// add a new edge oriented from the given tail towards the given head, whose domain is
+    /// the union of the two.
+    pub fn add_arc<I, J>(&mut self, tail: I, head: J, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        J: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let tail = tail.into_iter().collect::<VertexSet<Ix, S>>();
+        let head = head.into_iter().collect::<VertexSet<Ix, S>>();
+        if tail.is_empty() || head.is_empty() {
+            return Err(Error::InvalidOrientation);
+        }
+        if tail.iter().chain(&head).any(|v| !self.contains_node(v)) {
+            return Err(Error::NodeNotFound);
+        }
+        let id = self
+            .graph
+            .add_edge(tail.iter().chain(&head).copied(), weight)?;
+        self.orientations.insert(id, Orientation::new(tail, head));
+        Ok(id)
+    }
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, Mode, Ix, S>> {
+        self.graph.get_edge(index)
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.graph.get_node(index)
+    }
+    /// returns the orientation of the edge with the given index, if any
+    pub fn get_orientation(&self, index: &EdgeId<Ix>) -> Option<&Orientation<Ix, S>> {
+        self.orientations.get(index)
+    }
+    /// returns true if the edge with the given index has been oriented
+    pub fn is_oriented(&self, index: &EdgeId<Ix>) -> bool {
+        self.orientations.contains_key(index)
+    }
+    /// orient the edge with the given index from the given tail towards the given head; an
+    /// [`InvalidOrientation`](Error::InvalidOrientation) error is returned unless the tail and
+    /// head are both non-empty subsets of the domain whose union covers it.
+    pub fn set_orientation<I, J>(&mut self, index: &EdgeId<Ix>, tail: I, head: J) -> Result<()>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        J: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let domain = self.graph.get_domain(index)?;
+        let orientation = Orientation::new(tail.into_iter().collect(), head.into_iter().collect());
+        if !is_valid_orientation(domain, &orientation) {
+            return Err(Error::InvalidOrientation);
+        }
+        self.orientations.insert(*index, orientation);
+        Ok(())
+    }
+    /// removes the orientation of the edge with the given index, returning it if it existed
+    pub fn clear_orientation(&mut self, index: &EdgeId<Ix>) -> Option<Orientation<Ix, S>> {
+        self.orientations.remove(index)
+    }
+    /// reverse the orientation of every oriented edge
+    pub fn reverse(&mut self) -> &mut Self {
+        self.orientations.values_mut().for_each(|o| {
+            o.reverse();
+        });
+        self
+    }
+    /// converts the graph into its undirected form, discarding the orientation of every edge
+    pub fn symmetrize(&mut self) -> &mut Self {
+        self.orientations.clear();
+        self.set_mode(Mode::Undirected)
+    }
+    /// converts the graph into its directed form, orienting each edge using the given rule.
+    /// The rule is given the index and domain of each edge, returning the tail and head of
+    /// the edge or [`None`] to leave the edge unoriented, discarding any orientation the edge
+    /// previously held. Each of the orientations is
+    /// validated before any are applied, returning an
+    /// [`InvalidOrientation`](Error::InvalidOrientation) error should any of them be invalid.
+    pub fn orient_with<F>(&mut self, mut rule: F) -> Result<&mut Self>
+    where
+        F: FnMut(&EdgeId<Ix>, &VertexSet<Ix, S>) -> Option<(VertexSet<Ix, S>, VertexSet<Ix, S>)>,
+    {
+        let mut oriented = OrientationMap::default();
+        for (id, edge) in self.graph.edges() {
+            if let Some((tail, head)) = rule(id, edge.domain()) {
+                let orientation = Orientation::new(tail, head);
+                if !is_valid_orientation(edge.domain(), &orientation) {
+                    return Err(Error::InvalidOrientation);
+                }
+                oriented.insert(*id, orientation);
+            }
+        }
+        self.orientations = oriented;
+        Ok(self.set_mode(Mode::Directed))
+    }
+    /// converts the graph into its directed form, orienting each edge from the members with
+    /// the least key towards the remaining members; edges whose members all share the same
+    /// key are left unoriented, discarding any orientation they previously held.
+    pub fn orient_by_key<K, F>(&mut self, mut key: F) -> Result<&mut Self>
+    where
+        K: Ord,
+        F: FnMut(&VertexId<Ix>, &Weight<N>) -> K,
+    {
+        // compute the key of each vertex up front
+        let keys = self
+            .graph
+            .nodes()
+            .iter()
+            .map(|(id, node)| (*id, key(id, node.weight())))
+            .collect::<HashMap<_, _>>();
+        self.orient_with(|_, domain| {
+            let least = domain.iter().map(|v| &keys[v]).min()?;
+            let (tail, head): (VertexSet<Ix, S>, VertexSet<Ix, S>) =
+                domain.iter().partition(|v| &keys[*v] == least);
+            (!head.is_empty()).then_some((tail, head))
+        })
+    }
+    /// returns the set of vertices reachable from the given vertex using a single edge,
+    /// respecting the current mode of the graph
+    pub fn find_node_neighbors(&self, index: &VertexId<Ix>) -> Result<VertexSet<Ix, S>> {
+        if !self.contains_node(index) {
+            return Err(Error::NodeNotFound);
+        }
+        Ok(self.find_successors(index).collect())
+    }
+    /// returns the number of edges that may be used to leave the given vertex
+    pub fn out_degree(&self, index: &VertexId<Ix>) -> usize {
+        self.find_out_edges(index).count()
+    }
+    /// returns the number of edges that may be used to reach the given vertex
+    pub fn in_degree(&self, index: &VertexId<Ix>) -> usize {
+        self.find_in_edges(index).count()
+    }
+    /// removes the edge with the given index alongside its orientation
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<HashEdge<E, Mode, Ix, S>> {
+        let edge = self.graph.remove_edge(index)?;
+        self.orientations.remove(index);
+        Ok(edge)
+    }
+    /// removes the node with the given index alongside each of the edges containing it
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let node = self.graph.remove_node(index)?;
+        let graph = &self.graph;
+        self.orientations.retain(|id, _| graph.contains_edge(id));
+        Ok(node)
+    }
+}
+
+impl<N, E, A, S> Clone for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    A::Ix: Clone,
+    S: BuildHasher + Clone,
+    HyperMap<N, E, A, S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            mode: self.mode,
+            orientations: self.orientations.clone(),
+        }
+    }
+}
+
+impl<N, E, A, S> Default for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = Mode>,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new(Mode::default())
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynamicHyperMap")
+            .field("graph", &self.graph)
+            .field("mode", &self.mode)
+            .field("orientations", &self.orientations)
+            .finish()
+    }
+}
+
+/// returns true if the tail and head of the orientation are non-empty subsets of the domain
+/// whose union covers it
+fn is_valid_orientation<Ix, S>(domain: &VertexSet<Ix, S>, orientation: &Orientation<Ix, S>) -> bool
+where
+    Ix: HyperIndex,
+    S: BuildHasher,
+{
+    let Orientation { tail, head } = orientation;
+    !tail.is_empty()
+        && !head.is_empty()
+        && tail.is_subset(domain)
+        && head.is_subset(domain)
+        && domain.iter().all(|v| tail.contains(v) || head.contains(v))
+}
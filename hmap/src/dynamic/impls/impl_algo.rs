@@ -0,0 +1,22 @@
+/*
+    appellation: impl_algo <module>
+    authors: @FL03
+*/
+use crate::dynamic::DynamicHyperMap;
+use core::hash::BuildHasher;
+use rshyper_algo::OrientedBreadthFirst;
+use rshyper_core::{GraphProps, HyperIndex, Mode};
+
+/// implementations for the orientation-aware algorithms, respecting the current mode of the
+/// hypergraph
+impl<N, E, A, S> DynamicHyperMap<N, E, A, S>
+where
+    S: BuildHasher + Clone + Default,
+    A: GraphProps<Kind = Mode>,
+    A::Ix: HyperIndex,
+{
+    /// returns the [`OrientedBreadthFirst`] operator configured with the current hypergraph
+    pub fn oriented_bft(&self) -> OrientedBreadthFirst<'_, N, E, A, Self, S> {
+        OrientedBreadthFirst::new(self)
+    }
+}
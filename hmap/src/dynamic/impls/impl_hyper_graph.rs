@@ -0,0 +1,187 @@
+/*
+    appellation: impl_hyper_graph <module>
+    authors: @FL03
+*/
+use crate::dynamic::DynamicHyperMap;
+use crate::iter;
+use core::hash::{BuildHasher, Hash};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper_core::traits::{
+    HyperGraph, HyperGraphIterEdge, HyperGraphIterNode, OrientedHyperGraph, RawHyperGraph,
+};
+use rshyper_core::{GraphProps, Mode, Node, Result, VertexSet, Weight};
+
+impl<N, E, A, S> RawHyperGraph<A> for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    type Node<_N> = Node<_N, A::Ix>;
+    type Edge<_E> = HashEdge<_E, A::Kind, A::Ix, S>;
+}
+
+/// edges inserted through the [`HyperGraph`] interface are left unoriented, while borrowing
+/// the domain of an edge mutably, using [`get_edge_domain_mut`](HyperGraph::get_edge_domain_mut),
+/// drops its orientation since the tail and head may no longer cover it; the edge may be
+/// re-oriented using
+/// [`set_orientation`](DynamicHyperMap::set_orientation).
+impl<N, E, A, Ix, S> HyperGraph<N, E, A> for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = Mode, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    Ix: HyperIndex,
+{
+    fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<A::Ix>> {
+        self.add_node(weight)
+    }
+
+    fn add_surface<I>(&mut self, iter: I, weight: Weight<E>) -> Result<EdgeId<A::Ix>>
+    where
+        I: IntoIterator<Item = VertexId<A::Ix>>,
+    {
+        self.add_edge(iter, weight)
+    }
+
+    fn get_edge_domain(&self, index: &EdgeId<A::Ix>) -> Result<&VertexSet<A::Ix, S>> {
+        self.graph.get_domain(index)
+    }
+
+    fn get_edge_domain_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut VertexSet<A::Ix, S>> {
+        let domain = self.graph.get_domain_mut(index)?;
+        self.orientations.remove(index);
+        Ok(domain)
+    }
+
+    fn get_edge(&self, index: &EdgeId<A::Ix>) -> Result<&HashEdge<E, A::Kind, A::Ix, S>> {
+        self.graph.get_edge(index)
+    }
+
+    fn get_edge_mut(
+        &mut self,
+        index: &EdgeId<A::Ix>,
+    ) -> Result<&mut HashEdge<E, A::Kind, A::Ix, S>> {
+        self.graph.get_edge_mut(index)
+    }
+
+    fn get_edge_weight(&self, index: &EdgeId<A::Ix>) -> Result<&Weight<E>> {
+        self.graph.get_edge_weight(index)
+    }
+
+    fn get_edge_weight_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut Weight<E>> {
+        self.graph.get_edge_weight_mut(index)
+    }
+
+    fn get_node(&self, index: &VertexId<A::Ix>) -> Result<&Node<N, A::Ix>> {
+        self.graph.get_node(index)
+    }
+
+    fn get_node_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Node<N, A::Ix>> {
+        self.graph.get_node_mut(index)
+    }
+
+    fn get_node_weight(&self, index: &VertexId<A::Ix>) -> Result<&Weight<N>> {
+        self.graph.get_node_weight(index)
+    }
+
+    fn get_node_weight_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Weight<N>> {
+        self.graph.get_node_weight_mut(index)
+    }
+
+    fn contains_edge(&self, index: &EdgeId<A::Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+
+    fn contains_node(&self, index: &VertexId<A::Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+
+    fn find_edges_with_node(
+        &self,
+        index: &VertexId<A::Ix>,
+    ) -> impl Iterator<Item = &EdgeId<A::Ix>> {
+        self.graph.find_edges_with_node(index)
+    }
+}
+
+/// oriented edges are only respected while the graph is [directed](Mode::Directed)
+impl<N, E, A, Ix, S> OrientedHyperGraph<N, E, A> for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = Mode, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    Ix: HyperIndex,
+{
+    fn mode(&self) -> Mode {
+        self.mode()
+    }
+
+    fn is_edge_tail(&self, index: &EdgeId<A::Ix>, vertex: &VertexId<A::Ix>) -> bool {
+        match self.get_orientation(index) {
+            Some(orientation) if self.is_directed() => orientation.is_tail(vertex),
+            _ => self.graph.is_node_in_domain(index, vertex),
+        }
+    }
+
+    fn is_edge_head(&self, index: &EdgeId<A::Ix>, vertex: &VertexId<A::Ix>) -> bool {
+        match self.get_orientation(index) {
+            Some(orientation) if self.is_directed() => orientation.is_head(vertex),
+            _ => self.graph.is_node_in_domain(index, vertex),
+        }
+    }
+}
+
+impl<N, E, A, S> HyperGraphIterNode<N, E, A> for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = Mode>,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+{
+    type Nodes<'a>
+        = iter::NodeIter<'a, N, A::Ix>
+    where
+        Self: 'a,
+        Self::Node<N>: 'a;
+    type Verts<'a>
+        = iter::NodeKeys<'a, N, A::Ix>
+    where
+        Self: 'a;
+
+    fn iter_nodes(&self) -> Self::Nodes<'_> {
+        self.graph.iter_nodes()
+    }
+
+    fn vertices(&self) -> Self::Verts<'_> {
+        self.graph.vertices()
+    }
+}
+
+impl<N, E, A, S> HyperGraphIterEdge<N, E, A> for DynamicHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = Mode>,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+{
+    type Surfaces<'a>
+        = iter::EdgeIter<'a, E, A::Kind, A::Ix, S>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    type Edges<'a>
+        = iter::EdgeKeys<'a, E, A::Kind, A::Ix, S>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    fn iter_surfaces(&self) -> Self::Surfaces<'_> {
+        self.graph.iter_edges()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        self.graph.iter_edge_keys()
+    }
+}
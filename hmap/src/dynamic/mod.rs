@@ -0,0 +1,24 @@
+/*
+    appellation: dynamic <module>
+    authors: @FL03
+*/
+//! this module implements the [`DynamicHyperMap`], a hypergraph whose directedness is decided
+//! at runtime using a [`Mode`](rshyper_core::Mode).
+#[doc(inline)]
+pub use self::{graph::*, orientation::*};
+
+mod graph;
+mod orientation;
+
+mod impls {
+    #[cfg(feature = "algo")]
+    pub mod impl_algo;
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+    #[doc(inline)]
+    pub use super::orientation::*;
+}
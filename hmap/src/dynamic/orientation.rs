@@ -0,0 +1,76 @@
+/*
+    appellation: orientation <module>
+    authors: @FL03
+*/
+use core::hash::{BuildHasher, Hash};
+use hashbrown::DefaultHashBuilder;
+use rshyper_core::VertexSet;
+use rshyper_core::idx::{RawIndex, VertexId};
+
+/// An [`Orientation`] directs an edge from its _tail_ towards its _head_, meaning the edge may
+/// only be used to move from a member of the tail onto a member of the head. Together, the
+/// tail and head cover the domain of the edge, although a vertex may belong to both.
+#[derive(Clone)]
+pub struct Orientation<Ix, S = DefaultHashBuilder>
+where
+    Ix: RawIndex,
+{
+    /// the vertices the edge may be used to leave
+    pub(crate) tail: VertexSet<Ix, S>,
+    /// the vertices the edge may be used to reach
+    pub(crate) head: VertexSet<Ix, S>,
+}
+
+impl<Ix, S> Orientation<Ix, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    /// returns a new orientation from the given tail and head
+    pub const fn new(tail: VertexSet<Ix, S>, head: VertexSet<Ix, S>) -> Self {
+        Self { tail, head }
+    }
+    /// returns an immutable reference to the tail of the orientation
+    pub const fn tail(&self) -> &VertexSet<Ix, S> {
+        &self.tail
+    }
+    /// returns an immutable reference to the head of the orientation
+    pub const fn head(&self) -> &VertexSet<Ix, S> {
+        &self.head
+    }
+    /// returns true if the given vertex is a member of the tail
+    pub fn is_tail(&self, vertex: &VertexId<Ix>) -> bool
+    where
+        Ix: Eq + Hash,
+    {
+        self.tail.contains(vertex)
+    }
+    /// returns true if the given vertex is a member of the head
+    pub fn is_head(&self, vertex: &VertexId<Ix>) -> bool
+    where
+        Ix: Eq + Hash,
+    {
+        self.head.contains(vertex)
+    }
+    /// reverse the orientation, swapping its tail and head
+    pub const fn reverse(&mut self) -> &mut Self {
+        core::mem::swap(&mut self.tail, &mut self.head);
+        self
+    }
+    /// consumes the orientation to return its tail and head
+    pub fn into_parts(self) -> (VertexSet<Ix, S>, VertexSet<Ix, S>) {
+        (self.tail, self.head)
+    }
+}
+
+impl<Ix, S> core::fmt::Debug for Orientation<Ix, S>
+where
+    Ix: RawIndex,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Orientation")
+            .field("tail", &self.tail)
+            .field("head", &self.head)
+            .finish()
+    }
+}
@@ -0,0 +1,318 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use crate::{HyperMap, NodeMap};
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::{Edge, IncidenceWeights, WeightedEdge};
+use rshyper_core::idx::{EdgeId, HyperIndex, IndexTracker, RawIndex, Udx, VertexId};
+use rshyper_core::{Error, GraphType, Node, Result, Weight};
+
+/// a type alias for a map associating each edge, whose domain is weighted, with its index
+pub type WeightedEdgeMap<E, V, K, Ix, S = DefaultHashBuilder> =
+    HashMap<EdgeId<Ix>, WeightedEdge<E, V, K, Ix, S>, S>;
+/// a type alias for a [directed](rshyper_core::Directed) [`EdvwHyperMap`]
+pub type DiEdvwHyperMap<N, E, V = f64, Idx = Udx, S = DefaultHashBuilder> =
+    EdvwHyperMap<N, E, DiAttrs<Idx>, V, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`EdvwHyperMap`]
+pub type UnEdvwHyperMap<N, E, V = f64, Idx = Udx, S = DefaultHashBuilder> =
+    EdvwHyperMap<N, E, UnAttrs<Idx>, V, S>;
+
+/// The [`EdvwHyperMap`] is a hypergraph with _edge-dependent vertex weights_ (EDVW), meaning
+/// that each vertex carries a separate weight, of type `V`, for every edge containing it
+/// (e.g. the position of an author on each of their papers).
+///
+/// ## Overview
+///
+/// Each edge of the graph is a [`WeightedEdge`] whose domain, the [`IncidenceWeights`], maps
+/// every vertex of the edge onto its weight; the domain is the only record of the members of
+/// an edge, so the weights can never fall out of sync with them. Iterating over the domain
+/// yields its vertices, allowing the algorithms of the [`rshyper_algo`] crate to traverse the
+/// graph as usual, while the per-incidence weights are honored by the random walks and the
+/// [Dijkstra](rshyper_algo::Dijkstra) cost functions through the
+/// [`WeightedIncidence`](rshyper_core::WeightedIncidence) trait.
+pub struct EdvwHyperMap<N = (), E = (), A = UnAttrs<Udx>, V = f64, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the edges of the graph, each mapping its vertices onto their weights
+    pub(crate) edges: WeightedEdgeMap<E, V, A::Kind, A::Ix, S>,
+    /// the nodes of the graph
+    pub(crate) nodes: NodeMap<N, A::Ix, S>,
+    /// the attributes of the graph
+    pub(crate) attrs: A,
+    /// tracks the indices of the nodes and edges of the graph
+    pub(crate) history: IndexTracker<A::Ix>,
+}
+
+impl<N, E, A, K, Ix, V, S> EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`EdvwHyperMap`]
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            edges: WeightedEdgeMap::default(),
+            nodes: NodeMap::default(),
+            attrs: A::new(),
+            history: Default::default(),
+        }
+    }
+    /// returns an immutable reference to the edges of the graph
+    pub const fn edges(&self) -> &WeightedEdgeMap<E, V, K, Ix, S> {
+        &self.edges
+    }
+    /// returns an immutable reference to the nodes of the graph
+    pub const fn nodes(&self) -> &NodeMap<N, Ix, S> {
+        &self.nodes
+    }
+    /// returns an immutable reference to the history of the graph
+    pub const fn history(&self) -> &IndexTracker<Ix> {
+        &self.history
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.edges.is_empty()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.nodes.len()
+    }
+    /// returns the number of edges within the graph
+    pub fn size(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl<N, E, A, K, Ix, V, S> EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// create a new instance from the given [`HyperMap`], assigning each vertex the given
+    /// weight within every edge containing it
+    pub fn from_hyper_map(graph: HyperMap<N, E, A, S>, weight: V) -> Self
+    where
+        V: Clone,
+    {
+        let edges = graph
+            .edges
+            .into_iter()
+            .map(|(id, edge)| {
+                let (_, domain, edge_weight) = edge.into_parts();
+                let weights = domain
+                    .into_iter()
+                    .map(|v| (v, Weight(weight.clone())))
+                    .collect();
+                (id, Edge::from_parts(id, weights, edge_weight))
+            })
+            .collect();
+        Self {
+            edges,
+            nodes: graph.nodes,
+            attrs: graph.attrs,
+            history: graph.history,
+        }
+    }
+    /// materializes the graph as a standard [`HyperMap`], discarding the weights of the
+    /// incidences; the indices of the components are preserved.
+    pub fn to_hyper_map(&self) -> HyperMap<N, E, A, S>
+    where
+        E: Clone,
+        N: Clone,
+    {
+        let hasher = self.edges.hasher().clone();
+        let mut edges = crate::EdgeMap::with_hasher(hasher.clone());
+        edges.extend(self.edges.iter().map(|(id, edge)| {
+            let domain = edge.domain().vertices().copied().collect();
+            (*id, Edge::from_parts(*id, domain, edge.weight().clone()))
+        }));
+        let mut graph = HyperMap::with_hasher(hasher);
+        graph
+            .set_nodes(self.nodes.clone())
+            .set_edges(edges)
+            .set_history(self.history.clone())
+            .reindex();
+        graph
+    }
+    /// add a new node with the given weight to the graph
+    pub fn add_node(&mut self, Weight(weight): Weight<N>) -> Result<VertexId<Ix>> {
+        let id = self.history.next_vertex()?;
+        self.nodes.insert(id, Node::new(id, weight));
+        Ok(id)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.add_node(Default::default())
+    }
+    /// add a new edge composed of the given vertices, each paired with its weight within the
+    /// edge; if a vertex is given more than once, its last weight is used. An error is
+    /// returned if the edge is empty or any of the vertices do not exist within the graph.
+    pub fn add_edge<I>(&mut self, members: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = (VertexId<Ix>, Weight<V>)>,
+    {
+        let weights = members.into_iter().collect::<IncidenceWeights<Ix, V, S>>();
+        if weights.is_empty() {
+            return Err(Error::EmptyHyperedge);
+        }
+        if weights.vertices().any(|v| !self.contains_node(v)) {
+            return Err(Error::NodeNotFound);
+        }
+        let id = self.history.next_edge()?;
+        self.edges.insert(id, Edge::from_parts(id, weights, weight));
+        Ok(id)
+    }
+    /// add a new edge composed of the given vertices, each paired with its weight within the
+    /// edge, using the logical default for the weight of the edge
+    pub fn add_link<I>(&mut self, members: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = (VertexId<Ix>, Weight<V>)>,
+        E: Default,
+    {
+        self.add_edge(members, Default::default())
+    }
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.edges.contains_key(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.nodes.contains_key(index)
+    }
+    /// returns an iterator over the edges containing the given vertex
+    pub fn find_edges_with_node(&self, index: &VertexId<Ix>) -> impl Iterator<Item = &EdgeId<Ix>> {
+        let index = *index;
+        self.edges
+            .iter()
+            .filter(move |(_, edge)| edge.domain().contains(&index))
+            .map(|(id, _)| id)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&WeightedEdge<E, V, K, Ix, S>> {
+        self.edges.get(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns a mutable reference to the edge with the given index
+    pub fn get_edge_mut(
+        &mut self,
+        index: &EdgeId<Ix>,
+    ) -> Result<&mut WeightedEdge<E, V, K, Ix, S>> {
+        self.edges.get_mut(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns the weighted domain of the edge with the given index
+    pub fn get_domain(&self, index: &EdgeId<Ix>) -> Result<&IncidenceWeights<Ix, V, S>> {
+        self.get_edge(index).map(|edge| edge.domain())
+    }
+    /// returns a mutable reference to the weighted domain of the edge with the given index
+    pub fn get_domain_mut(
+        &mut self,
+        index: &EdgeId<Ix>,
+    ) -> Result<&mut IncidenceWeights<Ix, V, S>> {
+        self.get_edge_mut(index).map(|edge| edge.domain_mut())
+    }
+    /// returns the weight of the given vertex within the edge with the given index
+    pub fn get_incidence_weight(
+        &self,
+        edge: &EdgeId<Ix>,
+        vertex: &VertexId<Ix>,
+    ) -> Result<&Weight<V>> {
+        self.get_domain(edge)?
+            .get(vertex)
+            .ok_or(Error::NodeNotFound)
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.nodes.get(index).ok_or(Error::NodeNotFound)
+    }
+    /// returns a mutable reference to the node with the given index
+    pub fn get_node_mut(&mut self, index: &VertexId<Ix>) -> Result<&mut Node<N, Ix>> {
+        self.nodes.get_mut(index).ok_or(Error::NodeNotFound)
+    }
+    /// update the weight of the given vertex within the edge with the given index, returning
+    /// the previous weight
+    pub fn set_incidence_weight(
+        &mut self,
+        edge: &EdgeId<Ix>,
+        vertex: &VertexId<Ix>,
+        weight: Weight<V>,
+    ) -> Result<Weight<V>> {
+        self.get_domain_mut(edge)?
+            .get_mut(vertex)
+            .map(|prev| core::mem::replace(prev, weight))
+            .ok_or(Error::NodeNotFound)
+    }
+    /// removes the edge with the given index, returning it alongside the weights of its
+    /// vertices
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<WeightedEdge<E, V, K, Ix, S>> {
+        self.edges.remove(index).ok_or(Error::EdgeNotFound)
+    }
+    /// removes the node with the given index alongside each of the edges containing it
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let node = self.nodes.remove(index).ok_or(Error::NodeNotFound)?;
+        self.history.remove_node(node.id());
+        self.edges.retain(|_, edge| !edge.domain().contains(index));
+        Ok(node)
+    }
+}
+
+impl<N, E, A, V, S> Clone for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps,
+    A::Ix: Clone,
+    A::Kind: Clone,
+    E: Clone,
+    N: Clone,
+    S: BuildHasher + Clone,
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            edges: self.edges.clone(),
+            nodes: self.nodes.clone(),
+            attrs: self.attrs,
+            history: self.history.clone(),
+        }
+    }
+}
+
+impl<N, E, A, V, S> Default for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, V, S> core::fmt::Debug for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    V: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EdvwHyperMap")
+            .field("nodes", &self.nodes)
+            .field("edges", &self.edges)
+            .finish()
+    }
+}
@@ -0,0 +1,29 @@
+/*
+    appellation: impl_algo <module>
+    authors: @FL03
+*/
+use crate::edvw::EdvwHyperMap;
+use core::hash::BuildHasher;
+use rshyper_algo::{Dijkstra, RandomWalk};
+use rshyper_core::{GraphProps, HyperIndex};
+
+/// implementations for the algorithms honoring the edge-dependent vertex weights of the graph
+impl<N, E, A, V, S> EdvwHyperMap<N, E, A, V, S>
+where
+    S: BuildHasher + Clone + Default,
+    A: GraphProps,
+    A::Ix: HyperIndex,
+    V: num_traits::One,
+{
+    /// returns the [`Dijkstra`] operator configured with the current hypergraph
+    pub fn dijkstra(&self) -> Dijkstra<'_, N, E, A, Self, S> {
+        Dijkstra::new(self)
+    }
+    /// returns the [`RandomWalk`] operator configured with the current hypergraph
+    pub fn random_walk(&self) -> RandomWalk<'_, N, E, A, Self>
+    where
+        E: num_traits::ToPrimitive,
+    {
+        RandomWalk::new(self)
+    }
+}
@@ -0,0 +1,179 @@
+/*
+    appellation: impl_hyper_graph <module>
+    authors: @FL03
+*/
+use crate::edvw::EdvwHyperMap;
+use crate::iter;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::hash_map;
+use rshyper_core::edge::{IncidenceWeights, WeightedEdge};
+use rshyper_core::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper_core::traits::{
+    HyperGraph, HyperGraphIterEdge, HyperGraphIterNode, RawHyperGraph, WeightedIncidence,
+};
+use rshyper_core::{GraphProps, GraphType, Node, Result, Weight};
+
+impl<N, E, A, V, S> RawHyperGraph<A> for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    type Node<_N> = Node<_N, A::Ix>;
+    type Edge<_E> = WeightedEdge<_E, V, A::Kind, A::Ix, S>;
+}
+
+/// edges inserted through the [`HyperGraph`] interface carry no incidence weights, so each of
+/// their vertices is assigned a weight of [one](num_traits::One::one).
+impl<N, E, A, K, Ix, V, S> HyperGraph<N, E, A> for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+    V: num_traits::One,
+{
+    fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<A::Ix>> {
+        self.add_node(weight)
+    }
+
+    fn add_surface<I>(&mut self, iter: I, weight: Weight<E>) -> Result<EdgeId<A::Ix>>
+    where
+        I: IntoIterator<Item = VertexId<A::Ix>>,
+    {
+        self.add_edge(iter.into_iter().map(|v| (v, Weight(V::one()))), weight)
+    }
+
+    fn get_edge_domain(&self, index: &EdgeId<A::Ix>) -> Result<&IncidenceWeights<A::Ix, V, S>> {
+        self.get_domain(index)
+    }
+
+    fn get_edge_domain_mut(
+        &mut self,
+        index: &EdgeId<A::Ix>,
+    ) -> Result<&mut IncidenceWeights<A::Ix, V, S>> {
+        self.get_domain_mut(index)
+    }
+
+    fn get_edge(&self, index: &EdgeId<A::Ix>) -> Result<&WeightedEdge<E, V, A::Kind, A::Ix, S>> {
+        self.get_edge(index)
+    }
+
+    fn get_edge_mut(
+        &mut self,
+        index: &EdgeId<A::Ix>,
+    ) -> Result<&mut WeightedEdge<E, V, A::Kind, A::Ix, S>> {
+        self.get_edge_mut(index)
+    }
+
+    fn get_node(&self, index: &VertexId<A::Ix>) -> Result<&Node<N, A::Ix>> {
+        self.get_node(index)
+    }
+
+    fn get_node_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Node<N, A::Ix>> {
+        self.get_node_mut(index)
+    }
+
+    fn get_node_weight(&self, index: &VertexId<A::Ix>) -> Result<&Weight<N>> {
+        self.get_node(index).map(|node| node.weight())
+    }
+
+    fn get_node_weight_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Weight<N>> {
+        self.get_node_mut(index).map(|node| node.weight_mut())
+    }
+
+    fn contains_edge(&self, index: &EdgeId<A::Ix>) -> bool {
+        self.contains_edge(index)
+    }
+
+    fn contains_node(&self, index: &VertexId<A::Ix>) -> bool {
+        self.contains_node(index)
+    }
+
+    fn find_edges_with_node(
+        &self,
+        index: &VertexId<A::Ix>,
+    ) -> impl Iterator<Item = &EdgeId<A::Ix>> {
+        self.find_edges_with_node(index)
+    }
+}
+
+impl<N, E, A, K, Ix, V, S> WeightedIncidence<N, E, A> for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+    V: num_traits::One,
+{
+    type Incidence = V;
+
+    fn get_incidence_weight(
+        &self,
+        edge: &EdgeId<A::Ix>,
+        vertex: &VertexId<A::Ix>,
+    ) -> Result<&Weight<V>> {
+        self.get_incidence_weight(edge, vertex)
+    }
+}
+
+impl<N, E, A, V, S> HyperGraphIterNode<N, E, A> for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+    V: num_traits::One,
+{
+    type Nodes<'a>
+        = iter::NodeIter<'a, N, A::Ix>
+    where
+        Self: 'a,
+        Self::Node<N>: 'a;
+    type Verts<'a>
+        = iter::NodeKeys<'a, N, A::Ix>
+    where
+        Self: 'a;
+
+    fn iter_nodes(&self) -> Self::Nodes<'_> {
+        iter::NodeIter {
+            iter: self.nodes.iter(),
+        }
+    }
+
+    fn vertices(&self) -> Self::Verts<'_> {
+        iter::NodeKeys {
+            iter: self.nodes.keys(),
+        }
+    }
+}
+
+impl<N, E, A, V, S> HyperGraphIterEdge<N, E, A> for EdvwHyperMap<N, E, A, V, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+    V: num_traits::One,
+{
+    type Surfaces<'a>
+        = hash_map::Iter<'a, EdgeId<A::Ix>, WeightedEdge<E, V, A::Kind, A::Ix, S>>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    type Edges<'a>
+        = hash_map::Keys<'a, EdgeId<A::Ix>, WeightedEdge<E, V, A::Kind, A::Ix, S>>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    fn iter_surfaces(&self) -> Self::Surfaces<'_> {
+        self.edges.iter()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        self.edges.keys()
+    }
+}
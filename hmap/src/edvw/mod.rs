@@ -0,0 +1,21 @@
+/*
+    appellation: edvw <module>
+    authors: @FL03
+*/
+//! this module implements the [`EdvwHyperMap`], a hypergraph with _edge-dependent vertex
+//! weights_ (EDVW), where each vertex is weighted according to the edges containing it.
+#[doc(inline)]
+pub use self::graph::*;
+
+mod graph;
+
+mod impls {
+    #[cfg(feature = "algo")]
+    pub mod impl_algo;
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+}
@@ -4,6 +4,7 @@
 */
 use crate::types::prelude::*;
 
+use alloc::string::String;
 use core::borrow::Borrow;
 use core::hash::{BuildHasher, Hash};
 use hashbrown::DefaultHashBuilder;
@@ -12,14 +13,16 @@ use rshyper_core::idx::{self, EdgeId, HashIndex, IndexTracker, RawIndex, Udx, Ve
 use rshyper_core::{AddStep, GraphType, Mode};
 
 /// a type alias for a [directed](rshyper_core::Directed) [`HyperMap`]
-pub type DiHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> = HyperMap<N, E, DiAttrs<Idx>, S>;
+pub type DiHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder, L = String> =
+    HyperMap<N, E, DiAttrs<Idx>, S, L>;
 /// a type alias for an [undirected](rshyper_core::Undirected) [`HyperMap`]
-pub type UnHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> = HyperMap<N, E, UnAttrs<Idx>, S>;
+pub type UnHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder, L = String> =
+    HyperMap<N, E, UnAttrs<Idx>, S, L>;
 
 /// The [`HyperMap`] is a map-based implementation of a hypergraph that provides a flexible and
 /// efficient way to store and manipulate hypergraphs. It is designed to be generic over the
-/// types of nodes N, edges E, attributes A, and the hasher S used for hashing the nodes and
-/// edges. This design allows for a wide range of applications, from simple hypergraphs to more
+/// types of nodes N, edges E, attributes A, the hasher S used for hashing the nodes and
+/// edges, and the labels L that may be used to name the vertices. This design allows for a wide range of applications, from simple hypergraphs to more
 /// complex structures with custom attributes and hashing strategies.
 ///
 /// ## Overview
@@ -28,8 +31,13 @@ pub type UnHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> = HyperMap<N, E, Un
 /// various traits to help shape its behavior and properties. Additionally, the internal
 /// history imbues the instance with a native sense of order enabling the design of sequential
 /// iteraotrs that respect the order in which the respective component was created in.
+///
+/// The vertices may optionally be named using labels of type `L`, such as strings, enabling
+/// nodes to be looked up, and edges to be created, by name. The [`Interner`] backing these
+/// lookups is only allocated once the first label is assigned and is kept consistent as the
+/// nodes of the graph are removed or renumbered.
 #[derive(Clone, Default)]
-pub struct HyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+pub struct HyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder, L = String>
 where
     A: GraphProps,
     S: BuildHasher,
@@ -44,9 +52,15 @@ where
     /// tracks the current position of the hypergraph, which is used to determine the next
     /// available indices for edges and vertices.
     pub(crate) history: IndexTracker<A::Ix>,
+    /// an index associating the domain of each edge with its id
+    pub(crate) domains: DomainIndex<A::Ix>,
+    /// an index associating each vertex with the edges containing it
+    pub(crate) incidence: IncidenceIndex<A::Ix>,
+    /// an optional index associating user-defined labels with the vertices they name
+    pub(crate) labels: Option<Interner<L, A::Ix, S>>,
 }
 
-impl<N, E, A, K, Ix, S> HyperMap<N, E, A, S>
+impl<N, E, A, K, Ix, S, L> HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Ix, Kind = K>,
     S: BuildHasher,
@@ -65,6 +79,9 @@ where
             nodes: NodeMap::default(),
             attrs: A::new(),
             history: Default::default(),
+            domains: Default::default(),
+            incidence: Default::default(),
+            labels: None,
         }
     }
     /// creates a new instance of the hypergraph with the given capacity for edges and nodes
@@ -78,6 +95,9 @@ where
             nodes: NodeMap::with_capacity_and_hasher(nodes, Default::default()),
             attrs: A::new(),
             history: Default::default(),
+            domains: Default::default(),
+            incidence: Default::default(),
+            labels: None,
         }
     }
     #[doc(hidden)]
@@ -93,6 +113,9 @@ where
             nodes: NodeMap::with_capacity_and_hasher(nodes, hash_builder),
             attrs: A::new(),
             history: Default::default(),
+            domains: Default::default(),
+            incidence: Default::default(),
+            labels: None,
         }
     }
     #[doc(hidden)]
@@ -107,6 +130,9 @@ where
             nodes: NodeMap::with_hasher(hash_builder),
             attrs: A::new(),
             history: Default::default(),
+            domains: Default::default(),
+            incidence: Default::default(),
+            labels: None,
         }
     }
     #[doc(hidden)]
@@ -151,16 +177,65 @@ where
     pub const fn edges(&self) -> &EdgeMap<E, K, Ix, S> {
         &self.edges
     }
-    /// returns a mutable reference to the surfaces of the hypergraph
+    /// returns a mutable reference to the surfaces of the hypergraph; since the edges may
+    /// be modified wholesale, the [`DomainIndex`] and [`IncidenceIndex`] are invalidated and
+    /// are rebuilt by the next insertion, or whenever the graph is
+    /// [reindexed](HyperMap::reindex).
     pub const fn edges_mut(&mut self) -> &mut EdgeMap<E, K, Ix, S> {
+        self.invalidate_indices();
         &mut self.edges
     }
+    /// returns an immutable reference to the [`DomainIndex`] of the hypergraph
+    pub const fn domain_index(&self) -> &DomainIndex<Ix> {
+        &self.domains
+    }
+    /// returns an immutable reference to the [`IncidenceIndex`] of the hypergraph
+    pub const fn incidence_index(&self) -> &IncidenceIndex<Ix> {
+        &self.incidence
+    }
     #[inline]
-    /// overrides the current surfaces and returns a mutable reference to the hypergraph
+    /// overrides the current surfaces and returns a mutable reference to the hypergraph; the
+    /// internal indices are invalidated until the next insertion, or until the graph is
+    /// [reindexed](HyperMap::reindex).
     pub fn set_edges(&mut self, edges: EdgeMap<E, K, Ix, S>) -> &mut Self {
         self.edges = edges;
+        self.invalidate_indices();
+        self
+    }
+    /// rebuilds the internal indices of the hypergraph; this is never required for the
+    /// indices to remain correct, though it may be used to restore them eagerly after the
+    /// edges have been exposed using [`edges_mut`](HyperMap::edges_mut).
+    pub fn reindex(&mut self) -> &mut Self
+    where
+        Ix: HashIndex + Clone,
+    {
+        self.domains
+            .rebuild(self.edges.iter().map(|(id, edge)| (id, edge.domain())));
+        self.incidence
+            .rebuild(self.edges.iter().map(|(id, edge)| (id, edge.domain())));
         self
     }
+    /// marks each of the internal indices as being out-of-sync with the edges of the graph
+    pub(crate) const fn invalidate_indices(&mut self) {
+        self.domains.invalidate();
+        self.incidence.invalidate();
+    }
+    /// brings the internal indices up-to-date, rebuilding them if they were invalidated and
+    /// otherwise recording the current domains of any edges released by a mutable borrow
+    pub(crate) fn sync_indices(&mut self)
+    where
+        Ix: HashIndex + Clone,
+    {
+        if self.domains.is_stale() || self.incidence.is_stale() {
+            self.reindex();
+            return;
+        }
+        let edges = &self.edges;
+        self.domains
+            .restore(|id| edges.get(id).map(|edge| edge.domain()));
+        self.incidence
+            .restore(|id| edges.get(id).map(|edge| edge.domain()));
+    }
     /// overrides the current nodes and returns a mutable reference to the hypergraph
     #[inline]
     pub fn set_nodes(&mut self, nodes: NodeMap<N, Ix, S>) -> &mut Self
@@ -275,7 +350,7 @@ where
     }
 }
 
-impl<N, E, A, S> core::fmt::Debug for HyperMap<N, E, A, S>
+impl<N, E, A, S, L> core::fmt::Debug for HyperMap<N, E, A, S, L>
 where
     A: GraphProps,
     E: core::fmt::Debug,
@@ -291,7 +366,7 @@ where
     }
 }
 
-impl<N, E, A, S> core::fmt::Display for HyperMap<N, E, A, S>
+impl<N, E, A, S, L> core::fmt::Display for HyperMap<N, E, A, S, L>
 where
     A: GraphProps,
     E: core::fmt::Debug,
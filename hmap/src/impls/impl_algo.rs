@@ -3,17 +3,29 @@
     authors: @FL03
 */
 use crate::HyperMap;
-use core::hash::BuildHasher;
-use rshyper_algo::{AStarSearch, BreadthFirstTraversal, DepthFirstTraversal, Dijkstra, Heuristic};
+use core::hash::{BuildHasher, Hash};
+use rshyper_algo::{
+    AStarSearch, Acyclicity, BreadthFirstTraversal, Coloring, CoreDecomposition,
+    DepthFirstTraversal, Dijkstra, Heuristic, IndependentSets, LabelPropagation, Matching,
+    Modularity, Transversals, TreeDecomposer,
+};
 use rshyper_core::{GraphProps, HyperIndex};
 
 /// implementations for various algorithms and operators on the hypergraph
-impl<N, E, A, S> HyperMap<N, E, A, S>
+impl<N, E, A, S, L> HyperMap<N, E, A, S, L>
 where
     S: BuildHasher + Default,
     A: GraphProps,
     A::Ix: HyperIndex,
 {
+    /// returns the [`Acyclicity`] operator configured with the current hypergraph.
+    pub fn acyclicity(&self) -> Acyclicity<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        Acyclicity::new(self)
+    }
     /// returns a new [`A*`](AStarSearch) search operator configured with the current
     /// graph and the provided heuristic function.
     pub fn astar<F>(&self, heuristic: F) -> AStarSearch<'_, N, E, A, F, Self, S>
@@ -26,6 +38,22 @@ where
     pub fn bft(&self) -> BreadthFirstTraversal<'_, N, E, A, Self, S> {
         BreadthFirstTraversal::new(self)
     }
+    /// returns the [`Coloring`] operator configured with the current hypergraph.
+    pub fn coloring(&self) -> Coloring<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        Coloring::new(self)
+    }
+    /// returns the [`CoreDecomposition`] operator configured with the current hypergraph.
+    pub fn core_decomposition(&self) -> CoreDecomposition<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        CoreDecomposition::new(self)
+    }
     /// returns the [`DepthFirstTraversal`] operator configured with the current hypergraph.
     pub fn dft(&self) -> DepthFirstTraversal<'_, N, E, A, Self, S> {
         DepthFirstTraversal::new(self)
@@ -34,4 +62,52 @@ where
     pub fn dijkstra(&self) -> Dijkstra<'_, N, E, A, Self, S> {
         Dijkstra::new(self)
     }
+    /// returns the [`TreeDecomposer`] operator configured with the current hypergraph.
+    pub fn tree_decomposer(&self) -> TreeDecomposer<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        TreeDecomposer::new(self)
+    }
+    /// returns the [`IndependentSets`] operator configured with the current hypergraph.
+    pub fn independent_sets(&self) -> IndependentSets<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        IndependentSets::new(self)
+    }
+    /// returns the [`LabelPropagation`] operator configured with the current hypergraph.
+    pub fn label_propagation(&self) -> LabelPropagation<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        LabelPropagation::new(self)
+    }
+    /// returns the [`Matching`] operator configured with the current hypergraph.
+    pub fn matching(&self) -> Matching<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        Matching::new(self)
+    }
+    /// returns the [`Modularity`] operator configured with the current hypergraph.
+    pub fn modularity(&self) -> Modularity<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        Modularity::new(self)
+    }
+    /// returns the [`Transversals`] operator configured with the current hypergraph.
+    pub fn transversals(&self) -> Transversals<'_, N, E, A, Self>
+    where
+        N: Eq + Hash,
+        E: Eq + Hash,
+    {
+        Transversals::new(self)
+    }
 }
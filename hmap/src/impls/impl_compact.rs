@@ -0,0 +1,120 @@
+/*
+    appellation: impl_compact <module>
+    authors: @FL03
+*/
+use crate::IndexRemap;
+use crate::graph::HyperMap;
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::HashSet;
+use rshyper_core::idx::{HyperIndex, IndexFrame, VertexId};
+use rshyper_core::{AddStep, Error, GraphProps, GraphType, Result, VertexSet};
+
+impl<N, E, A, S, K, Ix, L> HyperMap<N, E, A, S, L>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex + AddStep<Output = Ix>,
+{
+    /// renumbers the vertices and edges of the graph densely, in the order they were created,
+    /// returning the mapping of the previous indices onto the new ones; see
+    /// [`compact_with`](HyperMap::compact_with) for more details.
+    pub fn compact(&mut self) -> Result<IndexRemap<Ix, S>> {
+        self.compact_with(core::iter::empty())
+    }
+    /// renumbers the vertices of the graph densely using the given order, such as those
+    /// produced by a breadth-first traversal, before renumbering the edges densely in the
+    /// order they were created. Any vertices missing from the given order are numbered
+    /// afterwards, in the order they were created, while repeated vertices are ignored.
+    ///
+    /// The domain of each edge, alongside the history and labels of the graph, are rewritten
+    /// using the new indices, and the cursor of the history is placed immediately after the
+    /// last of them. The mapping of the previous indices onto the new ones is returned.
+    ///
+    /// **Note:** an error is returned, leaving the graph untouched, if the order contains a
+    /// vertex that is missing from the graph.
+    pub fn compact_with<I>(&mut self, order: I) -> Result<IndexRemap<Ix, S>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let order = order.into_iter().collect::<Vec<_>>();
+        if order.iter().any(|v| !self.contains_node(v)) {
+            return Err(Error::NodeNotFound);
+        }
+        let mut remap = IndexRemap::<Ix, S>::new();
+        let mut cursor = IndexFrame::<Ix>::default();
+        // assign the new vertex indices, followed by those of the edges
+        for prev in order
+            .into_iter()
+            .chain(creation_order(self.history.nodes(), self.nodes.keys()))
+        {
+            if !remap.nodes.contains_key(&prev) {
+                remap.nodes.insert(prev, cursor.next_node()?);
+            }
+        }
+        for prev in creation_order(self.history.edges(), self.edges.keys()) {
+            remap.edges.insert(prev, cursor.next_edge()?);
+        }
+        // rewrite the nodes and edges using their new indices
+        self.nodes = self
+            .nodes
+            .drain()
+            .map(|(prev, node)| {
+                let id = remap.nodes[&prev];
+                (id, node.with_id(id))
+            })
+            .collect();
+        self.edges = self
+            .edges
+            .drain()
+            .map(|(prev, mut edge)| {
+                let id = remap.edges[&prev];
+                let domain = edge
+                    .domain()
+                    .iter()
+                    .map(|v| remap.nodes[v])
+                    .collect::<VertexSet<Ix, S>>();
+                edge.set_domain(domain);
+                (id, edge.with_id(id))
+            })
+            .collect();
+        if let Some(labels) = self.labels.as_mut() {
+            labels.remap(|v| remap.nodes[v]);
+        }
+        // rewrite the history, in order of the new indices
+        let mut nodes = self.nodes.keys().copied().collect::<Vec<_>>();
+        let mut edges = self.edges.keys().copied().collect::<Vec<_>>();
+        nodes.sort();
+        edges.sort();
+        self.history
+            .set_nodes(nodes)
+            .set_edges(edges)
+            .set_cursor(cursor);
+        self.reindex();
+        Ok(remap)
+    }
+}
+
+/// returns the given indices in the order they were recorded by the history, followed by any
+/// indices missing from the history in ascending order
+fn creation_order<'a, T, I>(history: &[T], keys: I) -> Vec<T>
+where
+    T: 'a + Copy + Ord + Hash,
+    I: IntoIterator<Item = &'a T>,
+{
+    let keys = keys.into_iter().copied().collect::<HashSet<T>>();
+    let mut order = history
+        .iter()
+        .filter(|id| keys.contains(*id))
+        .copied()
+        .collect::<Vec<_>>();
+    let recorded = order.iter().copied().collect::<HashSet<T>>();
+    let mut rest = keys
+        .into_iter()
+        .filter(|id| !recorded.contains(id))
+        .collect::<Vec<_>>();
+    rest.sort();
+    order.extend(rest);
+    order
+}
@@ -10,7 +10,7 @@ use rshyper::error::Result;
 use rshyper::prelude::*;
 
 #[doc(hidden)]
-impl<N, E, A, S, Ix, K> HyperMap<N, E, A, S>
+impl<N, E, A, S, Ix, K, L> HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Kind = K, Ix = Ix>,
     S: BuildHasher,
@@ -78,6 +78,7 @@ where
     pub fn retain_surfaces<F>(&mut self, f: F) -> &mut Self
     where
         F: FnMut(&EdgeId<Ix>, &mut HashEdge<E, K, Ix, S>) -> bool,
+        Ix: Clone,
     {
         self.retain_edges(f)
     }
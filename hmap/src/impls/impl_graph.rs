@@ -2,16 +2,19 @@
     appellation: impl_graph <module>
     authors: @FL03
 */
+use crate::Incidence;
 use crate::graph::HyperMap;
 use core::borrow::Borrow;
 use core::hash::{BuildHasher, Hash};
 use rshyper_core::edge::{Edge, HashEdge};
 use rshyper_core::idx::{EdgeId, HashIndex, VertexId};
-use rshyper_core::{AddStep, Error, GraphProps, GraphType, Node, Result, VertexSet, Weight};
+use rshyper_core::{
+    AddStep, EdgeSet, Error, GraphProps, GraphType, Node, Result, VertexSet, Weight,
+};
 
 /// this implementation of the [`HyperMap`] works to provide fundamental manipulation methods
 /// alongside additional functional accessors, validators, and more.
-impl<N, E, A, S, K, Ix> HyperMap<N, E, A, S>
+impl<N, E, A, S, K, Ix, L> HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Kind = K, Ix = Ix>,
     S: BuildHasher,
@@ -55,6 +58,76 @@ where
         // add the hyperedge to the graph
         self.insert_edge_unchecked(surface)
     }
+    /// add a new hyperedge with the given vertices and weight unless the graph already
+    /// contains an edge composed of the exact same vertices, in which case the index of the
+    /// existing edge is returned and the given weight is discarded.
+    pub fn add_edge_unique<I>(&mut self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        Ix: AddStep<Output = Ix> + Clone,
+        S: Default,
+    {
+        self.sync_indices();
+        // collect the valid vertices into a set
+        let verts = vertices
+            .into_iter()
+            .filter(|v| self.contains_node(v))
+            .collect::<VertexSet<Ix, S>>();
+        // return the existing edge, if any
+        if let Some(id) = self.find_edge_by_domain(verts.iter().cloned()) {
+            return Ok(id.clone());
+        }
+        // fetch the next edge index
+        let id = self.next_edge_id();
+        // add the hyperedge to the graph
+        self.insert_edge_unchecked(Edge::from_parts(id, verts, weight))
+    }
+    /// add a new hyperedge with the given vertices and weight; if the graph already contains
+    /// an edge composed of the exact same vertices, the weights are merged using the
+    /// [`Add`](core::ops::Add) trait and the index of the existing edge is returned instead.
+    pub fn add_edge_or_merge<I>(&mut self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        Ix: AddStep<Output = Ix> + Clone,
+        S: Default,
+        for<'a> &'a E: core::ops::Add<Output = E>,
+    {
+        self.add_edge_or_merge_with(vertices, weight, |w1, w2| w1 + w2)
+    }
+    /// add a new hyperedge with the given vertices and weight; if the graph already contains
+    /// an edge composed of the exact same vertices, the weights are merged using the provided
+    /// function (`f(existing, given)`) and the index of the existing edge is returned instead.
+    pub fn add_edge_or_merge_with<I, F>(
+        &mut self,
+        vertices: I,
+        weight: Weight<E>,
+        f: F,
+    ) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        Ix: AddStep<Output = Ix> + Clone,
+        S: Default,
+        F: FnOnce(&E, &E) -> E,
+    {
+        self.sync_indices();
+        // collect the valid vertices into a set
+        let verts = vertices
+            .into_iter()
+            .filter(|v| self.contains_node(v))
+            .collect::<VertexSet<Ix, S>>();
+        // merge the weights if an edge with the same domain already exists
+        if let Some(id) = self.find_edge_by_domain(verts.iter().cloned()).cloned() {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("merging the weight into the existing edge ({id})...");
+            let prev = self.get_edge_weight_mut(&id)?;
+            *prev = Weight(f(prev.get(), weight.get()));
+            return Ok(id);
+        }
+        // fetch the next edge index
+        let id = self.next_edge_id();
+        // add the hyperedge to the graph
+        self.insert_edge_unchecked(Edge::from_parts(id, verts, weight))
+    }
     /// add a new node with the given weight and return its index
     pub fn add_node(&mut self, Weight(weight): Weight<N>) -> Result<VertexId<Ix>>
     where
@@ -95,11 +168,14 @@ where
         #[cfg(feature = "tracing")]
         tracing::trace!("clearing the hypergraph...");
         // clear the edges
-        self.edges_mut().clear();
+        self.edges.clear();
+        self.domains.clear();
+        self.incidence.clear();
         // clear the nodes
         self.nodes_mut().clear();
-        // clear the history
+        // clear the history alongside the labels
         self.history_mut().clear();
+        self.labels = None;
         #[cfg(feature = "tracing")]
         tracing::info!("cleared the hypergraph successfully...");
         self
@@ -120,6 +196,167 @@ where
             }
         })
     }
+    /// returns the index of the edge composed of exactly the given vertices, if any; if the
+    /// graph contains several such edges, the one that was created first is returned.
+    ///
+    /// The lookup is backed by the [`DomainIndex`](crate::DomainIndex) of the graph, verifying
+    /// any edges that were borrowed mutably directly and falling back to a linear scan
+    /// whenever the index is stale.
+    pub fn find_edge_by_domain<I>(&self, vertices: I) -> Option<&EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let domain = vertices.into_iter().collect::<hashbrown::HashSet<_>>();
+        if domain.is_empty() {
+            return None;
+        }
+        // returns true if the edge is composed of exactly the same vertices
+        let matches = |edge: &HashEdge<E, K, Ix, S>| {
+            edge.len() == domain.len() && domain.iter().all(|v| edge.contains(v))
+        };
+        if self.domain_index().is_stale() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("the domain index is stale; falling back to a linear scan...");
+            return self
+                .history()
+                .edges()
+                .iter()
+                .find(|id| self.edges().get(*id).is_some_and(matches));
+        }
+        let hash = self.domain_index().hash_domain(domain.iter());
+        let mut found = self
+            .domain_index()
+            .candidates(hash)
+            .iter()
+            .chain(self.domain_index().pending())
+            .filter(|id| self.edges().get(*id).is_some_and(matches));
+        let first = found.next()?;
+        if found.next().is_none() {
+            return Some(first);
+        }
+        // several edges share the domain, so the history determines which came first
+        self.history()
+            .edges()
+            .iter()
+            .find(|id| self.edges().get(*id).is_some_and(matches))
+    }
+    /// returns the set of edges whose domain is a subset of the given vertices, i.e. every
+    /// edge composed entirely of the given vertices.
+    ///
+    /// The query is backed by the [`IncidenceIndex`](crate::IncidenceIndex) of the graph,
+    /// only visiting the edges that contain at least one of the given vertices, alongside any
+    /// edges that were borrowed mutably; whenever the index is stale, the method falls back
+    /// to a linear scan.
+    pub fn find_subset_edges<I>(&self, vertices: I) -> EdgeSet<Ix, S>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        Ix: Clone,
+        S: Default,
+    {
+        let query = vertices.into_iter().collect::<VertexSet<Ix, S>>();
+        if self.incidence_index().is_stale() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("the incidence index is stale; falling back to a linear scan...");
+            return self
+                .edges()
+                .iter()
+                .filter(|(_, edge)| edge.domain().is_subset(&query))
+                .map(|(id, _)| id.clone())
+                .collect();
+        }
+        // an edge is a subset of the query if each of its vertices was counted
+        self.count_incident_edges(&query)
+            .into_iter()
+            .filter(|(id, count)| self.edges().get(*id).is_some_and(|e| e.len() == *count))
+            .map(|(id, _)| id)
+            .chain(self.find_pending_edges(|edge| edge.domain().is_subset(&query)))
+            .cloned()
+            .collect()
+    }
+    /// returns the set of edges whose domain is a superset of the given vertices, i.e. every
+    /// edge containing all of the given vertices; if no vertices are given, every edge is
+    /// returned.
+    ///
+    /// The query is backed by the [`IncidenceIndex`](crate::IncidenceIndex) of the graph,
+    /// only visiting the edges that contain the least connected of the given vertices,
+    /// alongside any edges that were borrowed mutably; whenever the index is stale, the method
+    /// falls back to a linear scan.
+    pub fn find_superset_edges<I>(&self, vertices: I) -> EdgeSet<Ix, S>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        Ix: Clone,
+        S: Default,
+    {
+        let query = vertices.into_iter().collect::<VertexSet<Ix, S>>();
+        if query.is_empty() {
+            return self.edges().keys().cloned().collect();
+        }
+        if self.incidence_index().is_stale() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("the incidence index is stale; falling back to a linear scan...");
+            return self
+                .edges()
+                .iter()
+                .filter(|(_, edge)| edge.domain().is_superset(&query))
+                .map(|(id, _)| id.clone())
+                .collect();
+        }
+        // start from the vertex contained by the fewest edges; if any of the vertices are
+        // isolated then no recorded edge may contain all of them
+        let index = self.incidence_index();
+        let isolated = query.iter().any(|v| index.get(v).is_none());
+        let smallest = query
+            .iter()
+            .filter_map(|v| index.get(v))
+            .min_by_key(|edges: &&Incidence<Ix>| edges.len())
+            .filter(|_| !isolated);
+        smallest
+            .into_iter()
+            .flatten()
+            .filter(|id| {
+                self.edges()
+                    .get(*id)
+                    .is_some_and(|e| e.domain().is_superset(&query))
+            })
+            .chain(self.find_pending_edges(|edge| edge.domain().is_superset(&query)))
+            .cloned()
+            .collect()
+    }
+    /// returns the set of edges sharing at least `k` vertices with the given vertices; if `k`
+    /// is zero, every edge is returned.
+    ///
+    /// The query is backed by the [`IncidenceIndex`](crate::IncidenceIndex) of the graph,
+    /// only visiting the edges that contain at least one of the given vertices, alongside any
+    /// edges that were borrowed mutably; whenever the index is stale, the method falls back
+    /// to a linear scan.
+    pub fn find_intersecting_edges<I>(&self, vertices: I, k: usize) -> EdgeSet<Ix, S>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        Ix: Clone,
+        S: Default,
+    {
+        if k == 0 {
+            return self.edges().keys().cloned().collect();
+        }
+        let query = vertices.into_iter().collect::<VertexSet<Ix, S>>();
+        if self.incidence_index().is_stale() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("the incidence index is stale; falling back to a linear scan...");
+            return self
+                .edges()
+                .iter()
+                .filter(|(_, edge)| edge.domain().intersection(&query).count() >= k)
+                .map(|(id, _)| id.clone())
+                .collect();
+        }
+        self.count_incident_edges(&query)
+            .into_iter()
+            .filter(|(_, count)| *count >= k)
+            .map(|(id, _)| id)
+            .chain(self.find_pending_edges(|edge| edge.domain().intersection(&query).count() >= k))
+            .cloned()
+            .collect()
+    }
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(skip_all, name = "neighbors", target = "hyper_map")
@@ -154,7 +391,9 @@ where
     {
         self.get_edge(index).map(|edge| edge.domain())
     }
-    /// returns a mutable reference to the set of vertices composing the given edge
+    /// returns a mutable reference to the set of vertices composing the given edge; the edge
+    /// is released from the internal indices of the graph until its domain is recorded once
+    /// again by the next insertion.
     pub fn get_domain_mut<Q>(&mut self, index: &Q) -> Result<&mut VertexSet<Ix, S>>
     where
         Q: ?Sized + Eq + Hash,
@@ -170,15 +409,19 @@ where
     {
         self.edges().get(index).ok_or_else(Error::edge_not_found)
     }
-    /// returns a mutable reference to the [`HashFacet`] associated with the given index
+    /// returns a mutable reference to the [`HashFacet`] associated with the given index; since
+    /// the domain of the edge may be modified directly, the edge is released from the
+    /// internal indices of the graph until its domain is recorded once again by the next
+    /// insertion.
     pub fn get_edge_mut<Q>(&mut self, index: &Q) -> Result<&mut HashEdge<E, K, Ix, S>>
     where
         Q: ?Sized + Eq + Hash,
         EdgeId<Ix>: Borrow<Q>,
     {
-        self.edges_mut()
-            .get_mut(index)
-            .ok_or_else(|| Error::EdgeNotFound)
+        let edge = self.edges.get_mut(index).ok_or(Error::EdgeNotFound)?;
+        self.domains.release(edge.id());
+        self.incidence.release(edge.id(), edge.domain());
+        Ok(edge)
     }
     /// returns the number of vertices within the given edge
     pub fn get_edge_order<Q>(&self, index: &Q) -> Result<usize>
@@ -202,7 +445,10 @@ where
         Q: ?Sized + Eq + Hash,
         EdgeId<Ix>: Borrow<Q>,
     {
-        self.get_edge_mut(index).map(|edge| edge.weight_mut())
+        self.edges
+            .get_mut(index)
+            .map(|edge| edge.weight_mut())
+            .ok_or(Error::EdgeNotFound)
     }
     /// returns the degree of a given vertex where the degree is the number of hyperedges that
     /// contain the vertex
@@ -304,7 +550,9 @@ where
         // initialize a new facet using the merged vertices, new index, and source weight
         let surface = Edge::from_parts(edge_id, vertices, Weight(weight));
         // insert the new hyperedge into the surfaces map
-        self.edges_mut().insert(edge_id, surface);
+        self.domains.insert(edge_id, surface.domain());
+        self.incidence.insert(edge_id, surface.domain());
+        self.edges.insert(edge_id, surface);
         // return the new edge ID
         Ok(edge_id)
     }
@@ -319,10 +567,13 @@ where
         Q: ?Sized + Eq + Hash,
         EdgeId<Ix>: Borrow<Q>,
     {
-        self.edges_mut()
+        self.edges
             .remove(index)
             .ok_or(Error::EdgeNotFound)
             .inspect(|edge| {
+                // remove the edge from the domain and incidence indices
+                self.domains.remove(edge.id());
+                self.incidence.remove(edge.id(), edge.domain());
                 #[cfg(feature = "tracing")]
                 tracing::trace!(
                     "successfully removed the hyperedge from the hypergraph with id: {}",
@@ -342,6 +593,7 @@ where
     /// removes the vertex with the given id and all of its associated hyperedges
     pub fn remove_node<Q>(&mut self, index: &Q) -> Result<Node<N, Ix>>
     where
+        L: Eq + Hash,
         Q: Eq + core::fmt::Debug + Hash,
         VertexId<Ix>: Borrow<Q>,
     {
@@ -352,14 +604,25 @@ where
             .remove(index)
             .ok_or(Error::NodeNotFound)
             .inspect(|node| {
-                // remove the node from the history
+                // remove the node from the history and drop its label, if any
                 self.history_mut().remove_node(node.id());
+                if let Some(labels) = self.labels.as_mut() {
+                    labels.remove_vertex(node.id());
+                }
                 #[cfg(feature = "tracing")]
                 tracing::trace!(
                     "successfully removed the node; removing edges that contained the vertex..."
                 );
                 // Remove all hyperedges containing this vertex
-                self.retain_edges(|_, facet| !facet.contains(index));
+                let (domains, incidence) = (&mut self.domains, &mut self.incidence);
+                self.edges.retain(|id, facet| {
+                    let keep = !facet.contains(index);
+                    if !keep {
+                        domains.remove(id);
+                        incidence.remove(id, facet.domain());
+                    }
+                    keep
+                });
                 #[cfg(feature = "tracing")]
                 tracing::trace!(
                     "successfully removed the edges containing the removed vertex {index:?}..."
@@ -389,12 +652,27 @@ where
         feature = "tracing",
         tracing::instrument(skip_all, level = "trace", target = "hyper_map")
     )]
-    /// retain surfaces in the hypergraph based on a predicate;
-    pub fn retain_edges<F>(&mut self, f: F) -> &mut Self
+    /// retain surfaces in the hypergraph based on a predicate; since the predicate has mutable
+    /// access to the edges, each retained edge is recorded by the internal indices of the
+    /// graph once again as soon as the predicate returns.
+    pub fn retain_edges<F>(&mut self, mut f: F) -> &mut Self
     where
         F: FnMut(&EdgeId<Ix>, &mut HashEdge<E, K, Ix, S>) -> bool,
+        Ix: Clone,
     {
-        self.edges_mut().retain(f);
+        let (domains, incidence) = (&mut self.domains, &mut self.incidence);
+        self.edges.retain(|id, edge| {
+            // the predicate may modify the domain, so the edge is removed from the indices
+            // using the domain recorded beforehand
+            domains.remove(id);
+            incidence.remove(id, edge.domain());
+            let keep = f(id, edge);
+            if keep {
+                domains.insert(id.clone(), edge.domain());
+                incidence.insert(id.clone(), edge.domain());
+            }
+            keep
+        });
         self
     }
     #[inline]
@@ -425,12 +703,37 @@ where
             .map_err(|_| Error::NodeNotFound)?;
         Ok(self)
     }
+    /// uses the [`IncidenceIndex`](crate::IncidenceIndex) to count the number of the given
+    /// vertices contained by each edge incident to at least one of them
+    fn count_incident_edges(
+        &self,
+        vertices: &VertexSet<Ix, S>,
+    ) -> hashbrown::HashMap<&EdgeId<Ix>, usize> {
+        let mut counts = hashbrown::HashMap::<&EdgeId<Ix>, usize>::new();
+        for v in vertices {
+            for id in self.incidence_index().get(v).into_iter().flatten() {
+                *counts.entry(id).or_default() += 1;
+            }
+        }
+        counts
+    }
+    /// returns an iterator over the edges released from the
+    /// [`IncidenceIndex`](crate::IncidenceIndex) satisfying the given predicate
+    fn find_pending_edges<F>(&self, f: F) -> impl Iterator<Item = &EdgeId<Ix>>
+    where
+        F: Fn(&HashEdge<E, K, Ix, S>) -> bool,
+    {
+        self.incidence_index()
+            .pending()
+            .iter()
+            .filter(move |id| self.edges().get(*id).is_some_and(&f))
+    }
 }
 
 #[allow(dead_code)]
 /// private implementations of the [`HyperMap`] providing methods, for convenience and
 /// consistency.
-impl<N, E, A, K, Idx, S> HyperMap<N, E, A, S>
+impl<N, E, A, K, Idx, S, L> HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Idx, Kind = K>,
     K: GraphType,
@@ -545,12 +848,16 @@ where
             );
             return Err(Error::EmptyHyperedge);
         }
+        // record the domains of any edges released since the last insertion
+        self.sync_indices();
         // get the id of the surface
         let id = edge.id().clone();
         #[cfg(feature = "tracing")]
         tracing::debug!("inserting a new hyperedge ({id}) into the graph...");
-        // insert the new hyperedge into the adjacency map
-        self.edges_mut().insert(id.clone(), edge);
+        // record the domain of the edge before inserting it into the adjacency map
+        self.domains.insert(id.clone(), edge.domain());
+        self.incidence.insert(id.clone(), edge.domain());
+        self.edges.insert(id.clone(), edge);
         // return the id
         Ok(id)
     }
@@ -9,7 +9,7 @@ use rshyper_core::idx::{EdgeId, HyperIndex, VertexId};
 use rshyper_core::traits::{HyperGraph, HyperGraphIterEdge, HyperGraphIterNode, RawHyperGraph};
 use rshyper_core::{GraphProps, GraphType, Node, Result, VertexSet, Weight};
 
-impl<N, E, A, S> RawHyperGraph<A> for HyperMap<N, E, A, S>
+impl<N, E, A, S, L> RawHyperGraph<A> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps,
     S: BuildHasher,
@@ -18,7 +18,7 @@ where
     type Edge<_E> = HashEdge<_E, A::Kind, A::Ix, S>;
 }
 
-impl<N, E, A, S, K, Ix> HyperGraph<N, E, A> for HyperMap<N, E, A, S>
+impl<N, E, A, S, K, Ix, L> HyperGraph<N, E, A> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Kind = K, Ix = Ix>,
     S: BuildHasher + Default,
@@ -95,7 +95,7 @@ where
     }
 }
 
-impl<N, E, A, S> HyperGraphIterNode<N, E, A> for HyperMap<N, E, A, S>
+impl<N, E, A, S, L> HyperGraphIterNode<N, E, A> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps,
     S: BuildHasher + Default,
@@ -122,7 +122,7 @@ where
     }
 }
 
-impl<N, E, A, S> HyperGraphIterEdge<N, E, A> for HyperMap<N, E, A, S>
+impl<N, E, A, S, L> HyperGraphIterEdge<N, E, A> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps,
     S: BuildHasher + Default,
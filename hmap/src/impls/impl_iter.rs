@@ -9,7 +9,7 @@ use core::hash::BuildHasher;
 use rshyper::{GraphProps, GraphType, HashIndex};
 
 /// implements various iterators for the [`HyperMap`]
-impl<N, E, A, S, K, Idx> HyperMap<N, E, A, S>
+impl<N, E, A, S, K, Idx, L> HyperMap<N, E, A, S, L>
 where
     S: BuildHasher,
     A: GraphProps<Kind = K, Ix = Idx>,
@@ -50,10 +50,14 @@ where
     /// consisting of:
     ///
     ///  - `0`: a reference to the [`EdgeId`](rshyper::EdgeId)
-    ///  - `1`: a mutable reference to the corresponding [`Edge`](rshyper::Edge).
+    ///  - `1`: an [`EdgeMut`](crate::iter::EdgeMut) handle to the corresponding
+    ///    [`Edge`](rshyper::Edge), allowing its weight to be modified.
+    ///
+    /// The domains of the edges are read-only, so the internal indices of the graph are left
+    /// untouched; use [`get_domain_mut`](HyperMap::get_domain_mut) to modify a domain.
     pub fn iter_edges_mut(&mut self) -> EdgeIterMut<'_, E, K, Idx, S> {
         EdgeIterMut {
-            iter: self.edges_mut().iter_mut(),
+            iter: self.edges.iter_mut(),
         }
     }
     /// returns an immutable iterator over each of the associated identifiers of the edges
@@ -70,10 +74,11 @@ where
         }
     }
     /// returns a mutable iterator over each of the [`Edge`](rshyper::Edge) values within the
-    /// graph.
+    /// graph, yielding an [`EdgeMut`](crate::iter::EdgeMut) handle to each edge whose weight,
+    /// but not its domain, may be modified.
     pub fn facets_mut(&mut self) -> EdgeValuesMut<'_, E, K, Idx, S> {
         EdgeValuesMut {
-            iter: self.edges_mut().values_mut(),
+            iter: self.edges.values_mut(),
         }
     }
     /// returns an iterator over the keys of the nodes, yielding the indices of the entries.
@@ -141,7 +146,7 @@ where
 }
 
 #[cfg(feature = "rayon")]
-impl<N, E, A, S, K, Idx> HyperMap<N, E, A, S>
+impl<N, E, A, S, K, Idx, L> HyperMap<N, E, A, S, L>
 where
     S: BuildHasher,
     A: GraphProps<Kind = K, Ix = Idx>,
@@ -161,8 +166,9 @@ where
             iter: self.edges().par_values(),
         }
     }
-    /// returns a mutable parallel iterator over the surfaces of the hypergraph, yielding pairs of
-    /// [`EdgeId`](rshyper::EdgeId) and a mutable reference to the corresponding [`Edge`](rshyper::Edge).
+    /// returns a mutable parallel iterator over the surfaces of the hypergraph, yielding an
+    /// [`EdgeMut`](crate::iter::EdgeMut) handle to each [`Edge`](rshyper::Edge) whose weight,
+    /// but not its domain, may be modified.
     pub fn par_iter_facets_mut(&mut self) -> ParEdgeValuesMut<'_, E, K, Idx, S>
     where
         E: Send + Sync,
@@ -171,7 +177,7 @@ where
         S: Send + Sync,
     {
         ParEdgeValuesMut {
-            iter: self.edges_mut().par_values_mut(),
+            iter: self.edges.par_values_mut(),
         }
     }
 
@@ -0,0 +1,147 @@
+/*
+    appellation: impl_labels <module>
+    authors: @FL03
+*/
+use crate::HyperMap;
+use crate::types::Interner;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+use rshyper_core::idx::{EdgeId, HyperIndex, RawIndex, VertexId};
+use rshyper_core::{Error, GraphProps, GraphType, Node, Result, Weight};
+
+impl<N, E, A, K, Ix, S, L> HyperMap<N, E, A, S, L>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns an immutable reference to the labels naming the vertices of the graph, if any
+    /// have been assigned
+    pub const fn labels(&self) -> Option<&Interner<L, Ix, S>> {
+        self.labels.as_ref()
+    }
+    /// returns true if any of the vertices of the graph have been named
+    pub fn is_labeled(&self) -> bool {
+        self.labels
+            .as_ref()
+            .is_some_and(|labels| !labels.is_empty())
+    }
+}
+
+impl<N, E, A, K, Ix, S, L> HyperMap<N, E, A, S, L>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+    L: Clone + Eq + Hash,
+{
+    /// add a new node with the given name and weight to the graph.
+    ///
+    /// **Note:** an error is returned if the name is already in use.
+    pub fn add_named_node(&mut self, name: L, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        if let Some(id) = self.labels.as_ref().and_then(|labels| labels.get(&name)) {
+            return Err(Error::node_already_exists(*id.get()));
+        }
+        let id = self.add_node(weight)?;
+        self.labels_mut().insert(name, id);
+        Ok(id)
+    }
+    /// add a new vertex with the given name using the logical default for its weight
+    pub fn add_named_vertex(&mut self, name: L) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.add_named_node(name, Default::default())
+    }
+    /// returns the index of the vertex with the given name, adding a new vertex using the
+    /// logical default for its weight if the name is not in use.
+    pub fn intern(&mut self, name: L) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        match self.labels.as_ref().and_then(|labels| labels.get(&name)) {
+            Some(id) => Ok(*id),
+            None => self.add_named_vertex(name),
+        }
+    }
+    /// add a new edge composed of the vertices with the given names.
+    ///
+    /// **Note:** an error is returned if any of the names are not in use.
+    pub fn add_edge_by_names<'a, I, Q>(&mut self, names: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = &'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + ?Sized + Eq + Hash,
+    {
+        let verts = names
+            .into_iter()
+            .map(|name| self.node_by_name(name))
+            .collect::<Result<Vec<_>>>()?;
+        self.add_edge(verts, weight)
+    }
+    /// returns true if the graph contains a node with the given name
+    pub fn contains_name<Q>(&self, name: &Q) -> bool
+    where
+        L: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.labels
+            .as_ref()
+            .is_some_and(|labels| labels.contains(name))
+    }
+    /// returns the index of the node with the given name
+    pub fn node_by_name<Q>(&self, name: &Q) -> Result<VertexId<Ix>>
+    where
+        L: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.labels
+            .as_ref()
+            .and_then(|labels| labels.get(name))
+            .copied()
+            .ok_or(Error::NodeNotFound)
+    }
+    /// returns the name of the node with the given index, if any
+    pub fn name_of(&self, index: &VertexId<Ix>) -> Option<&L> {
+        self.labels.as_ref()?.label_of(index)
+    }
+    /// name the node with the given index, replacing any previous name of the node; returns
+    /// the previous name, if any.
+    ///
+    /// **Note:** an error is returned if the node does not exist or if the name already
+    /// belongs to another node.
+    pub fn set_name(&mut self, index: &VertexId<Ix>, name: L) -> Result<Option<L>> {
+        if !self.contains_node(index) {
+            return Err(Error::NodeNotFound);
+        }
+        let labels = self.labels_mut();
+        match labels.get(&name) {
+            Some(id) if id == index => return Ok(Some(name)),
+            Some(id) => return Err(Error::node_already_exists(*id.get())),
+            None => {}
+        }
+        let prev = labels.remove_vertex(index);
+        labels.insert(name, *index);
+        Ok(prev)
+    }
+    /// removes the name of the node with the given index, returning it
+    pub fn remove_name(&mut self, index: &VertexId<Ix>) -> Option<L> {
+        self.labels.as_mut()?.remove_vertex(index)
+    }
+    /// removes the node with the given name, alongside each of the edges containing it
+    pub fn remove_node_by_name<Q>(&mut self, name: &Q) -> Result<Node<N, Ix>>
+    where
+        L: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let index = self.node_by_name(name)?;
+        self.remove_node(&index)
+    }
+    /// returns a mutable reference to the labels of the graph, allocating them if necessary
+    fn labels_mut(&mut self) -> &mut Interner<L, Ix, S> {
+        self.labels.get_or_insert_with(Interner::new)
+    }
+}
@@ -10,7 +10,7 @@ use rshyper_core::edge::HashEdge;
 use rshyper_core::idx::{EdgeId, HashIndex, HyperIndex, VertexId};
 use rshyper_core::{Combine, GraphProps, Node, Result};
 
-impl<N, E, A, S, Ix> Combine<EdgeId<Ix>, EdgeId<Ix>> for HyperMap<N, E, A, S>
+impl<N, E, A, S, Ix, L> Combine<EdgeId<Ix>, EdgeId<Ix>> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher + Default,
@@ -24,7 +24,7 @@ where
     }
 }
 
-impl<'a, N, E, A, S, Ix> Combine<&'a EdgeId<Ix>, &'a EdgeId<Ix>> for HyperMap<N, E, A, S>
+impl<'a, N, E, A, S, Ix, L> Combine<&'a EdgeId<Ix>, &'a EdgeId<Ix>> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher + Default,
@@ -38,7 +38,7 @@ where
     }
 }
 
-impl<N, E, A, S, Ix> Index<&EdgeId<Ix>> for HyperMap<N, E, A, S>
+impl<N, E, A, S, Ix, L> Index<&EdgeId<Ix>> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher,
@@ -51,7 +51,7 @@ where
     }
 }
 
-impl<N, E, A, S, Ix> IndexMut<&EdgeId<Ix>> for HyperMap<N, E, A, S>
+impl<N, E, A, S, Ix, L> IndexMut<&EdgeId<Ix>> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher,
@@ -62,7 +62,7 @@ where
     }
 }
 
-impl<N, E, A, S, Ix> Index<&VertexId<Ix>> for HyperMap<N, E, A, S>
+impl<N, E, A, S, Ix, L> Index<&VertexId<Ix>> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher,
@@ -75,7 +75,7 @@ where
     }
 }
 
-impl<N, E, A, S, Ix> IndexMut<&VertexId<Ix>> for HyperMap<N, E, A, S>
+impl<N, E, A, S, Ix, L> IndexMut<&VertexId<Ix>> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher,
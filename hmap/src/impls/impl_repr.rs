@@ -2,13 +2,15 @@
     appellation: impl_repr <module>
     authors: @FL03
 */
-use crate::{DiHyperMap, HyperMap, UnHyperMap};
-use core::hash::BuildHasher;
+use crate::{DiHyperMap, EdgeMap, HyperMap, NodeMap, UnHyperMap};
+use core::hash::{BuildHasher, Hash};
+use num_traits::{NumCast, ToPrimitive};
+use rshyper::edge::Edge;
 use rshyper::error::Result;
 use rshyper::idx::{HashIndex, RawIndex, VertexId};
-use rshyper::{AddStep, GraphProps, Mode, Weight};
+use rshyper::{AddStep, Attrs, GraphProps, GraphType, Mode, VertexSet, Weight};
 
-impl<N, E, A, S, Ix> HyperMap<N, E, A, S>
+impl<N, E, A, S, Ix, L> HyperMap<N, E, A, S, L>
 where
     A: GraphProps<Kind = Mode, Ix = Ix>,
     S: BuildHasher,
@@ -25,7 +27,7 @@ where
     }
 }
 
-impl<N, E, S, Ix> DiHyperMap<N, E, Ix, S>
+impl<N, E, S, Ix, L> DiHyperMap<N, E, Ix, S, L>
 where
     S: BuildHasher,
     Ix: RawIndex,
@@ -39,7 +41,7 @@ where
         HyperMap::new()
     }
 }
-impl<N, E, S, Ix> UnHyperMap<N, E, Ix, S>
+impl<N, E, S, Ix, L> UnHyperMap<N, E, Ix, S, L>
 where
     S: BuildHasher,
     Ix: RawIndex,
@@ -54,7 +56,66 @@ where
     }
 }
 
-impl<E, A, S, Ix> HyperMap<(), E, A, S>
+impl<N, E, K, S, Ix, L> HyperMap<N, E, Attrs<Ix, K>, S, L>
+where
+    K: GraphType,
+    S: BuildHasher + Default,
+    Ix: HashIndex + ToPrimitive,
+{
+    /// consumes the hypergraph to create another whose indices are of type `I2`, converting
+    /// the id of every vertex and edge alongside the history, its cursor, and the labels of the
+    /// vertices. This is useful for shrinking the memory footprint of a populated graph, e.g.
+    /// by moving from [`usize`] to [`u32`] indices.
+    ///
+    /// **Note:** an [`IndexOutOfBounds`](rshyper::idx::IndexError::IndexOutOfBounds) error is
+    /// returned if any of the indices, including the next ones to be assigned, cannot be
+    /// represented by the target type.
+    #[allow(clippy::type_complexity)]
+    pub fn try_with_index<I2>(self) -> Result<HyperMap<N, E, Attrs<I2, K>, S, L>>
+    where
+        I2: HashIndex + Copy + Default + NumCast,
+        L: Clone + Eq + Hash,
+    {
+        let nodes = self
+            .nodes
+            .into_iter()
+            .map(|(id, node)| {
+                let id = id.try_cast::<I2>()?;
+                Ok((id, node.with_id(id)))
+            })
+            .collect::<Result<NodeMap<N, I2, S>>>()?;
+        let edges = self
+            .edges
+            .into_iter()
+            .map(|(id, edge)| {
+                let (_, domain, weight) = edge.into_parts();
+                let id = id.try_cast::<I2>()?;
+                let domain = domain
+                    .into_iter()
+                    .map(|v| v.try_cast::<I2>())
+                    .collect::<rshyper::idx::IndexResult<VertexSet<I2, S>>>()?;
+                Ok((id, Edge::from_parts(id, domain, weight)))
+            })
+            .collect::<Result<EdgeMap<E, K, I2, S>>>()?;
+        let labels = self
+            .labels
+            .map(|labels| labels.try_map_index(|v| v.try_cast::<I2>()))
+            .transpose()?;
+        let mut graph = HyperMap {
+            nodes,
+            edges,
+            attrs: self.attrs.with_index::<I2>(),
+            history: self.history.try_cast::<I2>()?,
+            domains: Default::default(),
+            incidence: Default::default(),
+            labels,
+        };
+        graph.reindex();
+        Ok(graph)
+    }
+}
+
+impl<E, A, S, Ix, L> HyperMap<(), E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher,
@@ -67,7 +128,7 @@ where
     }
 }
 
-impl<N, E, A, S, Ix> HyperMap<Option<N>, E, A, S>
+impl<N, E, A, S, Ix, L> HyperMap<Option<N>, E, A, S, L>
 where
     A: GraphProps<Ix = Ix>,
     S: BuildHasher,
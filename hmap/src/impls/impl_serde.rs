@@ -3,20 +3,25 @@
     authors: @FL03
 */
 use crate::HyperMap;
+use crate::types::Interner;
 use core::hash::{BuildHasher, Hash};
+use hashbrown::HashMap;
 use rshyper::GraphProps;
-use serde::de::{Deserialize, DeserializeOwned, MapAccess, Visitor};
-use serde::ser::Serialize;
+use rshyper::idx::{RawIndex, VertexId};
+use serde::de::{Deserialize, DeserializeOwned, Error, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap};
 
-const FIELDS: &[&str] = &["attrs", "edges", "history", "nodes"];
+const FIELDS: &[&str] = &["attrs", "edges", "history", "labels", "nodes"];
 
-impl<'a, N, E, A, S> Deserialize<'a> for HyperMap<N, E, A, S>
+/// any labels naming vertices that are missing from the deserialized graph are discarded
+impl<'a, N, E, A, S, L> Deserialize<'a> for HyperMap<N, E, A, S, L>
 where
     A: GraphProps + DeserializeOwned,
     E: DeserializeOwned,
+    L: Clone + Eq + Hash + DeserializeOwned,
     N: DeserializeOwned,
     S: BuildHasher + Default,
-    A::Ix: Default + Eq + Hash + DeserializeOwned,
+    A::Ix: Default + Eq + Hash + Clone + DeserializeOwned,
     A::Kind: DeserializeOwned,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -33,10 +38,12 @@ where
     }
 }
 
-impl<N, E, A, S> Serialize for HyperMap<N, E, A, S>
+/// the labels are only serialized once any of the vertices have been named
+impl<N, E, A, S, L> Serialize for HyperMap<N, E, A, S, L>
 where
     A: GraphProps + Serialize,
     E: Serialize,
+    L: Serialize,
     N: Serialize,
     S: BuildHasher + Default,
     A::Ix: Default + Eq + Hash + Serialize,
@@ -47,29 +54,36 @@ where
         Ser: serde::ser::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("HashGraph", 4)?;
+        let labels = self.labels().filter(|labels| !labels.is_empty());
+        let len = if labels.is_some() { 5 } else { 4 };
+        let mut state = serializer.serialize_struct("HashGraph", len)?;
         state.serialize_field("attrs", &self.attrs())?;
         state.serialize_field("edges", self.edges())?;
         state.serialize_field("history", self.history())?;
+        match labels {
+            Some(labels) => state.serialize_field("labels", labels)?,
+            None => state.skip_field("labels")?,
+        }
         state.serialize_field("nodes", self.nodes())?;
         state.end()
     }
 }
 
-struct HashGraphVisitor<N, E, A, S> {
-    _marker: core::marker::PhantomData<(N, E, A, S)>,
+struct HashGraphVisitor<N, E, A, S, L> {
+    _marker: core::marker::PhantomData<(N, E, A, S, L)>,
 }
 
-impl<'de, N, E, A, S> Visitor<'de> for HashGraphVisitor<N, E, A, S>
+impl<'de, N, E, A, S, L> Visitor<'de> for HashGraphVisitor<N, E, A, S, L>
 where
     A: GraphProps + DeserializeOwned,
     E: DeserializeOwned,
+    L: Clone + Eq + Hash + DeserializeOwned,
     N: DeserializeOwned,
     S: BuildHasher + Default,
-    A::Ix: Default + Eq + Hash + DeserializeOwned,
+    A::Ix: Default + Eq + Hash + Clone + DeserializeOwned,
     A::Kind: DeserializeOwned,
 {
-    type Value = HyperMap<N, E, A, S>;
+    type Value = HyperMap<N, E, A, S, L>;
 
     fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         formatter.write_str("a HashGraph")
@@ -79,10 +93,11 @@ where
     where
         M: MapAccess<'de>,
     {
-        let mut nodes = None;
+        let mut nodes = None::<crate::NodeMap<N, A::Ix, S>>;
         let mut edges = None;
         let mut position = None;
         let mut attrs = None;
+        let mut labels = None::<Interner<L, A::Ix, S>>;
 
         while let Some(key) = map.next_key::<&str>()? {
             match key {
@@ -104,6 +119,12 @@ where
                     }
                     position = Some(map.next_value()?);
                 }
+                "labels" => {
+                    if labels.is_some() {
+                        return Err(serde::de::Error::duplicate_field("labels"));
+                    }
+                    labels = Some(map.next_value()?);
+                }
                 "nodes" => {
                     if nodes.is_some() {
                         return Err(serde::de::Error::duplicate_field("nodes"));
@@ -118,12 +139,95 @@ where
         let edges = edges.ok_or_else(|| serde::de::Error::missing_field("edges"))?;
         let history = position.ok_or_else(|| serde::de::Error::missing_field("history"))?;
         let nodes = nodes.ok_or_else(|| serde::de::Error::missing_field("nodes"))?;
+        let labels = labels.map(|mut labels| {
+            labels.retain(|vertex| nodes.contains_key(vertex));
+            labels
+        });
 
-        Ok(HyperMap {
+        let mut graph = HyperMap {
             nodes,
             edges,
             history,
             attrs,
+            domains: Default::default(),
+            incidence: Default::default(),
+            labels,
+        };
+        // rebuild the indices of the graph
+        graph.reindex();
+        Ok(graph)
+    }
+}
+
+/// the labels are serialized as a map of each label to the index of the vertex it names
+impl<K, Ix, S> Serialize for Interner<K, Ix, S>
+where
+    K: Serialize,
+    Ix: RawIndex + Serialize,
+    S: BuildHasher,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::ser::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.len()))?;
+        for (label, vertex) in self.iter() {
+            state.serialize_entry(label, vertex)?;
+        }
+        state.end()
+    }
+}
+
+/// an error is returned if a label appears more than once or if a vertex is named by more
+/// than one label
+impl<'de, K, Ix, S> Deserialize<'de> for Interner<K, Ix, S>
+where
+    K: Clone + Eq + Hash + DeserializeOwned,
+    Ix: RawIndex + Clone + Eq + Hash + DeserializeOwned,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(InternerVisitor {
+            _marker: core::marker::PhantomData,
         })
     }
 }
+
+struct InternerVisitor<K, Ix, S> {
+    _marker: core::marker::PhantomData<(K, Ix, S)>,
+}
+
+impl<'de, K, Ix, S> Visitor<'de> for InternerVisitor<K, Ix, S>
+where
+    K: Clone + Eq + Hash + DeserializeOwned,
+    Ix: RawIndex + Clone + Eq + Hash + DeserializeOwned,
+    S: BuildHasher + Default,
+{
+    type Value = Interner<K, Ix, S>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a map of labels to the vertices they name")
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut ids = HashMap::<K, VertexId<Ix>, S>::default();
+        let mut labels = HashMap::<VertexId<Ix>, K, S>::default();
+        while let Some((label, vertex)) = map.next_entry::<K, VertexId<Ix>>()? {
+            if ids.contains_key(&label) {
+                return Err(M::Error::custom("duplicate label"));
+            }
+            if labels.contains_key(&vertex) {
+                return Err(M::Error::custom("vertex is named by more than one label"));
+            }
+            labels.insert(vertex.clone(), label.clone());
+            ids.insert(label, vertex);
+        }
+        Ok(Interner { ids, labels })
+    }
+}
@@ -0,0 +1,492 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use super::{RawWeightIndex, WeightIndex};
+use crate::HyperMap;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use core::ops::RangeBounds;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, EdgeSet, HyperIndex, RawIndex, Udx, VertexId, VertexSet};
+use rshyper_core::{Error, GraphType, Node, Result, Weight};
+
+/// a type alias for a map of named, type-erased indexes over the weights of some elements
+pub(crate) type IndexMap<T, I, S> = HashMap<String, Box<dyn RawWeightIndex<T, I>>, S>;
+/// a type alias for a [`WeightIndex`] over the weights of the nodes of a graph
+pub type NodeWeightIndex<N, K, Ix = Udx, S = DefaultHashBuilder> =
+    WeightIndex<N, K, VertexId<Ix>, S>;
+/// a type alias for a [`WeightIndex`] over the weights of the edges of a graph
+pub type EdgeWeightIndex<E, K, Ix = Udx, S = DefaultHashBuilder> = WeightIndex<E, K, EdgeId<Ix>, S>;
+/// a type alias for a [directed](rshyper_core::Directed) [`IndexedHyperMap`]
+pub type DiIndexedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    IndexedHyperMap<N, E, DiAttrs<Idx>, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`IndexedHyperMap`]
+pub type UnIndexedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    IndexedHyperMap<N, E, UnAttrs<Idx>, S>;
+
+/// The [`IndexedHyperMap`] is a hypergraph maintaining any number of named, secondary indexes
+/// over the weights of its nodes and edges, enabling equality and range lookups without
+/// scanning the entire graph.
+///
+/// ## Overview
+///
+/// The graph wraps a standard [`HyperMap`] alongside a collection of [`WeightIndex`]es, each
+/// of which is declared using a function extracting some ordered key from a weight. The
+/// indexes are maintained automatically as elements are inserted, removed, or reweighted
+/// using the methods of the graph.
+///
+/// Like the [`DomainIndex`](crate::DomainIndex), an element whose weight is exposed mutably
+/// (e.g. through the [`HyperGraph`](rshyper_core::HyperGraph) interface) is released from
+/// the indexes until the next modification of the graph, at which point it is re-keyed using
+/// its current weight. Lookups check the released elements directly, so only those elements
+/// are ever scanned.
+pub struct IndexedHyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the underlying hypergraph
+    pub(crate) graph: HyperMap<N, E, A, S>,
+    /// the indexes over the weights of the nodes
+    pub(crate) node_indexes: IndexMap<N, VertexId<A::Ix>, S>,
+    /// the indexes over the weights of the edges
+    pub(crate) edge_indexes: IndexMap<E, EdgeId<A::Ix>, S>,
+    /// the nodes released from the indexes since they were last synced
+    pub(crate) pending_nodes: VertexSet<A::Ix, S>,
+    /// the edges released from the indexes since they were last synced
+    pub(crate) pending_edges: EdgeSet<A::Ix, S>,
+}
+
+impl<N, E, A, K, Ix, S> IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`IndexedHyperMap`]
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            graph: HyperMap::new(),
+            node_indexes: HashMap::default(),
+            edge_indexes: HashMap::default(),
+            pending_nodes: VertexSet::default(),
+            pending_edges: EdgeSet::default(),
+        }
+    }
+    /// returns an immutable reference to the underlying [`HyperMap`]
+    pub const fn graph(&self) -> &HyperMap<N, E, A, S> {
+        &self.graph
+    }
+    /// consumes the instance to return the underlying [`HyperMap`], discarding its indexes
+    pub fn into_graph(self) -> HyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// returns true if any of the elements have been released from the indexes since they
+    /// were last synced with the graph
+    pub fn is_stale(&self) -> bool {
+        !self.pending_nodes.is_empty() || !self.pending_edges.is_empty()
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.graph.order()
+    }
+    /// returns the number of edges within the graph
+    pub fn size(&self) -> usize {
+        self.graph.size()
+    }
+    /// returns an iterator over the names of the indexes over the weights of the nodes
+    pub fn node_index_names(&self) -> impl Iterator<Item = &str> {
+        self.node_indexes.keys().map(String::as_str)
+    }
+    /// returns an iterator over the names of the indexes over the weights of the edges
+    pub fn edge_index_names(&self) -> impl Iterator<Item = &str> {
+        self.edge_indexes.keys().map(String::as_str)
+    }
+}
+
+impl<N, E, A, K, Ix, S> IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// add a new node with the given weight to the graph
+    pub fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        self.sync_indexes();
+        let id = self.graph.add_node(weight)?;
+        let weight = self.graph.get_node_weight(&id)?;
+        for index in self.node_indexes.values_mut() {
+            index.insert(id, weight);
+        }
+        Ok(id)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.add_node(Default::default())
+    }
+    /// add a new edge composed of the given vertices
+    pub fn add_edge<I>(&mut self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        self.sync_indexes();
+        let id = self.graph.add_edge(vertices, weight)?;
+        let weight = self.graph.get_edge_weight(&id)?;
+        for index in self.edge_indexes.values_mut() {
+            index.insert(id, weight);
+        }
+        Ok(id)
+    }
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, K, Ix, S>> {
+        self.graph.get_edge(index)
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.graph.get_node(index)
+    }
+    /// update the weight of the edge with the given index, updating the indexes accordingly
+    pub fn set_edge_weight(&mut self, index: &EdgeId<Ix>, weight: Weight<E>) -> Result<&mut Self> {
+        self.sync_indexes();
+        self.graph.set_edge_weight(index, weight)?;
+        let weight = self.graph.get_edge_weight(index)?;
+        for idx in self.edge_indexes.values_mut() {
+            idx.insert(*index, weight);
+        }
+        Ok(self)
+    }
+    /// update the weight of the node with the given index, updating the indexes accordingly
+    pub fn set_node_weight(
+        &mut self,
+        index: &VertexId<Ix>,
+        weight: Weight<N>,
+    ) -> Result<&mut Self> {
+        self.sync_indexes();
+        self.graph.set_node_weight(index, weight)?;
+        let weight = self.graph.get_node_weight(index)?;
+        for idx in self.node_indexes.values_mut() {
+            idx.insert(*index, weight);
+        }
+        Ok(self)
+    }
+    /// removes the edge with the given index, removing it from the indexes
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>> {
+        self.sync_indexes();
+        let edge = self.graph.remove_edge(index)?;
+        for idx in self.edge_indexes.values_mut() {
+            idx.remove(index);
+        }
+        Ok(edge)
+    }
+    /// removes the node with the given index alongside each of the edges containing it,
+    /// removing them from the indexes
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        self.sync_indexes();
+        let removed = self
+            .graph
+            .find_edges_with_node(index)
+            .copied()
+            .collect::<Vec<_>>();
+        let node = self.graph.remove_node(index)?;
+        for idx in self.node_indexes.values_mut() {
+            idx.remove(index);
+        }
+        for edge in removed {
+            for idx in self.edge_indexes.values_mut() {
+                idx.remove(&edge);
+            }
+        }
+        Ok(node)
+    }
+    /// rebuild each of the indexes of the graph, bringing them back in sync with the graph
+    pub fn reindex(&mut self) -> &mut Self {
+        for idx in self.node_indexes.values_mut() {
+            idx.clear();
+            for (id, node) in self.graph.nodes() {
+                idx.insert(*id, node.weight());
+            }
+        }
+        for idx in self.edge_indexes.values_mut() {
+            idx.clear();
+            for (id, edge) in self.graph.edges() {
+                idx.insert(*id, edge.weight());
+            }
+        }
+        self.pending_nodes.clear();
+        self.pending_edges.clear();
+        self
+    }
+    /// re-key each of the elements released from the indexes using their current weights
+    pub(crate) fn sync_indexes(&mut self) {
+        for id in self.pending_nodes.drain() {
+            if let Ok(weight) = self.graph.get_node_weight(&id) {
+                for idx in self.node_indexes.values_mut() {
+                    idx.insert(id, weight);
+                }
+            }
+        }
+        for id in self.pending_edges.drain() {
+            if let Ok(weight) = self.graph.get_edge_weight(&id) {
+                for idx in self.edge_indexes.values_mut() {
+                    idx.insert(id, weight);
+                }
+            }
+        }
+    }
+    /// release the given node from the indexes ahead of its weight being exposed mutably
+    pub(crate) fn release_node(&mut self, index: &VertexId<Ix>) {
+        if self.graph.contains_node(index) {
+            for idx in self.node_indexes.values_mut() {
+                idx.remove(index);
+            }
+            self.pending_nodes.insert(*index);
+        }
+    }
+    /// release the given edge from the indexes ahead of its weight being exposed mutably
+    pub(crate) fn release_edge(&mut self, index: &EdgeId<Ix>) {
+        if self.graph.contains_edge(index) {
+            for idx in self.edge_indexes.values_mut() {
+                idx.remove(index);
+            }
+            self.pending_edges.insert(*index);
+        }
+    }
+}
+
+impl<N, E, A, K, Ix, S> IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: 'static + BuildHasher + Clone + Default + Send + Sync,
+    K: GraphType,
+    Ix: HyperIndex + Send + Sync,
+    N: 'static,
+    E: 'static,
+{
+    /// declare a new index over the weights of the nodes using the given function to extract
+    /// the key of each weight, replacing any existing index with the same name.
+    pub fn add_node_index<Q, F>(&mut self, name: &str, extract: F) -> &mut Self
+    where
+        F: Fn(&Weight<N>) -> Q + Send + Sync + 'static,
+        Q: 'static + Clone + Ord + Send + Sync,
+    {
+        let mut idx = NodeWeightIndex::<N, Q, Ix, S>::new(extract);
+        for (id, node) in self.graph.nodes() {
+            idx.insert(*id, node.weight());
+        }
+        self.node_indexes.insert(name.into(), Box::new(idx));
+        self
+    }
+    /// declare a new index over the weights of the edges using the given function to extract
+    /// the key of each weight, replacing any existing index with the same name.
+    pub fn add_edge_index<Q, F>(&mut self, name: &str, extract: F) -> &mut Self
+    where
+        F: Fn(&Weight<E>) -> Q + Send + Sync + 'static,
+        Q: 'static + Clone + Ord + Send + Sync,
+    {
+        let mut idx = EdgeWeightIndex::<E, Q, Ix, S>::new(extract);
+        for (id, edge) in self.graph.edges() {
+            idx.insert(*id, edge.weight());
+        }
+        self.edge_indexes.insert(name.into(), Box::new(idx));
+        self
+    }
+    /// removes the index over the weights of the nodes with the given name, returning true if
+    /// it existed
+    pub fn remove_node_index(&mut self, name: &str) -> bool {
+        self.node_indexes.remove(name).is_some()
+    }
+    /// removes the index over the weights of the edges with the given name, returning true if
+    /// it existed
+    pub fn remove_edge_index(&mut self, name: &str) -> bool {
+        self.edge_indexes.remove(name).is_some()
+    }
+    /// returns the index over the weights of the nodes with the given name, keyed by `Q`;
+    /// the index omits any elements released since it was last synced (see
+    /// [`is_stale`](IndexedHyperMap::is_stale)).
+    pub fn node_index<Q: 'static>(&self, name: &str) -> Result<&NodeWeightIndex<N, Q, Ix, S>> {
+        self.node_indexes
+            .get(name)
+            .ok_or(Error::SecondaryIndexNotFound)?
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::SecondaryIndexKeyMismatch)
+    }
+    /// returns the index over the weights of the edges with the given name, keyed by `Q`;
+    /// the index omits any elements released since it was last synced (see
+    /// [`is_stale`](IndexedHyperMap::is_stale)).
+    pub fn edge_index<Q: 'static>(&self, name: &str) -> Result<&EdgeWeightIndex<E, Q, Ix, S>> {
+        self.edge_indexes
+            .get(name)
+            .ok_or(Error::SecondaryIndexNotFound)?
+            .as_any()
+            .downcast_ref()
+            .ok_or(Error::SecondaryIndexKeyMismatch)
+    }
+    /// returns the nodes whose key, according to the named index, is equal to the given key
+    pub fn find_nodes_by<Q>(&self, name: &str, key: &Q) -> Result<Vec<VertexId<Ix>>>
+    where
+        Q: 'static + Ord,
+    {
+        self.find_nodes_where(name, |k| k == key, |idx| idx.find(key).copied().collect())
+    }
+    /// returns the nodes whose key, according to the named index, lies within the given
+    /// range, ordered by their keys
+    pub fn find_nodes_in_range<Q, R>(&self, name: &str, range: R) -> Result<Vec<VertexId<Ix>>>
+    where
+        Q: 'static + Ord,
+        R: RangeBounds<Q>,
+    {
+        self.find_nodes_where(
+            name,
+            |k| range.contains(k),
+            |idx| {
+                idx.find_range((range.start_bound(), range.end_bound()))
+                    .copied()
+                    .collect()
+            },
+        )
+    }
+    /// returns the edges whose key, according to the named index, is equal to the given key
+    pub fn find_edges_by<Q>(&self, name: &str, key: &Q) -> Result<Vec<EdgeId<Ix>>>
+    where
+        Q: 'static + Ord,
+    {
+        self.find_edges_where(name, |k| k == key, |idx| idx.find(key).copied().collect())
+    }
+    /// returns the edges whose key, according to the named index, lies within the given
+    /// range, ordered by their keys
+    pub fn find_edges_in_range<Q, R>(&self, name: &str, range: R) -> Result<Vec<EdgeId<Ix>>>
+    where
+        Q: 'static + Ord,
+        R: RangeBounds<Q>,
+    {
+        self.find_edges_where(
+            name,
+            |k| range.contains(k),
+            |idx| {
+                idx.find_range((range.start_bound(), range.end_bound()))
+                    .copied()
+                    .collect()
+            },
+        )
+    }
+    /// consults the named node index using the `lookup` function, checking each of the
+    /// released nodes for keys satisfying the `filter` and merging them by their keys
+    fn find_nodes_where<Q, F, L>(
+        &self,
+        name: &str,
+        filter: F,
+        lookup: L,
+    ) -> Result<Vec<VertexId<Ix>>>
+    where
+        Q: 'static + Ord,
+        F: Fn(&Q) -> bool,
+        L: FnOnce(&NodeWeightIndex<N, Q, Ix, S>) -> Vec<VertexId<Ix>>,
+    {
+        let idx = self.node_index::<Q>(name)?;
+        let mut released = Vec::new();
+        for id in &self.pending_nodes {
+            let key = idx.key_of(self.graph.get_node_weight(id)?);
+            if filter(&key) {
+                released.push((key, *id));
+            }
+        }
+        Ok(merge_released(lookup(idx), released, |v| idx.get_key(v)))
+    }
+    /// consults the named edge index using the `lookup` function, checking each of the
+    /// released edges for keys satisfying the `filter` and merging them by their keys
+    fn find_edges_where<Q, F, L>(&self, name: &str, filter: F, lookup: L) -> Result<Vec<EdgeId<Ix>>>
+    where
+        Q: 'static + Ord,
+        F: Fn(&Q) -> bool,
+        L: FnOnce(&EdgeWeightIndex<E, Q, Ix, S>) -> Vec<EdgeId<Ix>>,
+    {
+        let idx = self.edge_index::<Q>(name)?;
+        let mut released = Vec::new();
+        for id in &self.pending_edges {
+            let key = idx.key_of(self.graph.get_edge_weight(id)?);
+            if filter(&key) {
+                released.push((key, *id));
+            }
+        }
+        Ok(merge_released(lookup(idx), released, |e| idx.get_key(e)))
+    }
+}
+
+impl<N, E, A, S> Default for IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("IndexedHyperMap")
+            .field("graph", &self.graph)
+            .field("node_indexes", &self.node_indexes.keys())
+            .field("edge_indexes", &self.edge_indexes.keys())
+            .field("pending_nodes", &self.pending_nodes)
+            .field("pending_edges", &self.pending_edges)
+            .finish()
+    }
+}
+
+/// merges the released elements, keyed using their current weights, into the elements found
+/// by an index, which are already ordered by their keys; the released elements are placed
+/// after any indexed elements sharing their key.
+fn merge_released<'a, Q, I, F>(found: Vec<I>, mut released: Vec<(Q, I)>, key_of: F) -> Vec<I>
+where
+    Q: 'a + Ord,
+    I: Ord,
+    F: Fn(&I) -> Option<&'a Q>,
+{
+    released.sort();
+    let mut merged = Vec::with_capacity(found.len() + released.len());
+    let mut released = released.into_iter().peekable();
+    for id in found {
+        let key = key_of(&id);
+        while let Some((_, next)) = released.next_if(|(k, _)| Some(k) < key) {
+            merged.push(next);
+        }
+        merged.push(id);
+    }
+    merged.extend(released.map(|(_, id)| id));
+    merged
+}
@@ -0,0 +1,160 @@
+/*
+    appellation: impl_hyper_graph <module>
+    authors: @FL03
+*/
+use crate::indexed::IndexedHyperMap;
+use crate::iter;
+use core::hash::{BuildHasher, Hash};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper_core::traits::{HyperGraph, HyperGraphIterEdge, HyperGraphIterNode, RawHyperGraph};
+use rshyper_core::{GraphProps, GraphType, Node, Result, VertexSet, Weight};
+
+impl<N, E, A, S> RawHyperGraph<A> for IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    type Node<_N> = Node<_N, A::Ix>;
+    type Edge<_E> = HashEdge<_E, A::Kind, A::Ix, S>;
+}
+
+/// exposing the edges or nodes of the graph mutably through the [`HyperGraph`] interface
+/// releases them from the indexes until the next modification of the graph, since their
+/// weights may be modified directly.
+impl<N, E, A, K, Ix, S> HyperGraph<N, E, A> for IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<A::Ix>> {
+        self.add_node(weight)
+    }
+
+    fn add_surface<I>(&mut self, iter: I, weight: Weight<E>) -> Result<EdgeId<A::Ix>>
+    where
+        I: IntoIterator<Item = VertexId<A::Ix>>,
+    {
+        self.add_edge(iter, weight)
+    }
+
+    fn get_edge_domain(&self, index: &EdgeId<A::Ix>) -> Result<&VertexSet<A::Ix, S>> {
+        self.graph.get_domain(index)
+    }
+
+    fn get_edge_domain_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut VertexSet<A::Ix, S>> {
+        self.graph.get_domain_mut(index)
+    }
+
+    fn get_edge(&self, index: &EdgeId<A::Ix>) -> Result<&HashEdge<E, A::Kind, A::Ix, S>> {
+        self.graph.get_edge(index)
+    }
+
+    fn get_edge_mut(
+        &mut self,
+        index: &EdgeId<A::Ix>,
+    ) -> Result<&mut HashEdge<E, A::Kind, A::Ix, S>> {
+        self.release_edge(index);
+        self.graph.get_edge_mut(index)
+    }
+
+    fn get_edge_weight(&self, index: &EdgeId<A::Ix>) -> Result<&Weight<E>> {
+        self.graph.get_edge_weight(index)
+    }
+
+    fn get_edge_weight_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut Weight<E>> {
+        self.release_edge(index);
+        self.graph.get_edge_weight_mut(index)
+    }
+
+    fn get_node(&self, index: &VertexId<A::Ix>) -> Result<&Node<N, A::Ix>> {
+        self.graph.get_node(index)
+    }
+
+    fn get_node_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Node<N, A::Ix>> {
+        self.release_node(index);
+        self.graph.get_node_mut(index)
+    }
+
+    fn get_node_weight(&self, index: &VertexId<A::Ix>) -> Result<&Weight<N>> {
+        self.graph.get_node_weight(index)
+    }
+
+    fn get_node_weight_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Weight<N>> {
+        self.release_node(index);
+        self.graph.get_node_weight_mut(index)
+    }
+
+    fn contains_edge(&self, index: &EdgeId<A::Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+
+    fn contains_node(&self, index: &VertexId<A::Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+
+    fn find_edges_with_node(
+        &self,
+        index: &VertexId<A::Ix>,
+    ) -> impl Iterator<Item = &EdgeId<A::Ix>> {
+        self.graph.find_edges_with_node(index)
+    }
+}
+
+impl<N, E, A, S> HyperGraphIterNode<N, E, A> for IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+{
+    type Nodes<'a>
+        = iter::NodeIter<'a, N, A::Ix>
+    where
+        Self: 'a,
+        Self::Node<N>: 'a;
+    type Verts<'a>
+        = iter::NodeKeys<'a, N, A::Ix>
+    where
+        Self: 'a;
+
+    fn iter_nodes(&self) -> Self::Nodes<'_> {
+        self.graph.iter_nodes()
+    }
+
+    fn vertices(&self) -> Self::Verts<'_> {
+        self.graph.vertices()
+    }
+}
+
+impl<N, E, A, S> HyperGraphIterEdge<N, E, A> for IndexedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+{
+    type Surfaces<'a>
+        = iter::EdgeIter<'a, E, A::Kind, A::Ix, S>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    type Edges<'a>
+        = iter::EdgeKeys<'a, E, A::Kind, A::Ix, S>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    fn iter_surfaces(&self) -> Self::Surfaces<'_> {
+        self.graph.iter_edges()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        self.graph.iter_edge_keys()
+    }
+}
@@ -0,0 +1,23 @@
+/*
+    appellation: indexed <module>
+    authors: @FL03
+*/
+//! this module implements the [`IndexedHyperMap`], a hypergraph maintaining any number of
+//! secondary indexes over the weights of its nodes and edges, each of which is described by a
+//! [`WeightIndex`].
+#[doc(inline)]
+pub use self::{graph::*, weight_index::*};
+
+mod graph;
+mod weight_index;
+
+mod impls {
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+    #[doc(inline)]
+    pub use super::weight_index::*;
+}
@@ -0,0 +1,140 @@
+/*
+    appellation: weight_index <module>
+    authors: @FL03
+*/
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::Any;
+use core::hash::{BuildHasher, Hash};
+use core::ops::RangeBounds;
+use hashbrown::{DefaultHashBuilder, HashMap, HashSet};
+use rshyper_core::Weight;
+
+/// a type alias for the boxed function used to extract the key of a [`WeightIndex`]
+pub type KeyExtractor<T, K> = Box<dyn Fn(&Weight<T>) -> K + Send + Sync>;
+
+/// The [`WeightIndex`] is a secondary index over some projection, or _key_, of the weights of
+/// a collection of elements (keyed by `I`). The keys are ordered, enabling both equality and
+/// range lookups without scanning each of the elements.
+pub struct WeightIndex<T, K, I, S = DefaultHashBuilder> {
+    /// the function used to extract the key from a weight
+    pub(crate) extract: KeyExtractor<T, K>,
+    /// the elements associated with each key
+    pub(crate) entries: BTreeMap<K, HashSet<I, S>>,
+    /// the key of each element
+    pub(crate) keys: HashMap<I, K, S>,
+}
+
+/// [`RawWeightIndex`] is an object-safe interface used to maintain the indexes of a graph
+/// without knowledge of their keys.
+pub(crate) trait RawWeightIndex<T, I>: Send + Sync {
+    /// record the given element using its weight
+    fn insert(&mut self, id: I, weight: &Weight<T>);
+    /// remove the given element from the index
+    fn remove(&mut self, id: &I);
+    /// remove every element from the index
+    fn clear(&mut self);
+    /// returns the index as a type that may be downcast
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T, K, I, S> WeightIndex<T, K, I, S>
+where
+    S: BuildHasher + Default,
+{
+    /// returns a new, empty index using the given function to extract the key of each weight
+    pub fn new<F>(extract: F) -> Self
+    where
+        F: Fn(&Weight<T>) -> K + Send + Sync + 'static,
+    {
+        Self {
+            extract: Box::new(extract),
+            entries: BTreeMap::new(),
+            keys: HashMap::default(),
+        }
+    }
+}
+
+impl<T, K, I, S> WeightIndex<T, K, I, S>
+where
+    I: Eq + Hash,
+    K: Ord,
+    S: BuildHasher,
+{
+    /// returns the key of the given weight
+    pub fn key_of(&self, weight: &Weight<T>) -> K {
+        (self.extract)(weight)
+    }
+    /// returns true if the index contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+    /// returns the number of elements recorded by the index
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+    /// returns the key recorded for the given element, if any
+    pub fn get_key(&self, id: &I) -> Option<&K> {
+        self.keys.get(id)
+    }
+    /// returns an iterator over the elements whose key is equal to the given key
+    pub fn find(&self, key: &K) -> impl Iterator<Item = &I> {
+        self.entries.get(key).into_iter().flatten()
+    }
+    /// returns an iterator over the elements whose key lies within the given range, in order
+    /// of their keys
+    pub fn find_range<R>(&self, range: R) -> impl Iterator<Item = &I>
+    where
+        R: RangeBounds<K>,
+    {
+        self.entries.range(range).flat_map(|(_, ids)| ids)
+    }
+}
+
+impl<T, K, I, S> RawWeightIndex<T, I> for WeightIndex<T, K, I, S>
+where
+    T: 'static,
+    I: 'static + Clone + Eq + Hash + Send + Sync,
+    K: 'static + Clone + Ord + Send + Sync,
+    S: 'static + BuildHasher + Default + Send + Sync,
+{
+    fn insert(&mut self, id: I, weight: &Weight<T>) {
+        self.remove(&id);
+        let key = self.key_of(weight);
+        self.entries
+            .entry(key.clone())
+            .or_default()
+            .insert(id.clone());
+        self.keys.insert(id, key);
+    }
+
+    fn remove(&mut self, id: &I) {
+        if let Some(key) = self.keys.remove(id) {
+            if let Some(ids) = self.entries.get_mut(&key) {
+                ids.remove(id);
+                if ids.is_empty() {
+                    self.entries.remove(&key);
+                }
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.keys.clear();
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<T, K, I, S> core::fmt::Debug for WeightIndex<T, K, I, S>
+where
+    I: core::fmt::Debug,
+    K: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.entries.iter()).finish()
+    }
+}
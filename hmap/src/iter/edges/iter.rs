@@ -3,10 +3,11 @@
     authors: @FL03
 */
 use core::hash::BuildHasher;
+use core::ops::Deref;
 use hashbrown::hash_map;
-use rshyper_core::GraphType;
 use rshyper_core::edge::HashEdge;
 use rshyper_core::idx::{EdgeId, HashIndex, RawIndex};
+use rshyper_core::{GraphType, Weight};
 
 /// [`EdgeIter`] is an iterator over the edge entries within the `HyperMap`, yielding a 2-tuple
 /// consisting of:
@@ -22,11 +23,23 @@ where
 {
     pub(crate) iter: hash_map::Iter<'a, EdgeId<Ix>, HashEdge<E, K, Ix, S>>,
 }
+/// [`EdgeMut`] is a handle to an edge yielded by the mutable iterators of the `HyperMap`,
+/// dereferencing to the underlying [`HashEdge`] while only allowing its weight to be modified;
+/// the domain is left untouched so that the internal indices of the graph remain in sync.
+#[repr(transparent)]
+pub struct EdgeMut<'a, E, K, Ix, S>
+where
+    K: GraphType,
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    pub(crate) edge: &'a mut HashEdge<E, K, Ix, S>,
+}
 /// [`EdgeIterMut`] is a mutable iterator over the edge entries within the `HyperMap`, yielding
 /// a 2-tuple consisting of:
 ///
 /// - `0`: a reference to the [`EdgeId`] of the entry
-/// - `1`: a mutable reference to the [`HashEdge`] associated with the entry.
+/// - `1`: an [`EdgeMut`] handle to the [`HashEdge`] associated with the entry.
 #[repr(transparent)]
 pub struct EdgeIterMut<'a, E, K, Ix, S>
 where
@@ -57,7 +70,9 @@ where
 {
     pub(crate) iter: hash_map::Values<'a, EdgeId<Ix>, HashEdge<E, K, Ix, S>>,
 }
-/// [`EdgeValuesMut`] is a mutable iterator over the surfaces of a hypergraph, yielding
+/// [`EdgeValuesMut`] is a mutable iterator over the surfaces of a hypergraph, yielding an
+/// [`EdgeMut`] handle to each of the edges.
+#[repr(transparent)]
 pub struct EdgeValuesMut<'a, E, K, Ix, S>
 where
     E: 'a,
@@ -71,6 +86,48 @@ where
 /*
  ************* Implementations *************
 */
+impl<'a, E, K, Ix, S> EdgeMut<'a, E, K, Ix, S>
+where
+    K: GraphType,
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    /// returns a new handle to the given edge
+    pub(crate) const fn new(edge: &'a mut HashEdge<E, K, Ix, S>) -> Self {
+        Self { edge }
+    }
+    /// returns an immutable reference to the edge
+    pub const fn get(&self) -> &HashEdge<E, K, Ix, S> {
+        self.edge
+    }
+    /// returns a mutable reference to the weight of the edge
+    pub const fn weight_mut(&mut self) -> &mut Weight<E> {
+        self.edge.weight_mut()
+    }
+    /// update the weight of the edge, returning a mutable reference to the handle
+    pub fn set_weight(&mut self, weight: Weight<E>) -> &mut Self {
+        self.edge.set_weight(weight);
+        self
+    }
+    /// consumes the handle to return a mutable reference to the weight of the edge
+    pub const fn into_weight_mut(self) -> &'a mut Weight<E> {
+        self.edge.weight_mut()
+    }
+}
+
+impl<E, K, Ix, S> Deref for EdgeMut<'_, E, K, Ix, S>
+where
+    K: GraphType,
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    type Target = HashEdge<E, K, Ix, S>;
+
+    fn deref(&self) -> &Self::Target {
+        self.edge
+    }
+}
+
 impl<'a, E, K, Ix, S> Iterator for EdgeKeys<'a, E, K, Ix, S>
 where
     E: 'a,
@@ -106,10 +163,10 @@ where
     Ix: HashIndex,
     S: BuildHasher + 'a,
 {
-    type Item = &'a mut HashEdge<E, K, Ix, S>;
+    type Item = EdgeMut<'a, E, K, Ix, S>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
+        self.iter.next().map(EdgeMut::new)
     }
 }
 
@@ -134,9 +191,9 @@ where
     Ix: HashIndex,
     S: BuildHasher + 'a,
 {
-    type Item = (&'a EdgeId<Ix>, &'a mut HashEdge<E, K, Ix, S>);
+    type Item = (&'a EdgeId<Ix>, EdgeMut<'a, E, K, Ix, S>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
+        self.iter.next().map(|(id, edge)| (id, EdgeMut::new(edge)))
     }
 }
@@ -10,6 +10,8 @@ use rshyper_core::GraphType;
 use rshyper_core::edge::HashEdge;
 use rshyper_core::idx::{EdgeId, RawIndex};
 
+use super::EdgeMut;
+
 /// [`ParEdgeValues`] is a parallel iterator over the edges of a hypergraph, yielding
 /// references to the edges as [`HashEdge`]s.
 #[repr(transparent)]
@@ -23,7 +25,7 @@ where
     pub(crate) iter: hash_map::ParValues<'a, EdgeId<Idx>, HashEdge<E, K, Idx, S>>,
 }
 /// [`ParEdgeValuesMut`] is a mutable parallel iterator over the edges of a hypergraph,
-/// yielding a mutable reference to the corresponding [`HashEdge`].
+/// yielding an [`EdgeMut`] handle to the corresponding [`HashEdge`].
 #[repr(transparent)]
 pub struct ParEdgeValuesMut<'a, E, K, Idx, S>
 where
@@ -63,12 +65,12 @@ where
     Idx: RawIndex + Eq + Hash + Send + Sync,
     S: BuildHasher + Send + Sync + 'a,
 {
-    type Item = &'a mut HashEdge<E, K, Idx, S>;
+    type Item = EdgeMut<'a, E, K, Idx, S>;
 
     fn drive_unindexed<C>(self, consumer: C) -> C::Result
     where
         C: UnindexedConsumer<Self::Item>,
     {
-        self.iter.drive_unindexed(consumer)
+        self.iter.map(EdgeMut::new).drive_unindexed(consumer)
     }
 }
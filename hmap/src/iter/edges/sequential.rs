@@ -23,8 +23,8 @@ where
     pub(crate) keys: slice::Iter<'a, EdgeId<Ix>>,
     pub(crate) iter: iter::EdgeIter<'a, E, K, Ix, S>,
 }
-/// [`SeqEdgeIterMut`] is a mutable iterator producing mutable references to the edge entries
-/// w.r.t. the order in-which they were inserted.
+/// [`SeqEdgeIterMut`] is a mutable iterator producing [`EdgeMut`](iter::EdgeMut) handles to
+/// the edge entries w.r.t. the order in-which they were inserted.
 pub struct SeqEdgeIterMut<'a, E, K, Ix, S>
 where
     S: BuildHasher + 'a,
@@ -56,8 +56,8 @@ where
     pub(crate) keys: slice::Iter<'a, EdgeId<Ix>>,
     pub(crate) values: iter::EdgeValues<'a, E, K, Ix, S>,
 }
-/// [`SeqEdgeValuesMut`] is a mutable iterator producing mutable references to the edges of a
-/// hypergraph in a manner that respects the order in-which they were inserted.
+/// [`SeqEdgeValuesMut`] is a mutable iterator producing [`EdgeMut`](iter::EdgeMut) handles to
+/// the edges of a hypergraph in a manner that respects the order in-which they were inserted.
 pub struct SeqEdgeValuesMut<'a, E, K, Ix, S>
 where
     S: BuildHasher + 'a,
@@ -99,7 +99,7 @@ where
     K: GraphType,
     Ix: HashIndex,
 {
-    type Item = (&'a EdgeId<Ix>, &'a mut HashEdge<E, K, Ix, S>);
+    type Item = (&'a EdgeId<Ix>, iter::EdgeMut<'a, E, K, Ix, S>);
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(next) = self.keys.next() {
@@ -150,7 +150,7 @@ where
     K: GraphType,
     Ix: HashIndex,
 {
-    type Item = &'a mut HashEdge<E, K, Ix, S>;
+    type Item = iter::EdgeMut<'a, E, K, Ix, S>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(next) = self.keys.next() {
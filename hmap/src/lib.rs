@@ -12,6 +12,7 @@
 //!   - `A::Kind`: the _kind_ of hypergraph, either [`Directed`](rshyper::Directed) or [`Undirected`](rshyper::Undirected)
 //!   - `A::Ix`: the type of indices used by the instance; bounded by the [`RawIndex` trait
 //! - `S`: the type of [`BuildHasher`] used for the underling stores
+//! - `L`: the type of labels that may be used to name the vertices; defaults to [`String`]
 //!
 //! This is done to maximize compatibility and flexibility, allowing users to define their own
 //! hypergraphs with custom node and edge types, as well as different index types and hashers.
@@ -90,14 +91,38 @@ mod macros {
     pub(crate) mod seal;
 }
 // modules
+pub mod builder;
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+#[cfg(feature = "alloc")]
+pub mod edvw;
+#[cfg(feature = "alloc")]
+pub mod indexed;
 pub mod iter;
+#[cfg(feature = "alloc")]
+pub mod multilayer;
+#[cfg(feature = "alloc")]
+pub mod nested;
+#[cfg(feature = "alloc")]
+pub mod ordered;
+#[cfg(feature = "alloc")]
+pub mod persistent;
+#[cfg(feature = "alloc")]
+pub mod property;
+#[cfg(feature = "std")]
+pub mod sharded;
+#[cfg(feature = "alloc")]
+pub mod simplicial;
+pub mod temporal;
 
 mod graph;
 
 mod impls {
+    pub mod impl_compact;
     pub mod impl_graph;
     pub mod impl_hyper_graph;
     pub mod impl_iter;
+    pub mod impl_labels;
     pub mod impl_ops;
     pub mod impl_repr;
 
@@ -118,18 +143,52 @@ mod types {
     pub use self::prelude::*;
 
     mod aliases;
+    mod domain_index;
+    mod incidence_index;
+    mod index_remap;
+    mod interner;
 
     pub(crate) mod prelude {
         #[doc(inline)]
         pub use super::aliases::*;
+        #[doc(inline)]
+        pub use super::domain_index::*;
+        #[doc(inline)]
+        pub use super::incidence_index::*;
+        #[doc(inline)]
+        pub use super::index_remap::*;
+        #[doc(inline)]
+        pub use super::interner::*;
     }
 }
 // re-exports
 #[doc(inline)]
-pub use self::{graph::*, types::prelude::*};
+pub use self::{builder::HyperMapBuilder, graph::*, types::prelude::*};
 // prelude
 pub mod prelude {
+    pub use super::builder::*;
+    #[cfg(feature = "alloc")]
+    pub use super::dynamic::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use super::edvw::prelude::*;
     pub use super::graph::*;
+    #[cfg(feature = "alloc")]
+    pub use super::indexed::prelude::*;
     pub use super::iter::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use super::multilayer::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use super::nested::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use super::ordered::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use super::persistent::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use super::property::prelude::*;
+    #[cfg(feature = "std")]
+    pub use super::sharded::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use super::simplicial::prelude::*;
+    pub use super::temporal::prelude::*;
     pub use super::types::prelude::*;
 }
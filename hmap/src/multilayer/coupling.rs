@@ -0,0 +1,87 @@
+/*
+    appellation: coupling <module>
+    authors: @FL03
+*/
+use rshyper_core::Weight;
+use rshyper_core::idx::{EdgeId, LayerId, NodeLayer, RawIndex, Udx, VertexId};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The [`Coupling`] is an _interlayer_ hyperedge of a
+/// [`MultiLayerHyperMap`](super::MultiLayerHyperMap), joining together a collection of
+/// [node-layers](NodeLayer) that may span any number of layers. The most common case is that
+/// of a _diagonal_ coupling, linking the same vertex across several layers.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Coupling<E, Ix = Udx>
+where
+    Ix: RawIndex,
+{
+    /// the index of the coupling
+    pub(crate) id: EdgeId<Ix>,
+    /// the node-layers joined by the coupling
+    pub(crate) members: Vec<NodeLayer<Ix>>,
+    /// the weight of the coupling
+    pub(crate) weight: Weight<E>,
+}
+
+impl<E, Ix> Coupling<E, Ix>
+where
+    Ix: RawIndex,
+{
+    /// returns a new coupling with the given id, members, and weight
+    pub const fn new(id: EdgeId<Ix>, members: Vec<NodeLayer<Ix>>, weight: Weight<E>) -> Self {
+        Self {
+            id,
+            members,
+            weight,
+        }
+    }
+    /// returns an immutable reference to the id of the coupling
+    pub const fn id(&self) -> &EdgeId<Ix> {
+        &self.id
+    }
+    /// returns the node-layers joined by the coupling
+    pub fn members(&self) -> &[NodeLayer<Ix>] {
+        &self.members
+    }
+    /// returns an immutable reference to the weight of the coupling
+    pub const fn weight(&self) -> &Weight<E> {
+        &self.weight
+    }
+    /// returns a mutable reference to the weight of the coupling
+    pub const fn weight_mut(&mut self) -> &mut Weight<E> {
+        &mut self.weight
+    }
+    /// returns the number of node-layers joined by the coupling
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+    /// returns true if the coupling joins no node-layers
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+    /// returns true if the coupling contains the given vertex within the given layer
+    pub fn contains(&self, layer: &LayerId, vertex: &VertexId<Ix>) -> bool
+    where
+        Ix: PartialEq,
+    {
+        self.members.iter().any(|(l, v)| l == layer && v == vertex)
+    }
+    /// returns true if the coupling contains the given vertex within any layer
+    pub fn contains_vertex(&self, vertex: &VertexId<Ix>) -> bool
+    where
+        Ix: PartialEq,
+    {
+        self.members.iter().any(|(_, v)| v == vertex)
+    }
+    /// returns an iterator over the layers spanned by the coupling
+    pub fn layers(&self) -> impl Iterator<Item = &LayerId> {
+        self.members.iter().map(|(layer, _)| layer)
+    }
+}
@@ -0,0 +1,379 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use super::{Coupling, LayerView};
+use crate::HyperMap;
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, EdgeSet, HyperIndex, LayerId, NodeLayer, RawIndex, Udx, VertexId};
+use rshyper_core::{Error, GraphType, Node, Result, Weight};
+
+/// a type alias for a map associating each coupling with its index
+pub type CouplingMap<E, Ix, S = DefaultHashBuilder> = HashMap<EdgeId<Ix>, Coupling<E, Ix>, S>;
+/// a type alias for a [directed](rshyper_core::Directed) [`MultiLayerHyperMap`]
+pub type DiMultiLayerHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    MultiLayerHyperMap<N, E, DiAttrs<Idx>, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`MultiLayerHyperMap`]
+pub type UnMultiLayerHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    MultiLayerHyperMap<N, E, UnAttrs<Idx>, S>;
+
+/// The [`MultiLayerHyperMap`] is a hypergraph whose edges are partitioned into several
+/// _layers_, each of which describes a different kind of relation between the same set of
+/// vertices, with the layers being joined together by interlayer [couplings](Coupling).
+///
+/// ## Overview
+///
+/// The graph wraps a single [`HyperMap`] storing the shared vertices alongside the
+/// (intralayer) edges of every layer, ensuring that the data associated with each vertex is
+/// never duplicated and that the indices of the edges are unique across layers. The wrapped
+/// graph is therefore the _aggregate_ of the layers and may be accessed directly using the
+/// [`aggregate`](MultiLayerHyperMap::aggregate) method, while each layer may be inspected
+/// using a [`LayerView`].
+///
+/// The couplings draw their indices from the same history as the intralayer edges, meaning
+/// that no coupling will ever share an index with an edge of the graph.
+pub struct MultiLayerHyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the aggregate hypergraph containing the shared vertices and the edges of every layer
+    pub(crate) graph: HyperMap<N, E, A, S>,
+    /// the edges contained by each layer
+    pub(crate) layers: Vec<EdgeSet<A::Ix, S>>,
+    /// maps each edge onto the layer containing it
+    pub(crate) memberships: HashMap<EdgeId<A::Ix>, LayerId, S>,
+    /// the interlayer edges of the graph
+    pub(crate) couplings: CouplingMap<E, A::Ix, S>,
+    /// maps each node-layer onto the couplings containing it
+    pub(crate) coupled: HashMap<NodeLayer<A::Ix>, EdgeSet<A::Ix, S>, S>,
+}
+
+impl<N, E, A, K, Ix, S> MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`MultiLayerHyperMap`] without any layers
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            graph: HyperMap::new(),
+            layers: Vec::new(),
+            memberships: HashMap::default(),
+            couplings: HashMap::default(),
+            coupled: HashMap::default(),
+        }
+    }
+    /// returns a new instance of the [`MultiLayerHyperMap`] with the given number of (empty)
+    /// layers
+    pub fn with_layers(layers: usize) -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        let mut graph = Self::new();
+        graph.layers.resize_with(layers, Default::default);
+        graph
+    }
+    /// returns an immutable reference to the aggregate [`HyperMap`] containing the shared
+    /// vertices alongside the edges of every layer
+    pub const fn aggregate(&self) -> &HyperMap<N, E, A, S> {
+        &self.graph
+    }
+    /// returns an immutable reference to the couplings of the graph
+    pub const fn couplings(&self) -> &CouplingMap<E, Ix, S> {
+        &self.couplings
+    }
+    /// consumes the instance to return the aggregate [`HyperMap`], discarding the layers and
+    /// couplings
+    pub fn into_aggregate(self) -> HyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty() && self.couplings.is_empty()
+    }
+    /// returns the number of layers within the graph
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+    /// returns an iterator over the indices of the layers within the graph
+    pub fn layers(&self) -> impl Iterator<Item = LayerId> {
+        (0..self.layer_count()).map(LayerId::new)
+    }
+    /// returns true if the graph contains a layer with the given index
+    pub fn contains_layer(&self, layer: &LayerId) -> bool {
+        *layer.get() < self.layer_count()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.graph.order()
+    }
+    /// returns the number of (intralayer) edges within the graph
+    pub fn size(&self) -> usize {
+        self.graph.size()
+    }
+    /// returns the number of couplings within the graph
+    pub fn coupling_count(&self) -> usize {
+        self.couplings.len()
+    }
+}
+
+impl<N, E, A, K, Ix, S> MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+    /// returns true if the graph contains a coupling with the given index
+    pub fn contains_coupling(&self, index: &EdgeId<Ix>) -> bool {
+        self.couplings.contains_key(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+    /// returns an immutable reference to the coupling with the given index
+    pub fn get_coupling(&self, index: &EdgeId<Ix>) -> Result<&Coupling<E, Ix>> {
+        self.couplings.get(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, K, Ix, S>> {
+        self.graph.get_edge(index)
+    }
+    /// returns the index of the layer containing the edge with the given index
+    pub fn get_edge_layer(&self, index: &EdgeId<Ix>) -> Result<LayerId> {
+        self.memberships
+            .get(index)
+            .copied()
+            .ok_or(Error::EdgeNotFound)
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.graph.get_node(index)
+    }
+    /// returns an iterator over the couplings containing the given vertex within the given
+    /// layer
+    pub fn find_couplings(
+        &self,
+        layer: &LayerId,
+        vertex: &VertexId<Ix>,
+    ) -> impl Iterator<Item = &EdgeId<Ix>> {
+        self.coupled.get(&(*layer, *vertex)).into_iter().flatten()
+    }
+    /// returns an iterator over the node-layers that share a coupling with the given vertex
+    /// within the given layer
+    pub fn find_coupled(
+        &self,
+        layer: &LayerId,
+        vertex: &VertexId<Ix>,
+    ) -> impl Iterator<Item = NodeLayer<Ix>> {
+        let source = (*layer, *vertex);
+        self.find_couplings(layer, vertex)
+            .filter_map(|id| self.couplings.get(id))
+            .flat_map(|coupling| coupling.members().iter().copied())
+            .filter(move |member| *member != source)
+    }
+    /// returns a [`LayerView`] of the given layer
+    pub fn layer(&self, layer: LayerId) -> Result<LayerView<'_, N, E, A, S>> {
+        if !self.contains_layer(&layer) {
+            return Err(Error::LayerNotFound);
+        }
+        Ok(LayerView::new(self, layer))
+    }
+    /// returns an iterator over the [views](LayerView) of every layer within the graph
+    pub fn iter_layers(&self) -> impl Iterator<Item = LayerView<'_, N, E, A, S>> {
+        self.layers().map(|layer| LayerView::new(self, layer))
+    }
+}
+
+impl<N, E, A, K, Ix, S> MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// add a new, empty layer to the graph, returning its index
+    pub fn add_layer(&mut self) -> LayerId {
+        let id = LayerId::new(self.layer_count());
+        self.layers.push(Default::default());
+        id
+    }
+    /// add a new node with the given weight to the graph; the node is shared by every layer
+    pub fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        self.graph.add_node(weight)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.graph.add_vertex()
+    }
+    /// add a new edge composed of the given vertices to the given layer
+    pub fn add_edge<I>(
+        &mut self,
+        layer: LayerId,
+        vertices: I,
+        weight: Weight<E>,
+    ) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        if !self.contains_layer(&layer) {
+            return Err(Error::LayerNotFound);
+        }
+        let id = self.graph.add_edge(vertices, weight)?;
+        self.layers[*layer.get()].insert(id);
+        self.memberships.insert(id, layer);
+        Ok(id)
+    }
+    /// add a new, unweighted edge composed of the given vertices to the given layer
+    pub fn add_link<I>(&mut self, layer: LayerId, vertices: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        E: Default,
+    {
+        self.add_edge(layer, vertices, Default::default())
+    }
+    /// add a new coupling joining the given node-layers; the id of the coupling is drawn from
+    /// the same cursor as the edges, keeping the two disjoint, though it is never recorded in
+    /// the history of the underlying graph since the graph never holds the coupling.
+    pub fn add_coupling<I>(&mut self, members: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = NodeLayer<Ix>>,
+    {
+        let members = members.into_iter().collect::<Vec<_>>();
+        if members.is_empty() {
+            return Err(Error::EmptyHyperedge);
+        }
+        for (layer, vertex) in &members {
+            if !self.contains_layer(layer) {
+                return Err(Error::LayerNotFound);
+            }
+            if !self.graph.contains_node(vertex) {
+                return Err(Error::NodeNotFound);
+            }
+        }
+        let id = self.graph.position_mut().next_edge()?;
+        for member in &members {
+            self.coupled.entry(*member).or_default().insert(id);
+        }
+        self.couplings
+            .insert(id, Coupling::new(id, members, weight));
+        Ok(id)
+    }
+    /// add a new, unweighted coupling joining the given vertex across each of the given layers
+    pub fn couple<I>(&mut self, vertex: VertexId<Ix>, layers: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = LayerId>,
+        E: Default,
+    {
+        let members = layers.into_iter().map(|layer| (layer, vertex));
+        self.add_coupling(members, Default::default())
+    }
+    /// removes the coupling with the given index
+    pub fn remove_coupling(&mut self, index: &EdgeId<Ix>) -> Result<Coupling<E, Ix>> {
+        let coupling = self.couplings.remove(index).ok_or(Error::EdgeNotFound)?;
+        for member in coupling.members() {
+            if let Some(ids) = self.coupled.get_mut(member) {
+                ids.remove(index);
+                if ids.is_empty() {
+                    self.coupled.remove(member);
+                }
+            }
+        }
+        Ok(coupling)
+    }
+    /// removes the (intralayer) edge with the given index
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>> {
+        let edge = self.graph.remove_edge(index)?;
+        if let Some(layer) = self.memberships.remove(index) {
+            self.layers[*layer.get()].remove(index);
+        }
+        Ok(edge)
+    }
+    /// removes the node with the given index from every layer, alongside each of the edges
+    /// and couplings containing it
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let node = self.graph.remove_node(index)?;
+        // drop the memberships of the edges removed alongside the node
+        let graph = &self.graph;
+        self.memberships.retain(|id, _| graph.contains_edge(id));
+        self.layers
+            .iter_mut()
+            .for_each(|edges| edges.retain(|id| graph.contains_edge(id)));
+        // remove the couplings containing the node
+        let removed = self
+            .couplings
+            .values()
+            .filter(|coupling| coupling.contains_vertex(index))
+            .map(|coupling| *coupling.id())
+            .collect::<Vec<_>>();
+        for id in removed {
+            self.remove_coupling(&id)?;
+        }
+        Ok(node)
+    }
+}
+
+impl<N, E, A, S> Clone for MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    A::Ix: Clone,
+    E: Clone,
+    S: BuildHasher + Clone,
+    HyperMap<N, E, A, S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            layers: self.layers.clone(),
+            memberships: self.memberships.clone(),
+            couplings: self.couplings.clone(),
+            coupled: self.coupled.clone(),
+        }
+    }
+}
+
+impl<N, E, A, S> Default for MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MultiLayerHyperMap")
+            .field("graph", &self.graph)
+            .field("layers", &self.layers)
+            .field("couplings", &self.couplings)
+            .finish()
+    }
+}
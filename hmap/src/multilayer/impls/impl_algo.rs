@@ -0,0 +1,21 @@
+/*
+    appellation: impl_algo <module>
+    authors: @FL03
+*/
+use crate::multilayer::MultiLayerHyperMap;
+use core::hash::BuildHasher;
+use rshyper_algo::MultiLayerBreadthFirst;
+use rshyper_core::{GraphProps, HyperIndex};
+
+/// implementations for the layer-aware algorithms on the multilayer hypergraph
+impl<N, E, A, S> MultiLayerHyperMap<N, E, A, S>
+where
+    S: BuildHasher + Clone + Default,
+    A: GraphProps,
+    A::Ix: HyperIndex,
+{
+    /// returns the [`MultiLayerBreadthFirst`] operator configured with the current hypergraph
+    pub fn multilayer_bft(&self) -> MultiLayerBreadthFirst<'_, N, E, A, Self, S> {
+        MultiLayerBreadthFirst::new(self)
+    }
+}
@@ -0,0 +1,125 @@
+/*
+    appellation: impl_hyper_graph <module>
+    authors: @FL03
+*/
+use crate::multilayer::MultiLayerHyperMap;
+use core::hash::BuildHasher;
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, LayerId, NodeLayer, VertexId};
+use rshyper_core::traits::{HyperGraph, MultiLayerHyperGraph, RawHyperGraph};
+use rshyper_core::{GraphProps, GraphType, Node, Result, VertexSet, Weight};
+
+impl<N, E, A, S> RawHyperGraph<A> for MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    type Node<_N> = Node<_N, A::Ix>;
+    type Edge<_E> = HashEdge<_E, A::Kind, A::Ix, S>;
+}
+
+/// edges inserted through the [`HyperGraph`] interface are placed within the first layer of
+/// the graph, which is created if the graph has no layers.
+impl<N, E, A, K, Ix, S> HyperGraph<N, E, A> for MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<A::Ix>> {
+        self.add_node(weight)
+    }
+
+    fn add_surface<I>(&mut self, iter: I, weight: Weight<E>) -> Result<EdgeId<A::Ix>>
+    where
+        I: IntoIterator<Item = VertexId<A::Ix>>,
+    {
+        if self.layer_count() == 0 {
+            self.add_layer();
+        }
+        self.add_edge(LayerId::zero(), iter, weight)
+    }
+
+    fn get_edge_domain(&self, index: &EdgeId<A::Ix>) -> Result<&VertexSet<A::Ix, S>> {
+        self.graph.get_domain(index)
+    }
+
+    fn get_edge_domain_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut VertexSet<A::Ix, S>> {
+        self.graph.get_domain_mut(index)
+    }
+
+    fn get_edge(&self, index: &EdgeId<A::Ix>) -> Result<&HashEdge<E, A::Kind, A::Ix, S>> {
+        self.graph.get_edge(index)
+    }
+
+    fn get_edge_mut(
+        &mut self,
+        index: &EdgeId<A::Ix>,
+    ) -> Result<&mut HashEdge<E, A::Kind, A::Ix, S>> {
+        self.graph.get_edge_mut(index)
+    }
+
+    fn get_edge_weight(&self, index: &EdgeId<A::Ix>) -> Result<&Weight<E>> {
+        self.graph.get_edge_weight(index)
+    }
+
+    fn get_edge_weight_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut Weight<E>> {
+        self.graph.get_edge_weight_mut(index)
+    }
+
+    fn get_node(&self, index: &VertexId<A::Ix>) -> Result<&Node<N, A::Ix>> {
+        self.graph.get_node(index)
+    }
+
+    fn get_node_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Node<N, A::Ix>> {
+        self.graph.get_node_mut(index)
+    }
+
+    fn get_node_weight(&self, index: &VertexId<A::Ix>) -> Result<&Weight<N>> {
+        self.graph.get_node_weight(index)
+    }
+
+    fn get_node_weight_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Weight<N>> {
+        self.graph.get_node_weight_mut(index)
+    }
+
+    fn contains_edge(&self, index: &EdgeId<A::Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+
+    fn contains_node(&self, index: &VertexId<A::Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+
+    fn find_edges_with_node(
+        &self,
+        index: &VertexId<A::Ix>,
+    ) -> impl Iterator<Item = &EdgeId<A::Ix>> {
+        self.graph.find_edges_with_node(index)
+    }
+}
+
+impl<N, E, A, K, Ix, S> MultiLayerHyperGraph<N, E, A> for MultiLayerHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    fn layer_count(&self) -> usize {
+        self.layer_count()
+    }
+
+    fn get_edge_layer(&self, index: &EdgeId<A::Ix>) -> Result<LayerId> {
+        self.get_edge_layer(index)
+    }
+
+    fn find_coupled(
+        &self,
+        layer: &LayerId,
+        vertex: &VertexId<A::Ix>,
+    ) -> impl Iterator<Item = NodeLayer<A::Ix>> {
+        self.find_coupled(layer, vertex)
+    }
+}
@@ -0,0 +1,28 @@
+/*
+    appellation: multilayer <module>
+    authors: @FL03
+*/
+//! this module implements the [`MultiLayerHyperMap`], a hypergraph composed of several layers
+//! of edges defined over a shared set of vertices, alongside the interlayer [`Coupling`]s
+//! joining them and the [`LayerView`] used to inspect any particular layer.
+#[doc(inline)]
+pub use self::{coupling::*, graph::*, view::*};
+
+mod coupling;
+mod graph;
+mod view;
+
+mod impls {
+    #[cfg(feature = "algo")]
+    pub mod impl_algo;
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::coupling::*;
+    #[doc(inline)]
+    pub use super::graph::*;
+    #[doc(inline)]
+    pub use super::view::*;
+}
@@ -0,0 +1,159 @@
+/*
+    appellation: view <module>
+    authors: @FL03
+*/
+use super::MultiLayerHyperMap;
+use crate::{EdgeMap, HyperMap};
+use core::hash::BuildHasher;
+use rshyper_core::attrs::GraphProps;
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, EdgeSet, HyperIndex, LayerId, NodeLayer, VertexId, VertexSet};
+use rshyper_core::{Error, GraphType, Result};
+
+/// The [`LayerView`] is a read-only view of a single layer of a [`MultiLayerHyperMap`],
+/// restricting the graph to the (intralayer) edges of the layer while sharing the vertices of
+/// the graph.
+pub struct LayerView<'a, N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the multilayer hypergraph being viewed
+    pub(crate) graph: &'a MultiLayerHyperMap<N, E, A, S>,
+    /// the index of the layer
+    pub(crate) layer: LayerId,
+}
+
+impl<'a, N, E, A, K, Ix, S> LayerView<'a, N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// returns a new view of the given layer of the graph
+    pub const fn new(graph: &'a MultiLayerHyperMap<N, E, A, S>, layer: LayerId) -> Self {
+        Self { graph, layer }
+    }
+    /// returns an immutable reference to the multilayer hypergraph being viewed
+    pub const fn graph(&self) -> &'a MultiLayerHyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// returns the index of the layer
+    pub const fn layer(&self) -> LayerId {
+        self.layer
+    }
+    /// returns the set of edges contained by the layer
+    pub fn edge_set(&self) -> &'a EdgeSet<Ix, S> {
+        &self.graph.layers[*self.layer.get()]
+    }
+    /// returns true if the layer contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.edge_set().contains(index)
+    }
+    /// returns an iterator over the edges of the layer
+    pub fn edges(&self) -> impl Iterator<Item = (&'a EdgeId<Ix>, &'a HashEdge<E, K, Ix, S>)> {
+        let edges = self.graph.aggregate().edges();
+        self.edge_set()
+            .iter()
+            .filter_map(move |id| edges.get_key_value(id))
+    }
+    /// returns an iterator over the indices of the edges within the layer
+    pub fn edge_keys(&self) -> impl Iterator<Item = &'a EdgeId<Ix>> {
+        self.edge_set().iter()
+    }
+    /// returns an iterator over the edges of the layer containing the given vertex
+    pub fn find_edges_with_node(
+        &self,
+        index: &VertexId<Ix>,
+    ) -> impl Iterator<Item = &'a EdgeId<Ix>> {
+        let index = *index;
+        self.edges()
+            .filter(move |(_, edge)| edge.contains(&index))
+            .map(|(id, _)| id)
+    }
+    /// returns the set of vertices sharing an edge of the layer with the given vertex
+    pub fn find_node_neighbors(&self, index: &VertexId<Ix>) -> Result<VertexSet<Ix, S>>
+    where
+        S: Default,
+    {
+        if !self.graph.contains_node(index) {
+            return Err(Error::NodeNotFound);
+        }
+        let mut neighbors = VertexSet::default();
+        self.edges()
+            .filter(|(_, edge)| edge.contains(index))
+            .for_each(|(_, edge)| {
+                neighbors.extend(edge.domain().iter().filter(|&v| v != index).copied())
+            });
+        Ok(neighbors)
+    }
+    /// returns an iterator over the node-layers coupled to the given vertex within the layer
+    pub fn find_coupled(&self, index: &VertexId<Ix>) -> impl Iterator<Item = NodeLayer<Ix>> {
+        self.graph.find_coupled(&self.layer, index)
+    }
+    /// returns the number of edges containing the given vertex within the layer
+    pub fn get_node_degree(&self, index: &VertexId<Ix>) -> usize {
+        self.find_edges_with_node(index).count()
+    }
+    /// returns the number of edges within the layer
+    pub fn size(&self) -> usize {
+        self.edge_set().len()
+    }
+    /// returns true if the layer contains no edges
+    pub fn is_empty(&self) -> bool {
+        self.edge_set().is_empty()
+    }
+    /// materializes the layer as a standalone [`HyperMap`] containing every node of the graph
+    /// alongside the edges of the layer; the indices of the components are preserved.
+    pub fn to_hyper_map(&self) -> HyperMap<N, E, A, S>
+    where
+        E: Clone,
+        N: Clone,
+        K: Clone,
+        S: Clone + Default,
+    {
+        let source = self.graph.aggregate();
+        let hasher = source.edges().hasher().clone();
+        let mut edges = EdgeMap::with_hasher(hasher.clone());
+        edges.extend(self.edges().map(|(id, edge)| (*id, edge.clone())));
+        let mut history = source.history().clone();
+        history.retain_edges(|id| edges.contains_key(id));
+        let mut graph = HyperMap::with_hasher(hasher);
+        graph
+            .set_nodes(source.nodes().clone())
+            .set_edges(edges)
+            .set_history(history)
+            .reindex();
+        graph
+    }
+}
+
+impl<N, E, A, S> Clone for LayerView<'_, N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, E, A, S> Copy for LayerView<'_, N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+}
+
+impl<N, E, A, S> core::fmt::Debug for LayerView<'_, N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LayerView")
+            .field("layer", &self.layer)
+            .finish()
+    }
+}
@@ -0,0 +1,394 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use crate::{EdgeMap, HyperMap};
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::{Edge, HashEdge};
+use rshyper_core::idx::{EdgeId, EdgeSet, Grid, HyperIndex, RawIndex, Udx, VertexId, VertexSet};
+use rshyper_core::{Error, GraphType, Node, Result, Weight};
+
+/// a type alias for a map associating each edge with a set of other edges
+pub type NestingMap<Ix, S = DefaultHashBuilder> = HashMap<EdgeId<Ix>, EdgeSet<Ix, S>, S>;
+/// a type alias for a [directed](rshyper_core::Directed) [`NestedHyperMap`]
+pub type DiNestedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    NestedHyperMap<N, E, DiAttrs<Idx>, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`NestedHyperMap`]
+pub type UnNestedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    NestedHyperMap<N, E, UnAttrs<Idx>, S>;
+
+/// The [`NestedHyperMap`] is a recursive hypergraph, or _ubergraph_, whose edges may contain
+/// other edges alongside their vertices, allowing for relations about relations (i.e.
+/// statements about statements) to be expressed.
+///
+/// ## Overview
+///
+/// The graph wraps a standard [`HyperMap`], whose edges store the vertices they directly
+/// contain, recording the edges nested within each edge (its _children_) alongside the
+/// edges containing it (its _parents_). The members of an edge are described using the
+/// [`Grid`], a unified index covering both vertices and edges. The edges containing no
+/// vertices at all, i.e. those composed solely of other edges, are kept alongside the
+/// underlying graph rather than within it, ensuring that every edge of the [`HyperMap`]
+/// remains non-empty. These edges draw their indices from the same history as the edges of
+/// the underlying graph, meaning that no two edges will ever share an index.
+///
+/// Since an edge may only contain edges that already exist, the nesting of the edges is
+/// acyclic by construction. Removing an element from the graph also removes every edge
+/// containing it, directly or otherwise, mirroring the behaviour of the [`HyperMap`] when
+/// removing a vertex.
+pub struct NestedHyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the underlying hypergraph
+    pub(crate) graph: HyperMap<N, E, A, S>,
+    /// the edges composed solely of other edges
+    pub(crate) composites: EdgeMap<E, A::Kind, A::Ix, S>,
+    /// the edges directly contained by each edge
+    pub(crate) children: NestingMap<A::Ix, S>,
+    /// the edges directly containing each edge
+    pub(crate) parents: NestingMap<A::Ix, S>,
+}
+
+impl<N, E, A, K, Ix, S> NestedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`NestedHyperMap`]
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            graph: HyperMap::new(),
+            composites: EdgeMap::default(),
+            children: NestingMap::default(),
+            parents: NestingMap::default(),
+        }
+    }
+    /// returns an immutable reference to the underlying [`HyperMap`]
+    pub const fn graph(&self) -> &HyperMap<N, E, A, S> {
+        &self.graph
+    }
+    /// returns an immutable reference to the edges composed solely of other edges, which are
+    /// kept outside of the underlying graph
+    pub const fn composites(&self) -> &EdgeMap<E, K, Ix, S> {
+        &self.composites
+    }
+    /// returns an immutable reference to the map of edges nested within each edge
+    pub const fn children(&self) -> &NestingMap<Ix, S> {
+        &self.children
+    }
+    /// returns an immutable reference to the map of edges containing each edge
+    pub const fn parents(&self) -> &NestingMap<Ix, S> {
+        &self.parents
+    }
+    /// consumes the instance to return the underlying [`HyperMap`], discarding the nesting of
+    /// the edges alongside the edges composed solely of other edges
+    pub fn into_graph(self) -> HyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty() && self.composites.is_empty()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.graph.order()
+    }
+    /// returns the number of edges within the graph
+    pub fn size(&self) -> usize {
+        self.graph.size() + self.composites.len()
+    }
+}
+
+impl<N, E, A, K, Ix, S> NestedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// add a new node with the given weight to the graph
+    pub fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        self.graph.add_node(weight)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.graph.add_vertex()
+    }
+    /// add a new edge composed of the given members, which may be either vertices or other
+    /// edges within the graph.
+    ///
+    /// **Note:** an error is returned if any of the members are missing from the graph or if
+    /// the edge would be empty; [raw](Grid::Raw) indices are never considered members of the
+    /// graph.
+    pub fn add_edge<I>(&mut self, members: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = Grid<Ix>>,
+    {
+        let mut verts = VertexSet::<Ix, S>::default();
+        let mut edges = EdgeSet::<Ix, S>::default();
+        for member in members {
+            match member {
+                Grid::Vertex(v) if self.contains_node(&v) => {
+                    verts.insert(v);
+                }
+                Grid::Edge(e) if self.contains_edge(&e) => {
+                    edges.insert(e);
+                }
+                Grid::Edge(_) => return Err(Error::EdgeNotFound),
+                _ => return Err(Error::NodeNotFound),
+            }
+        }
+        if verts.is_empty() && edges.is_empty() {
+            return Err(Error::EmptyHyperedge);
+        }
+        // edges without any vertices are kept outside of the underlying graph
+        let id = if verts.is_empty() {
+            let id = self.graph.history_mut().next_edge()?;
+            self.composites
+                .insert(id, Edge::from_parts(id, verts, weight));
+            id
+        } else {
+            self.graph.add_edge(verts, weight)?
+        };
+        for child in edges.iter() {
+            self.parents.entry(*child).or_default().insert(id);
+        }
+        self.children.insert(id, edges);
+        Ok(id)
+    }
+    /// add a new edge composed of the given members using the logical default for its weight
+    pub fn add_link<I>(&mut self, members: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = Grid<Ix>>,
+        E: Default,
+    {
+        self.add_edge(members, Default::default())
+    }
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.contains_edge(index) || self.composites.contains_key(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, K, Ix, S>> {
+        match self.composites.get(index) {
+            Some(edge) => Ok(edge),
+            None => self.graph.get_edge(index),
+        }
+    }
+    /// returns a mutable reference to the weight of the edge with the given index
+    pub fn get_edge_weight_mut(&mut self, index: &EdgeId<Ix>) -> Result<&mut Weight<E>> {
+        match self.composites.get_mut(index) {
+            Some(edge) => Ok(edge.weight_mut()),
+            None => self.graph.get_edge_weight_mut(index),
+        }
+    }
+    /// returns the set of vertices directly contained by the edge with the given index
+    pub fn get_domain(&self, index: &EdgeId<Ix>) -> Result<&VertexSet<Ix, S>> {
+        self.get_edge(index).map(|edge| edge.domain())
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.graph.get_node(index)
+    }
+    /// returns the set of edges directly contained by the edge with the given index
+    pub fn get_children(&self, index: &EdgeId<Ix>) -> Result<&EdgeSet<Ix, S>> {
+        self.children.get(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns the members, vertices and edges alike, directly contained by the edge with the
+    /// given index
+    pub fn get_members(&self, index: &EdgeId<Ix>) -> Result<Vec<Grid<Ix>>> {
+        let verts = self.get_domain(index)?;
+        let edges = self.get_children(index)?;
+        let members = verts
+            .iter()
+            .cloned()
+            .map(Grid::Vertex)
+            .chain(edges.iter().copied().map(Grid::Edge))
+            .collect();
+        Ok(members)
+    }
+    /// returns an iterator over the edges directly containing the edge with the given index
+    pub fn find_parents(&self, index: &EdgeId<Ix>) -> impl Iterator<Item = &EdgeId<Ix>> {
+        self.parents.get(index).into_iter().flatten()
+    }
+    /// returns true if the edge with the given index contains no other edges
+    pub fn is_flat(&self, index: &EdgeId<Ix>) -> Result<bool> {
+        self.get_children(index).map(|edges| edges.is_empty())
+    }
+    /// returns the nesting depth of the edge with the given index, i.e. the length of the
+    /// longest chain of edges nested beneath it; edges containing only vertices have a depth
+    /// of zero.
+    ///
+    /// The depths are computed in post-order, with each edge being visited once regardless of
+    /// how many of the edges above it share it.
+    pub fn depth(&self, index: &EdgeId<Ix>) -> Result<usize> {
+        let mut depths = HashMap::<EdgeId<Ix>, usize, S>::default();
+        let mut stack = Vec::from([(*index, false)]);
+        while let Some((current, expanded)) = stack.pop() {
+            if depths.contains_key(&current) {
+                continue;
+            }
+            let children = self.get_children(&current)?;
+            if expanded {
+                // every child has been resolved before the edge is revisited
+                let depth = children
+                    .iter()
+                    .filter_map(|child| depths.get(child))
+                    .map(|depth| depth + 1)
+                    .max()
+                    .unwrap_or_default();
+                depths.insert(current, depth);
+            } else {
+                stack.push((current, true));
+                stack.extend(
+                    children
+                        .iter()
+                        .filter(|child| !depths.contains_key(*child))
+                        .map(|child| (*child, false)),
+                );
+            }
+        }
+        Ok(depths[index])
+    }
+    /// returns the set of vertices contained by the edge with the given index, descending
+    /// into each of the edges nested within it.
+    pub fn flatten(&self, index: &EdgeId<Ix>) -> Result<VertexSet<Ix, S>> {
+        let mut verts = VertexSet::<Ix, S>::default();
+        let mut stack = Vec::from([*index]);
+        let mut visited = EdgeSet::<Ix, S>::default();
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current) {
+                continue;
+            }
+            verts.extend(self.get_domain(&current)?.iter().copied());
+            stack.extend(self.get_children(&current)?.iter().copied());
+        }
+        Ok(verts)
+    }
+    /// removes the edge with the given index alongside every edge containing it, directly or
+    /// otherwise, returning the requested edge.
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>> {
+        let edge = self.remove_single(index)?;
+        // remove the edges that contained the removed edge, directly or otherwise
+        let mut stack = self
+            .parents
+            .remove(index)
+            .map(|parents| parents.into_iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        while let Some(parent) = stack.pop() {
+            if !self.contains_edge(&parent) {
+                continue;
+            }
+            self.remove_single(&parent)?;
+            if let Some(parents) = self.parents.remove(&parent) {
+                stack.extend(parents);
+            }
+        }
+        Ok(edge)
+    }
+    /// removes the node with the given index alongside every edge containing it, directly or
+    /// otherwise.
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let edges = self
+            .graph
+            .find_edges_with_node(index)
+            .cloned()
+            .collect::<Vec<_>>();
+        for edge in edges {
+            if self.contains_edge(&edge) {
+                self.remove_edge(&edge)?;
+            }
+        }
+        self.graph.remove_node(index)
+    }
+    /// removes the edge with the given index, detaching it from each of its children while
+    /// leaving the edges containing it in place.
+    fn remove_single(&mut self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>> {
+        let edge = match self.composites.remove(index) {
+            Some(edge) => {
+                self.graph.history_mut().remove_edge(index);
+                edge
+            }
+            None => self.graph.remove_edge(index)?,
+        };
+        self.detach(index);
+        Ok(edge)
+    }
+    /// removes the edge with the given index from the nesting of the graph, detaching it from
+    /// each of its children.
+    fn detach(&mut self, index: &EdgeId<Ix>) {
+        let children = self.children.remove(index).unwrap_or_default();
+        for child in children {
+            if let Some(parents) = self.parents.get_mut(&child) {
+                parents.remove(index);
+                if parents.is_empty() {
+                    self.parents.remove(&child);
+                }
+            }
+        }
+    }
+}
+
+impl<N, E, A, S> Clone for NestedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    A::Ix: Clone,
+    S: BuildHasher + Clone,
+    HyperMap<N, E, A, S>: Clone,
+    EdgeMap<E, A::Kind, A::Ix, S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            composites: self.composites.clone(),
+            children: self.children.clone(),
+            parents: self.parents.clone(),
+        }
+    }
+}
+
+impl<N, E, A, S> Default for NestedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for NestedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NestedHyperMap")
+            .field("graph", &self.graph)
+            .field("composites", &self.composites)
+            .field("children", &self.children)
+            .field("parents", &self.parents)
+            .finish()
+    }
+}
@@ -0,0 +1,21 @@
+/*
+    appellation: impl_algo <module>
+    authors: @FL03
+*/
+use crate::nested::NestedHyperMap;
+use core::hash::BuildHasher;
+use rshyper_algo::NestedBreadthFirst;
+use rshyper_core::{GraphProps, HyperIndex};
+
+/// implementations for the algorithms descending into the edges of the nested hypergraph
+impl<N, E, A, S> NestedHyperMap<N, E, A, S>
+where
+    S: BuildHasher + Clone + Default,
+    A: GraphProps,
+    A::Ix: HyperIndex,
+{
+    /// returns the [`NestedBreadthFirst`] operator configured with the current hypergraph
+    pub fn nested_bft(&self) -> NestedBreadthFirst<'_, N, E, A, Self, S> {
+        NestedBreadthFirst::new(self)
+    }
+}
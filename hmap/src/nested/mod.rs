@@ -0,0 +1,21 @@
+/*
+    appellation: nested <module>
+    authors: @FL03
+*/
+//! this module implements the [`NestedHyperMap`], a recursive hypergraph (ubergraph) whose
+//! edges may contain other edges alongside their vertices.
+#[doc(inline)]
+pub use self::graph::*;
+
+mod graph;
+
+mod impls {
+    #[cfg(feature = "algo")]
+    pub mod impl_algo;
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+}
@@ -0,0 +1,280 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use crate::HyperMap;
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::{Edge, HashEdge};
+use rshyper_core::idx::{EdgeId, EdgeSet, HyperIndex, RawIndex, Udx, VertexId};
+use rshyper_core::{Error, GraphType, Node, Result, Weight};
+
+/// a type alias for the ordered sequence of vertices composing an edge
+pub type Sequence<Ix> = Vec<VertexId<Ix>>;
+/// a type alias for a map associating each edge with its sequence of vertices
+pub type SequenceMap<Ix, S = DefaultHashBuilder> = HashMap<EdgeId<Ix>, Sequence<Ix>, S>;
+/// a type alias for a map associating each vertex with the edges whose sequence either starts
+/// or ends with it
+pub type EndpointMap<Ix, S = DefaultHashBuilder> = HashMap<VertexId<Ix>, EdgeSet<Ix, S>, S>;
+/// a type alias for an [`Edge`] whose _vertices_ are stored in their original order
+pub type OrderedEdge<E, K, Ix> = Edge<E, Sequence<Ix>, K, Ix>;
+/// a type alias for an edge paired with its sequence of vertices
+pub type SequencedEdge<E, K, Ix, S = DefaultHashBuilder> = (HashEdge<E, K, Ix, S>, Sequence<Ix>);
+/// a type alias for a [directed](rshyper_core::Directed) [`OrderedHyperMap`]
+pub type DiOrderedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    OrderedHyperMap<N, E, DiAttrs<Idx>, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`OrderedHyperMap`]
+pub type UnOrderedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    OrderedHyperMap<N, E, UnAttrs<Idx>, S>;
+
+/// The [`OrderedHyperMap`] is a hypergraph whose edges are ordered sequences of vertices,
+/// preserving both the order in which the vertices were given and any repetitions among them.
+///
+/// ## Overview
+///
+/// The graph wraps a standard [`HyperMap`], whose edges continue to store the (unordered) set
+/// of their vertices, recording the [`Sequence`] of each edge alongside it. This allows the
+/// graph to support each of the existing operators while enabling algorithms to treat the
+/// edges as n-ary relations (tuples) or as paths through their vertices, using the
+/// [`OrderedHyperGraph`](rshyper_core::OrderedHyperGraph) trait.
+pub struct OrderedHyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the underlying hypergraph
+    pub(crate) graph: HyperMap<N, E, A, S>,
+    /// the sequence of vertices composing each edge
+    pub(crate) sequences: SequenceMap<A::Ix, S>,
+    /// the edges whose sequence starts with each vertex
+    pub(crate) heads: EndpointMap<A::Ix, S>,
+    /// the edges whose sequence ends with each vertex
+    pub(crate) tails: EndpointMap<A::Ix, S>,
+}
+
+impl<N, E, A, K, Ix, S> OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`OrderedHyperMap`]
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            graph: HyperMap::new(),
+            sequences: SequenceMap::default(),
+            heads: EndpointMap::default(),
+            tails: EndpointMap::default(),
+        }
+    }
+    /// returns an immutable reference to the underlying [`HyperMap`]
+    pub const fn graph(&self) -> &HyperMap<N, E, A, S> {
+        &self.graph
+    }
+    /// returns an immutable reference to the sequences of the edges
+    pub const fn sequences(&self) -> &SequenceMap<Ix, S> {
+        &self.sequences
+    }
+    /// consumes the instance to return the underlying [`HyperMap`], discarding the order of
+    /// the edges
+    pub fn into_graph(self) -> HyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.graph.order()
+    }
+    /// returns the number of edges within the graph
+    pub fn size(&self) -> usize {
+        self.graph.size()
+    }
+}
+
+impl<N, E, A, K, Ix, S> OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// add a new node with the given weight to the graph
+    pub fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        self.graph.add_node(weight)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.graph.add_vertex()
+    }
+    /// add a new edge composed of the given sequence of vertices; the order of the vertices,
+    /// alongside any repetitions, is preserved.
+    pub fn add_edge<I>(&mut self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let seq = vertices.into_iter().collect::<Sequence<Ix>>();
+        let id = self.graph.add_edge(seq.iter().copied(), weight)?;
+        if let (Some(&head), Some(&tail)) = (seq.first(), seq.last()) {
+            self.heads.entry(head).or_default().insert(id);
+            self.tails.entry(tail).or_default().insert(id);
+        }
+        self.sequences.insert(id, seq);
+        Ok(id)
+    }
+    /// add a new edge composed of the given sequence of vertices using the logical default
+    /// for its weight
+    pub fn add_link<I>(&mut self, vertices: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        E: Default,
+    {
+        self.add_edge(vertices, Default::default())
+    }
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, K, Ix, S>> {
+        self.graph.get_edge(index)
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.graph.get_node(index)
+    }
+    /// returns the sequence of vertices composing the edge with the given index
+    pub fn get_sequence(&self, index: &EdgeId<Ix>) -> Result<&[VertexId<Ix>]> {
+        self.sequences
+            .get(index)
+            .map(Vec::as_slice)
+            .ok_or(Error::EdgeNotFound)
+    }
+    /// returns the vertex at the given position within the edge with the given index
+    pub fn get_member(&self, index: &EdgeId<Ix>, position: usize) -> Result<&VertexId<Ix>> {
+        self.get_sequence(index)?
+            .get(position)
+            .ok_or(Error::NodeNotFound)
+    }
+    /// returns the first position of the given vertex within the edge with the given index
+    pub fn get_position(&self, index: &EdgeId<Ix>, vertex: &VertexId<Ix>) -> Result<usize> {
+        self.get_sequence(index)?
+            .iter()
+            .position(|v| v == vertex)
+            .ok_or(Error::NodeNotFound)
+    }
+    /// returns an [`OrderedEdge`] whose domain is the sequence of the edge with the given index
+    pub fn get_ordered_edge(&self, index: &EdgeId<Ix>) -> Result<OrderedEdge<E, K, Ix>>
+    where
+        E: Clone,
+    {
+        let edge = self.get_edge(index)?;
+        let seq = self.get_sequence(index)?.to_vec();
+        Ok(Edge::from_parts(*index, seq, edge.weight().clone()))
+    }
+    /// returns an iterator over the edges whose sequence starts with the given vertex
+    pub fn find_edges_from(&self, index: &VertexId<Ix>) -> impl Iterator<Item = &EdgeId<Ix>> {
+        self.heads.get(index).into_iter().flatten()
+    }
+    /// returns an iterator over the edges whose sequence ends with the given vertex
+    pub fn find_edges_to(&self, index: &VertexId<Ix>) -> impl Iterator<Item = &EdgeId<Ix>> {
+        self.tails.get(index).into_iter().flatten()
+    }
+    /// removes the edge with the given index, returning it alongside its sequence
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<SequencedEdge<E, K, Ix, S>> {
+        let edge = self.graph.remove_edge(index)?;
+        let seq = self.sequences.remove(index).ok_or(Error::EdgeNotFound)?;
+        self.unindex_endpoints(index, &seq);
+        Ok((edge, seq))
+    }
+    /// removes the node with the given index alongside each of the edges containing it
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let node = self.graph.remove_node(index)?;
+        let removed = self
+            .sequences
+            .keys()
+            .filter(|&id| !self.graph.contains_edge(id))
+            .copied()
+            .collect::<Vec<_>>();
+        for id in removed {
+            if let Some(seq) = self.sequences.remove(&id) {
+                self.unindex_endpoints(&id, &seq);
+            }
+        }
+        Ok(node)
+    }
+    /// removes the edge with the given index from the head and tail of its sequence
+    fn unindex_endpoints(&mut self, index: &EdgeId<Ix>, seq: &[VertexId<Ix>]) {
+        for (endpoints, vertex) in [
+            (&mut self.heads, seq.first()),
+            (&mut self.tails, seq.last()),
+        ] {
+            let Some(vertex) = vertex else { continue };
+            if let Some(edges) = endpoints.get_mut(vertex) {
+                edges.remove(index);
+                if edges.is_empty() {
+                    endpoints.remove(vertex);
+                }
+            }
+        }
+    }
+}
+
+impl<N, E, A, S> Clone for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    A::Ix: Clone,
+    S: BuildHasher + Clone,
+    HyperMap<N, E, A, S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            sequences: self.sequences.clone(),
+            heads: self.heads.clone(),
+            tails: self.tails.clone(),
+        }
+    }
+}
+
+impl<N, E, A, S> Default for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OrderedHyperMap")
+            .field("graph", &self.graph)
+            .field("sequences", &self.sequences)
+            .finish()
+    }
+}
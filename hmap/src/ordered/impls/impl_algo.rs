@@ -0,0 +1,21 @@
+/*
+    appellation: impl_algo <module>
+    authors: @FL03
+*/
+use crate::ordered::OrderedHyperMap;
+use core::hash::BuildHasher;
+use rshyper_algo::OrderedBreadthFirst;
+use rshyper_core::{GraphProps, HyperIndex};
+
+/// implementations for the algorithms following the sequences of the ordered hypergraph
+impl<N, E, A, S> OrderedHyperMap<N, E, A, S>
+where
+    S: BuildHasher + Clone + Default,
+    A: GraphProps,
+    A::Ix: HyperIndex,
+{
+    /// returns the [`OrderedBreadthFirst`] operator configured with the current hypergraph
+    pub fn ordered_bft(&self) -> OrderedBreadthFirst<'_, N, E, A, Self, S> {
+        OrderedBreadthFirst::new(self)
+    }
+}
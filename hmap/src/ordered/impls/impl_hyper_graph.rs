@@ -0,0 +1,175 @@
+/*
+    appellation: impl_hyper_graph <module>
+    authors: @FL03
+*/
+use crate::iter;
+use crate::ordered::OrderedHyperMap;
+use core::hash::{BuildHasher, Hash};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper_core::traits::{
+    HyperGraph, HyperGraphIterEdge, HyperGraphIterNode, OrderedHyperGraph, RawHyperGraph,
+};
+use rshyper_core::{Error, GraphProps, GraphType, Node, Result, VertexSet, Weight};
+
+impl<N, E, A, S> RawHyperGraph<A> for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    type Node<_N> = Node<_N, A::Ix>;
+    type Edge<_E> = HashEdge<_E, A::Kind, A::Ix, S>;
+}
+
+/// edges inserted through the [`HyperGraph`] interface preserve the order in which their
+/// vertices are yielded by the iterator. Since the domain of each edge is derived from its
+/// sequence, the domains may not be borrowed mutably, with
+/// [`get_edge_domain_mut`](HyperGraph::get_edge_domain_mut) and
+/// [`get_edge_mut`](HyperGraph::get_edge_mut) returning an
+/// [`ImmutableDomain`](rshyper_core::Error::ImmutableDomain) error instead.
+impl<N, E, A, K, Ix, S> HyperGraph<N, E, A> for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<A::Ix>> {
+        self.add_node(weight)
+    }
+
+    fn add_surface<I>(&mut self, iter: I, weight: Weight<E>) -> Result<EdgeId<A::Ix>>
+    where
+        I: IntoIterator<Item = VertexId<A::Ix>>,
+    {
+        self.add_edge(iter, weight)
+    }
+
+    fn get_edge_domain(&self, index: &EdgeId<A::Ix>) -> Result<&VertexSet<A::Ix, S>> {
+        self.graph.get_domain(index)
+    }
+
+    fn get_edge_domain_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut VertexSet<A::Ix, S>> {
+        self.graph.get_edge(index)?;
+        Err(Error::ImmutableDomain)
+    }
+
+    fn get_edge(&self, index: &EdgeId<A::Ix>) -> Result<&HashEdge<E, A::Kind, A::Ix, S>> {
+        self.graph.get_edge(index)
+    }
+
+    fn get_edge_mut(
+        &mut self,
+        index: &EdgeId<A::Ix>,
+    ) -> Result<&mut HashEdge<E, A::Kind, A::Ix, S>> {
+        self.graph.get_edge(index)?;
+        Err(Error::ImmutableDomain)
+    }
+
+    fn get_edge_weight(&self, index: &EdgeId<A::Ix>) -> Result<&Weight<E>> {
+        self.graph.get_edge_weight(index)
+    }
+
+    fn get_edge_weight_mut(&mut self, index: &EdgeId<A::Ix>) -> Result<&mut Weight<E>> {
+        self.graph.get_edge_weight_mut(index)
+    }
+
+    fn get_node(&self, index: &VertexId<A::Ix>) -> Result<&Node<N, A::Ix>> {
+        self.graph.get_node(index)
+    }
+
+    fn get_node_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Node<N, A::Ix>> {
+        self.graph.get_node_mut(index)
+    }
+
+    fn get_node_weight(&self, index: &VertexId<A::Ix>) -> Result<&Weight<N>> {
+        self.graph.get_node_weight(index)
+    }
+
+    fn get_node_weight_mut(&mut self, index: &VertexId<A::Ix>) -> Result<&mut Weight<N>> {
+        self.graph.get_node_weight_mut(index)
+    }
+
+    fn contains_edge(&self, index: &EdgeId<A::Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+
+    fn contains_node(&self, index: &VertexId<A::Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+
+    fn find_edges_with_node(
+        &self,
+        index: &VertexId<A::Ix>,
+    ) -> impl Iterator<Item = &EdgeId<A::Ix>> {
+        self.graph.find_edges_with_node(index)
+    }
+}
+
+impl<N, E, A, K, Ix, S> OrderedHyperGraph<N, E, A> for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Kind = K, Ix = Ix>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    fn get_edge_sequence(&self, index: &EdgeId<A::Ix>) -> Result<&[VertexId<A::Ix>]> {
+        self.get_sequence(index)
+    }
+}
+
+impl<N, E, A, S> HyperGraphIterNode<N, E, A> for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+{
+    type Nodes<'a>
+        = iter::NodeIter<'a, N, A::Ix>
+    where
+        Self: 'a,
+        Self::Node<N>: 'a;
+    type Verts<'a>
+        = iter::NodeKeys<'a, N, A::Ix>
+    where
+        Self: 'a;
+
+    fn iter_nodes(&self) -> Self::Nodes<'_> {
+        self.graph.iter_nodes()
+    }
+
+    fn vertices(&self) -> Self::Verts<'_> {
+        self.graph.vertices()
+    }
+}
+
+impl<N, E, A, S> HyperGraphIterEdge<N, E, A> for OrderedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    E: Eq + Hash,
+    N: Eq + Hash,
+    A::Ix: HyperIndex,
+{
+    type Surfaces<'a>
+        = iter::EdgeIter<'a, E, A::Kind, A::Ix, S>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    type Edges<'a>
+        = iter::EdgeKeys<'a, E, A::Kind, A::Ix, S>
+    where
+        Self: 'a,
+        Self::Edge<E>: 'a;
+
+    fn iter_surfaces(&self) -> Self::Surfaces<'_> {
+        self.graph.iter_edges()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        self.graph.iter_edge_keys()
+    }
+}
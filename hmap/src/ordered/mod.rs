@@ -0,0 +1,21 @@
+/*
+    appellation: ordered <module>
+    authors: @FL03
+*/
+//! this module implements the [`OrderedHyperMap`], a hypergraph whose edges preserve the
+//! order and multiplicity of their vertices.
+#[doc(inline)]
+pub use self::graph::*;
+
+mod graph;
+
+mod impls {
+    #[cfg(feature = "algo")]
+    pub mod impl_algo;
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+}
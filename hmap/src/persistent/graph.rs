@@ -0,0 +1,454 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use super::HamtMap;
+use crate::HyperMap;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::DefaultHashBuilder;
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::{Edge, HashEdge};
+use rshyper_core::idx::{EdgeId, HashIndex, IndexFrame, IndexTracker, RawIndex, Udx, VertexId};
+use rshyper_core::{AddStep, Error, GraphType, Mode, Node, Result, VertexSet, Weight};
+
+/// a type alias for the persistent map storing the nodes of a [`PersistentHyperMap`]
+pub type PersistentNodeMap<N, Ix, S> = HamtMap<VertexId<Ix>, Node<N, Ix>, S>;
+/// a type alias for the persistent map storing the edges of a [`PersistentHyperMap`]
+pub type PersistentEdgeMap<E, K, Ix, S> = HamtMap<EdgeId<Ix>, HashEdge<E, K, Ix, S>, S>;
+
+/// a type alias for a [directed](rshyper_core::Directed) [`PersistentHyperMap`]
+pub type DiPersistentHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    PersistentHyperMap<N, E, DiAttrs<Idx>, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`PersistentHyperMap`]
+pub type UnPersistentHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    PersistentHyperMap<N, E, UnAttrs<Idx>, S>;
+
+/// The [`PersistentHyperMap`] is an immutable, _persistent_ hypergraph whose nodes and edges
+/// are stored within structurally shared [`HamtMap`]s.
+///
+/// ## Overview
+///
+/// Cloning the graph is `O(1)`, making it cheap to retain any number of historical versions.
+/// Each modification copies only the path of the trie leading to the affected entry, meaning
+/// an edge's domain is only ever copied when that particular edge is modified. The
+/// `with_*`/`without_*` methods return a new version of the graph while leaving the current
+/// one untouched, while the `&mut self` methods (and the [`HyperGraph`](rshyper_core::HyperGraph)
+/// implementation) update the current version in-place using copy-on-write semantics.
+///
+/// Unlike the [`HyperMap`], the graph only tracks the [`IndexFrame`] rather than a complete
+/// [`IndexTracker`] since a growing history cannot be shared between versions; because
+/// indices are allocated monotonically, the creation order of the entries is given by the
+/// order of their indices.
+pub struct PersistentHyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the hyperedges of the hypergraph, each identified by an `EdgeId`
+    pub(crate) edges: PersistentEdgeMap<E, A::Kind, A::Ix, S>,
+    /// the nodes of the hypergraph, each identified by a `VertexId`
+    pub(crate) nodes: PersistentNodeMap<N, A::Ix, S>,
+    /// the attributes of a graph define its _kind_ and the type of index used
+    pub(crate) attrs: A,
+    /// the next indices to be used when creating new edges and vertices
+    pub(crate) position: IndexFrame<A::Ix>,
+}
+
+impl<N, E, A, K, Ix, S> PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`PersistentHyperMap`]
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            edges: HamtMap::new(),
+            nodes: HamtMap::new(),
+            attrs: A::new(),
+            position: IndexFrame::default(),
+        }
+    }
+    /// initialize a new, empty instance configured with the given [`BuildHasher`]
+    pub fn with_hasher(hash_builder: S) -> Self
+    where
+        Ix: Default,
+        S: Clone,
+    {
+        Self {
+            edges: HamtMap::with_hasher(hash_builder.clone()),
+            nodes: HamtMap::with_hasher(hash_builder),
+            attrs: A::new(),
+            position: IndexFrame::default(),
+        }
+    }
+    /// returns the [`Mode`] of the hypergraph
+    pub fn mode(&self) -> Mode {
+        self.attrs.mode()
+    }
+    /// returns an immutable reference to the nodes of the hypergraph
+    pub const fn nodes(&self) -> &PersistentNodeMap<N, Ix, S> {
+        &self.nodes
+    }
+    /// returns an immutable reference to the edges of the hypergraph
+    pub const fn edges(&self) -> &PersistentEdgeMap<E, K, Ix, S> {
+        &self.edges
+    }
+    /// returns an immutable reference to the current position of the hypergraph
+    pub const fn position(&self) -> &IndexFrame<Ix> {
+        &self.position
+    }
+    /// returns true if the hypergraph is directed
+    pub fn is_directed(&self) -> bool {
+        self.attrs.is_directed()
+    }
+    /// returns true if the hypergraph is undirected
+    pub fn is_undirected(&self) -> bool {
+        self.attrs.is_undirected()
+    }
+    /// returns true if the hypergraph has no nodes nor any edges
+    pub fn is_empty(&self) -> bool {
+        self.nodes().is_empty() && self.edges().is_empty()
+    }
+    /// returns the number of nodes within the hypergraph
+    pub fn order(&self) -> usize {
+        self.nodes().len()
+    }
+    /// returns the number of edges within the hypergraph
+    pub fn size(&self) -> usize {
+        self.edges().len()
+    }
+    /// returns true if both instances are the same version of the hypergraph, meaning they
+    /// share both their nodes and edges.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.nodes().ptr_eq(other.nodes()) && self.edges().ptr_eq(other.edges())
+    }
+    /// returns an `O(1)` snapshot of the current version of the hypergraph
+    pub fn snapshot(&self) -> Self
+    where
+        S: Clone,
+        Ix: Clone,
+    {
+        self.clone()
+    }
+}
+
+impl<N, E, A, K, Ix, S> PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: HashIndex,
+{
+    /// returns true if the hypergraph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.edges().contains_key(index)
+    }
+    /// returns true if the hypergraph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.nodes().contains_key(index)
+    }
+    /// returns an immutable reference to the edge associated with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, K, Ix, S>> {
+        self.edges().get(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns the set of vertices composing the given edge
+    pub fn get_domain(&self, index: &EdgeId<Ix>) -> Result<&VertexSet<Ix, S>> {
+        self.get_edge(index).map(|edge| edge.domain())
+    }
+    /// returns an immutable reference to the weight of the given edge
+    pub fn get_edge_weight(&self, index: &EdgeId<Ix>) -> Result<&Weight<E>> {
+        self.get_edge(index).map(|edge| edge.weight())
+    }
+    /// returns an immutable reference to the node associated with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.nodes().get(index).ok_or(Error::NodeNotFound)
+    }
+    /// returns an immutable reference to the weight of the given node
+    pub fn get_node_weight(&self, index: &VertexId<Ix>) -> Result<&Weight<N>> {
+        self.get_node(index).map(|node| node.weight())
+    }
+    /// returns the number of edges containing the given vertex
+    pub fn get_node_degree(&self, index: &VertexId<Ix>) -> usize {
+        self.find_edges_with_node(index).count()
+    }
+    /// returns an interator over all the edges within the graph that contains the node
+    /// associated with the given index.
+    pub fn find_edges_with_node<Q>(&self, index: &Q) -> impl Iterator<Item = &EdgeId<Ix>>
+    where
+        Q: ?Sized + PartialEq,
+        VertexId<Ix>: core::borrow::Borrow<Q>,
+    {
+        self.edges()
+            .iter()
+            .filter_map(move |(id, edge)| edge.contains(index).then_some(id))
+    }
+    /// returns an iterator over the nodes of the hypergraph
+    pub fn iter_nodes(&self) -> super::Iter<'_, VertexId<Ix>, Node<N, Ix>> {
+        self.nodes().iter()
+    }
+    /// returns an iterator over the edges of the hypergraph
+    pub fn iter_edges(&self) -> super::Iter<'_, EdgeId<Ix>, HashEdge<E, K, Ix, S>> {
+        self.edges().iter()
+    }
+    /// returns an iterator over the indices of the nodes within the hypergraph
+    pub fn vertices(&self) -> super::Keys<'_, VertexId<Ix>, Node<N, Ix>> {
+        self.nodes().keys()
+    }
+    /// returns an iterator over the indices of the edges within the hypergraph
+    pub fn edge_keys(&self) -> super::Keys<'_, EdgeId<Ix>, HashEdge<E, K, Ix, S>> {
+        self.edges().keys()
+    }
+}
+
+/// the methods in this block update the current version in-place using copy-on-write
+/// semantics; any version previously cloned from this instance is left untouched.
+impl<N, E, A, K, Ix, S> PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType + Clone,
+    Ix: HashIndex + AddStep<Output = Ix> + Copy,
+    N: Clone,
+    E: Clone,
+{
+    /// add a new node with the given weight and return its index
+    pub fn add_node(&mut self, Weight(weight): Weight<N>) -> Result<VertexId<Ix>> {
+        let id = self.position.next_node()?;
+        self.nodes.insert(id, Node::new(id, weight));
+        Ok(id)
+    }
+    /// add a new node using the logical default for its weight and return its index
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.add_node(Default::default())
+    }
+    /// add a new hyperedge composed of the given vertices and weight, returning its index;
+    /// an error is returned if any of the vertices does not exist or if the domain is empty.
+    pub fn add_edge<I>(&mut self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let domain = vertices
+            .into_iter()
+            .map(|v| {
+                if self.contains_node(&v) {
+                    Ok(v)
+                } else {
+                    Err(Error::NodeNotFound)
+                }
+            })
+            .collect::<Result<VertexSet<Ix, S>>>()?;
+        if domain.is_empty() {
+            return Err(Error::EmptyHyperedge);
+        }
+        let id = self.position.next_edge()?;
+        self.edges.insert(id, Edge::from_parts(id, domain, weight));
+        Ok(id)
+    }
+    /// returns a mutable reference to the given edge, copying it if it is shared with
+    /// another version of the hypergraph
+    pub fn get_edge_mut(&mut self, index: &EdgeId<Ix>) -> Result<&mut HashEdge<E, K, Ix, S>> {
+        self.edges.get_mut(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns a mutable reference to the domain of the given edge
+    pub fn get_domain_mut(&mut self, index: &EdgeId<Ix>) -> Result<&mut VertexSet<Ix, S>> {
+        self.get_edge_mut(index).map(|edge| edge.domain_mut())
+    }
+    /// returns a mutable reference to the weight of the given edge
+    pub fn get_edge_weight_mut(&mut self, index: &EdgeId<Ix>) -> Result<&mut Weight<E>> {
+        self.get_edge_mut(index).map(|edge| edge.weight_mut())
+    }
+    /// returns a mutable reference to the given node, copying it if it is shared with another
+    /// version of the hypergraph
+    pub fn get_node_mut(&mut self, index: &VertexId<Ix>) -> Result<&mut Node<N, Ix>> {
+        self.nodes.get_mut(index).ok_or(Error::NodeNotFound)
+    }
+    /// returns a mutable reference to the weight of the given node
+    pub fn get_node_weight_mut(&mut self, index: &VertexId<Ix>) -> Result<&mut Weight<N>> {
+        self.get_node_mut(index).map(|node| node.weight_mut())
+    }
+    /// remove the edge with the given index from the current version of the hypergraph
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>> {
+        self.edges.remove(index).ok_or(Error::EdgeNotFound)
+    }
+    /// remove the given node, and every edge containing it, from the current version of the
+    /// hypergraph
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let node = self.nodes.remove(index).ok_or(Error::NodeNotFound)?;
+        self.edges.retain(|_, edge| !edge.contains(index));
+        Ok(node)
+    }
+    /// update the weight of the given edge
+    pub fn set_edge_weight(&mut self, index: &EdgeId<Ix>, weight: Weight<E>) -> Result<&mut Self> {
+        self.get_edge_mut(index)?.set_weight(weight);
+        Ok(self)
+    }
+    /// update the weight of the given node
+    pub fn set_node_weight(
+        &mut self,
+        index: &VertexId<Ix>,
+        Weight(weight): Weight<N>,
+    ) -> Result<&mut Self> {
+        self.get_node_mut(index)?.set_weight(weight);
+        Ok(self)
+    }
+    /// returns a new version of the hypergraph containing an additional node with the given
+    /// weight alongside its index.
+    pub fn with_node(&self, weight: Weight<N>) -> Result<(Self, VertexId<Ix>)> {
+        let mut next = self.clone();
+        let id = next.add_node(weight)?;
+        Ok((next, id))
+    }
+    /// returns a new version of the hypergraph containing an additional edge composed of the
+    /// given vertices and weight alongside its index.
+    pub fn with_edge<I>(&self, vertices: I, weight: Weight<E>) -> Result<(Self, EdgeId<Ix>)>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let mut next = self.clone();
+        let id = next.add_edge(vertices, weight)?;
+        Ok((next, id))
+    }
+    /// returns a new version of the hypergraph where the given edge has been updated with the
+    /// given weight
+    pub fn with_edge_weight(&self, index: &EdgeId<Ix>, weight: Weight<E>) -> Result<Self> {
+        let mut next = self.clone();
+        next.set_edge_weight(index, weight)?;
+        Ok(next)
+    }
+    /// returns a new version of the hypergraph where the given node has been updated with the
+    /// given weight
+    pub fn with_node_weight(&self, index: &VertexId<Ix>, weight: Weight<N>) -> Result<Self> {
+        let mut next = self.clone();
+        next.set_node_weight(index, weight)?;
+        Ok(next)
+    }
+    /// returns a new version of the hypergraph without the given edge
+    pub fn without_edge(&self, index: &EdgeId<Ix>) -> Result<Self> {
+        let mut next = self.clone();
+        next.remove_edge(index)?;
+        Ok(next)
+    }
+    /// returns a new version of the hypergraph without the given node, or any of the edges
+    /// containing it
+    pub fn without_node(&self, index: &VertexId<Ix>) -> Result<Self> {
+        let mut next = self.clone();
+        next.remove_node(index)?;
+        Ok(next)
+    }
+    /// converts the current version into a [`HyperMap`]; the history of the resulting graph
+    /// is reconstructed from the ordering of the indices.
+    pub fn to_hyper_map(&self) -> HyperMap<N, E, A, S>
+    where
+        Ix: Default + Ord,
+    {
+        let mut graph = HyperMap::with_capacity_and_hasher(self.size(), self.order(), S::default());
+        let mut nodes = self.vertices().copied().collect::<alloc::vec::Vec<_>>();
+        let mut edges = self.edge_keys().copied().collect::<alloc::vec::Vec<_>>();
+        nodes.sort();
+        edges.sort();
+        graph
+            .nodes_mut()
+            .extend(self.iter_nodes().map(|(id, node)| (*id, node.clone())));
+        graph
+            .edges_mut()
+            .extend(self.iter_edges().map(|(id, edge)| (*id, edge.clone())));
+        graph
+            .set_history(
+                IndexTracker::from_cursor(*self.position())
+                    .with_nodes(nodes)
+                    .with_edges(edges),
+            )
+            .reindex();
+        graph
+    }
+}
+
+impl<N, E, A, S> Clone for PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone,
+    A::Ix: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            edges: self.edges.clone(),
+            nodes: self.nodes.clone(),
+            attrs: self.attrs,
+            position: self.position.clone(),
+        }
+    }
+}
+
+impl<N, E, A, S> Default for PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PersistentHyperMap")
+            .field("position", self.position())
+            .field("nodes", self.nodes())
+            .field("edges", self.edges())
+            .finish()
+    }
+}
+
+impl<N, E, A, S> From<&HyperMap<N, E, A, S>> for PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    N: Clone,
+    E: Clone,
+    S: BuildHasher + Clone,
+    A::Ix: HashIndex + Copy,
+    A::Kind: Clone,
+{
+    fn from(graph: &HyperMap<N, E, A, S>) -> Self {
+        let hasher = graph.nodes().hasher().clone();
+        let mut nodes = HamtMap::with_hasher(hasher.clone());
+        let mut edges = HamtMap::with_hasher(hasher);
+        nodes.extend(graph.nodes().iter().map(|(k, v)| (*k, v.clone())));
+        edges.extend(graph.edges().iter().map(|(k, v)| (*k, v.clone())));
+        Self {
+            edges,
+            nodes,
+            attrs: graph.attrs(),
+            position: *graph.position(),
+        }
+    }
+}
+
+impl<N, E, A, S> PartialEq for PersistentHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    N: PartialEq,
+    E: PartialEq,
+    S: BuildHasher,
+    A::Ix: Eq + Hash,
+    A::Kind: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.nodes == other.nodes && self.edges == other.edges
+    }
+}
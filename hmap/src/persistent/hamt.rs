@@ -0,0 +1,608 @@
+/*
+    appellation: hamt <module>
+    authors: @FL03
+*/
+//! this module implements the [`HamtMap`], a persistent hash array mapped trie used as the
+//! backing store of the [`PersistentHyperMap`](super::PersistentHyperMap).
+//!
+//! Every node of the trie is reference counted, so cloning a map is `O(1)` and each
+//! modification only copies the nodes along the path leading to the affected entry; all
+//! other nodes (and values) are shared between the old and the new version.
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::DefaultHashBuilder;
+
+/// the number of hash bits consumed at each level of the trie
+const BITS: u32 = 5;
+/// the mask used to extract the bits of a hash for a given level
+const MASK: u64 = (1 << BITS) - 1;
+/// the maximum shift before the hash bits are exhausted
+const MAX_SHIFT: u32 = u64::BITS;
+
+/// The [`HamtMap`] is a persistent (immutable, structurally shared) hash map implemented as a
+/// hash array mapped trie. Cloning the map is `O(1)` and every mutation performs a
+/// _path copy_, leaving any other version of the map untouched.
+pub struct HamtMap<K, V, S = DefaultHashBuilder> {
+    pub(crate) root: Arc<Branch<K, V>>,
+    pub(crate) len: usize,
+    pub(crate) hasher: S,
+}
+
+/// a branch of the trie; the `bitmap` records which of the 32 possible slots are occupied
+/// while `slots` stores the occupied slots in order.
+pub(crate) struct Branch<K, V> {
+    pub(crate) bitmap: u32,
+    pub(crate) slots: Vec<Slot<K, V>>,
+}
+
+/// a [`Slot`] is either a single entry, a nested branch, or a bucket of entries whose hashes
+/// fully collide.
+pub(crate) enum Slot<K, V> {
+    Leaf {
+        hash: u64,
+        key: K,
+        value: Arc<V>,
+    },
+    Node(Arc<Branch<K, V>>),
+    Collision {
+        hash: u64,
+        entries: Vec<(K, Arc<V>)>,
+    },
+}
+
+/// [`Iter`] is an iterator over the entries of a [`HamtMap`], yielding a 2-tuple consisting
+/// of references to the key and the value of each entry.
+pub struct Iter<'a, K, V> {
+    pub(crate) stack: Vec<core::slice::Iter<'a, Slot<K, V>>>,
+    pub(crate) bucket: Option<core::slice::Iter<'a, (K, Arc<V>)>>,
+    pub(crate) remaining: usize,
+}
+/// [`Keys`] is an iterator over the keys of a [`HamtMap`]
+pub struct Keys<'a, K, V> {
+    pub(crate) iter: Iter<'a, K, V>,
+}
+/// [`Values`] is an iterator over the values of a [`HamtMap`]
+pub struct Values<'a, K, V> {
+    pub(crate) iter: Iter<'a, K, V>,
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl<K, V, S> HamtMap<K, V, S> {
+    /// returns a new, empty map using the default hasher
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self::with_hasher(Default::default())
+    }
+    /// returns a new, empty map configured to use the given hasher
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            root: Arc::new(Branch::new()),
+            len: 0,
+            hasher,
+        }
+    }
+    /// returns a reference to the hasher used by the map
+    pub const fn hasher(&self) -> &S {
+        &self.hasher
+    }
+    /// returns the number of entries within the map
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// returns true if the map contains no entries
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// returns true if both maps share the same root, meaning they are the same version
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.root, &other.root)
+    }
+    /// removes every entry from the map; other versions sharing the trie are unaffected
+    pub fn clear(&mut self) {
+        self.root = Arc::new(Branch::new());
+        self.len = 0;
+    }
+    /// returns an iterator over the entries of the map
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            stack: alloc::vec![self.root.slots.iter()],
+            bucket: None,
+            remaining: self.len,
+        }
+    }
+    /// returns an iterator over the keys of the map
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+    /// returns an iterator over the values of the map
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<K, V, S> HamtMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// returns true if the map contains an entry for the given key
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.get(key).is_some()
+    }
+    /// returns a reference to the value associated with the given key, if any
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let hash = self.hasher.hash_one(key);
+        let mut branch = &*self.root;
+        let mut shift = 0;
+        loop {
+            let bit = bitpos(hash, shift);
+            if branch.bitmap & bit == 0 {
+                return None;
+            }
+            match &branch.slots[branch.index(bit)] {
+                Slot::Leaf {
+                    hash: h,
+                    key: k,
+                    value,
+                } => {
+                    return (*h == hash && k.borrow() == key).then_some(&**value);
+                }
+                Slot::Node(child) => {
+                    branch = child;
+                    shift += BITS;
+                }
+                Slot::Collision { hash: h, entries } => {
+                    if *h != hash {
+                        return None;
+                    }
+                    return entries
+                        .iter()
+                        .find(|(k, _)| k.borrow() == key)
+                        .map(|(_, v)| &**v);
+                }
+            }
+        }
+    }
+    /// returns a mutable reference to the value associated with the given key; any node (or
+    /// value) shared with another version of the map is copied before being handed out.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q> + Clone,
+        Q: ?Sized + Eq + Hash,
+        V: Clone,
+    {
+        let hash = self.hasher.hash_one(key);
+        // avoid copying the path when the key is not present
+        if !self.contains_key(key) {
+            return None;
+        }
+        let mut branch = Arc::make_mut(&mut self.root);
+        let mut shift = 0;
+        loop {
+            let bit = bitpos(hash, shift);
+            let pos = branch.index(bit);
+            match &mut branch.slots[pos] {
+                Slot::Leaf { value, .. } => return Some(Arc::make_mut(value)),
+                Slot::Node(child) => {
+                    branch = Arc::make_mut(child);
+                    shift += BITS;
+                }
+                Slot::Collision { entries, .. } => {
+                    return entries
+                        .iter_mut()
+                        .find(|(k, _)| k.borrow() == key)
+                        .map(|(_, v)| Arc::make_mut(v));
+                }
+            }
+        }
+    }
+    /// inserts the given entry into the map, returning the previous value associated with the
+    /// key (if any).
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let hash = self.hasher.hash_one(&key);
+        let root = Arc::make_mut(&mut self.root);
+        let prev = root.insert(hash, 0, key, Arc::new(value));
+        if prev.is_none() {
+            self.len += 1;
+        }
+        prev.map(unwrap_or_clone)
+    }
+    /// removes the entry associated with the given key, returning its value (if any).
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q> + Clone,
+        Q: ?Sized + Eq + Hash,
+        V: Clone,
+    {
+        // avoid copying the path when the key is not present
+        if !self.contains_key(key) {
+            return None;
+        }
+        let hash = self.hasher.hash_one(key);
+        let root = Arc::make_mut(&mut self.root);
+        let prev = root.remove(hash, 0, key);
+        if prev.is_some() {
+            self.len -= 1;
+        }
+        prev.map(unwrap_or_clone)
+    }
+    /// retains only the entries satisfying the given predicate
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &V) -> bool,
+        K: Clone,
+        V: Clone,
+    {
+        let keys = self
+            .iter()
+            .filter(|(k, v)| !f(k, v))
+            .map(|(k, _)| k.clone())
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+}
+
+impl<K, V> Branch<K, V> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            bitmap: 0,
+            slots: Vec::new(),
+        }
+    }
+    /// returns the position of the slot associated with the given bit
+    pub(crate) fn index(&self, bit: u32) -> usize {
+        (self.bitmap & (bit - 1)).count_ones() as usize
+    }
+}
+
+impl<K, V> Branch<K, V>
+where
+    K: Clone + Eq,
+{
+    fn insert(&mut self, hash: u64, shift: u32, key: K, value: Arc<V>) -> Option<Arc<V>> {
+        let bit = bitpos(hash, shift);
+        let pos = self.index(bit);
+        if self.bitmap & bit == 0 {
+            self.bitmap |= bit;
+            self.slots.insert(pos, Slot::Leaf { hash, key, value });
+            return None;
+        }
+        let slot = &mut self.slots[pos];
+        match slot {
+            Slot::Leaf {
+                hash: h,
+                key: k,
+                value: v,
+            } => {
+                if *h == hash && *k == key {
+                    return Some(core::mem::replace(v, value));
+                }
+                // split the leaf into a new sub-trie (or a collision bucket)
+                let (h, k, v) = (*h, k.clone(), v.clone());
+                *slot = Slot::pair(shift + BITS, (h, k, v), (hash, key, value));
+                None
+            }
+            Slot::Node(child) => Arc::make_mut(child).insert(hash, shift + BITS, key, value),
+            Slot::Collision { hash: h, entries } => {
+                if *h == hash {
+                    if let Some((_, v)) = entries.iter_mut().find(|(k, _)| *k == key) {
+                        return Some(core::mem::replace(v, value));
+                    }
+                    entries.push((key, value));
+                    return None;
+                }
+                // nest the bucket one level deeper alongside the new leaf
+                let mut branch = Branch::new();
+                let (h, entries) = (*h, core::mem::take(entries));
+                let cbit = bitpos(h, shift + BITS);
+                branch.bitmap |= cbit;
+                branch.slots.push(Slot::Collision { hash: h, entries });
+                branch.insert(hash, shift + BITS, key, value);
+                *slot = Slot::Node(Arc::new(branch));
+                None
+            }
+        }
+    }
+
+    fn remove<Q>(&mut self, hash: u64, shift: u32, key: &Q) -> Option<Arc<V>>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        let bit = bitpos(hash, shift);
+        if self.bitmap & bit == 0 {
+            return None;
+        }
+        let pos = self.index(bit);
+        let removed = match &mut self.slots[pos] {
+            Slot::Leaf {
+                hash: h, key: k, ..
+            } => {
+                if *h != hash || (*k).borrow() != key {
+                    return None;
+                }
+                self.bitmap &= !bit;
+                match self.slots.remove(pos) {
+                    Slot::Leaf { value, .. } => value,
+                    _ => unreachable!(),
+                }
+            }
+            Slot::Node(child) => {
+                let child = Arc::make_mut(child);
+                let removed = child.remove(hash, shift + BITS, key)?;
+                // collapse the child whenever it is reduced to a single entry
+                if child.slots.is_empty() {
+                    self.bitmap &= !bit;
+                    self.slots.remove(pos);
+                } else if child.slots.len() == 1 && !matches!(child.slots[0], Slot::Node(_)) {
+                    let only = child.slots.pop().unwrap();
+                    self.slots[pos] = only;
+                }
+                removed
+            }
+            Slot::Collision { hash: h, entries } => {
+                if *h != hash {
+                    return None;
+                }
+                let at = entries.iter().position(|(k, _)| k.borrow() == key)?;
+                let (_, value) = entries.remove(at);
+                if entries.len() == 1 {
+                    let (key, value) = entries.pop().unwrap();
+                    self.slots[pos] = Slot::Leaf {
+                        hash: *h,
+                        key,
+                        value,
+                    };
+                }
+                value
+            }
+        };
+        Some(removed)
+    }
+}
+
+impl<K, V> Slot<K, V> {
+    /// creates a slot holding the two given (distinct) entries
+    fn pair(shift: u32, a: (u64, K, Arc<V>), b: (u64, K, Arc<V>)) -> Self {
+        if shift >= MAX_SHIFT || a.0 == b.0 {
+            return Slot::Collision {
+                hash: a.0,
+                entries: alloc::vec![(a.1, a.2), (b.1, b.2)],
+            };
+        }
+        let (abit, bbit) = (bitpos(a.0, shift), bitpos(b.0, shift));
+        let mut branch = Branch::new();
+        if abit == bbit {
+            branch.bitmap = abit;
+            branch.slots.push(Slot::pair(shift + BITS, a, b));
+        } else {
+            branch.bitmap = abit | bbit;
+            let (lo, hi) = if abit < bbit { (a, b) } else { (b, a) };
+            branch.slots.push(Slot::Leaf {
+                hash: lo.0,
+                key: lo.1,
+                value: lo.2,
+            });
+            branch.slots.push(Slot::Leaf {
+                hash: hi.0,
+                key: hi.1,
+                value: hi.2,
+            });
+        }
+        Slot::Node(Arc::new(branch))
+    }
+}
+
+/// returns the bit associated with the given hash at the given level
+const fn bitpos(hash: u64, shift: u32) -> u32 {
+    if shift >= MAX_SHIFT {
+        return 1;
+    }
+    1 << ((hash >> shift) & MASK)
+}
+
+/// returns the inner value of the [`Arc`], cloning it if it is still shared
+fn unwrap_or_clone<V: Clone>(value: Arc<V>) -> V {
+    Arc::try_unwrap(value).unwrap_or_else(|shared| (*shared).clone())
+}
+
+impl<K, V> Clone for Branch<K, V>
+where
+    K: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bitmap: self.bitmap,
+            slots: self.slots.clone(),
+        }
+    }
+}
+
+impl<K, V> Clone for Slot<K, V>
+where
+    K: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Slot::Leaf { hash, key, value } => Slot::Leaf {
+                hash: *hash,
+                key: key.clone(),
+                value: value.clone(),
+            },
+            Slot::Node(child) => Slot::Node(child.clone()),
+            Slot::Collision { hash, entries } => Slot::Collision {
+                hash: *hash,
+                entries: entries.clone(),
+            },
+        }
+    }
+}
+
+impl<K, V, S> Clone for HamtMap<K, V, S>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<K, V, S> Default for HamtMap<K, V, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> core::fmt::Debug for HamtMap<K, V, S>
+where
+    K: core::fmt::Debug,
+    V: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S> PartialEq for HamtMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.ptr_eq(other) {
+            return true;
+        }
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| other.get(k).is_some_and(|ov| v == ov))
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HamtMap<K, V, S>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HamtMap<K, V, S>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HamtMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(bucket) = self.bucket.as_mut() {
+                if let Some((k, v)) = bucket.next() {
+                    self.remaining -= 1;
+                    return Some((k, &**v));
+                }
+                self.bucket = None;
+            }
+            let slots = self.stack.last_mut()?;
+            match slots.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(Slot::Leaf { key, value, .. }) => {
+                    self.remaining -= 1;
+                    return Some((key, &**value));
+                }
+                Some(Slot::Node(child)) => self.stack.push(child.slots.iter()),
+                Some(Slot::Collision { entries, .. }) => self.bucket = Some(entries.iter()),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
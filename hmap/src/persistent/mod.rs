@@ -0,0 +1,23 @@
+/*
+    appellation: persistent <module>
+    authors: @FL03
+*/
+//! this module implements the [`PersistentHyperMap`], an immutable hypergraph whose nodes and
+//! edges are stored within a _hash array mapped trie_ ([`HamtMap`]), enabling `O(1)` clones
+//! and cheap, structurally shared versions of the graph.
+#[doc(inline)]
+pub use self::{graph::*, hamt::*};
+
+mod graph;
+mod hamt;
+
+mod impls {
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+    #[doc(inline)]
+    pub use super::hamt::HamtMap;
+}
@@ -0,0 +1,245 @@
+/*
+    appellation: column <module>
+    authors: @FL03
+*/
+use super::{Value, ValueKind};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{DefaultHashBuilder, HashMap};
+
+/// The [`Column`] stores the values of a single, named property for some collection of
+/// elements (keyed by `I`), where every value within the column is of the same
+/// [`ValueKind`].
+#[derive(Clone, strum::EnumIs)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Deserialize, serde_derive::Serialize),
+    serde(
+        rename_all = "snake_case",
+        tag = "kind",
+        content = "values",
+        bound(
+            serialize = "I: Eq + Hash + serde::Serialize, S: BuildHasher",
+            deserialize = "I: Eq + Hash + serde::Deserialize<'de>, S: BuildHasher + Default"
+        )
+    )
+)]
+pub enum Column<I, S = DefaultHashBuilder> {
+    Int(#[cfg_attr(feature = "serde", serde(with = "entries"))] HashMap<I, i64, S>),
+    Float(#[cfg_attr(feature = "serde", serde(with = "entries"))] HashMap<I, f64, S>),
+    Bool(#[cfg_attr(feature = "serde", serde(with = "entries"))] HashMap<I, bool, S>),
+    String(#[cfg_attr(feature = "serde", serde(with = "entries"))] HashMap<I, String, S>),
+    Bytes(#[cfg_attr(feature = "serde", serde(with = "entries"))] HashMap<I, Vec<u8>, S>),
+}
+
+/// The [`Property`] trait is implemented for each of the types that may be stored within a
+/// [`Column`], providing typed access to the values of the column.
+pub trait Property: Sized {
+    private!();
+    /// the kind of column storing the type
+    const KIND: ValueKind;
+    /// returns the values of the column, if the column stores the type
+    fn values<I, S>(column: &Column<I, S>) -> Option<&HashMap<I, Self, S>>;
+    /// returns a mutable reference to the values of the column, if the column stores the type
+    fn values_mut<I, S>(column: &mut Column<I, S>) -> Option<&mut HashMap<I, Self, S>>;
+}
+
+impl<I, S> Column<I, S>
+where
+    S: BuildHasher + Default,
+{
+    /// returns a new, empty column of the given kind
+    pub fn new(kind: ValueKind) -> Self {
+        match kind {
+            ValueKind::Int => Self::Int(HashMap::default()),
+            ValueKind::Float => Self::Float(HashMap::default()),
+            ValueKind::Bool => Self::Bool(HashMap::default()),
+            ValueKind::String => Self::String(HashMap::default()),
+            ValueKind::Bytes => Self::Bytes(HashMap::default()),
+        }
+    }
+}
+
+impl<I, S> Column<I, S> {
+    /// returns the [`ValueKind`] of the values stored within the column
+    pub const fn kind(&self) -> ValueKind {
+        match self {
+            Self::Int(_) => ValueKind::Int,
+            Self::Float(_) => ValueKind::Float,
+            Self::Bool(_) => ValueKind::Bool,
+            Self::String(_) => ValueKind::String,
+            Self::Bytes(_) => ValueKind::Bytes,
+        }
+    }
+    /// returns the number of values stored within the column
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Int(values) => values.len(),
+            Self::Float(values) => values.len(),
+            Self::Bool(values) => values.len(),
+            Self::String(values) => values.len(),
+            Self::Bytes(values) => values.len(),
+        }
+    }
+    /// returns true if the column contains no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// returns the values of the column as the given type, if the column stores it
+    pub fn values<T: Property>(&self) -> Option<&HashMap<I, T, S>> {
+        T::values(self)
+    }
+    /// returns a mutable reference to the values of the column as the given type, if the
+    /// column stores it
+    pub fn values_mut<T: Property>(&mut self) -> Option<&mut HashMap<I, T, S>> {
+        T::values_mut(self)
+    }
+}
+
+impl<I, S> Column<I, S>
+where
+    I: Eq + Hash,
+    S: BuildHasher,
+{
+    /// returns true if the column contains a value for the given element
+    pub fn contains(&self, key: &I) -> bool {
+        match self {
+            Self::Int(values) => values.contains_key(key),
+            Self::Float(values) => values.contains_key(key),
+            Self::Bool(values) => values.contains_key(key),
+            Self::String(values) => values.contains_key(key),
+            Self::Bytes(values) => values.contains_key(key),
+        }
+    }
+    /// returns a copy of the value of the given element, if any
+    pub fn get(&self, key: &I) -> Option<Value> {
+        match self {
+            Self::Int(values) => values.get(key).copied().map(Value::Int),
+            Self::Float(values) => values.get(key).copied().map(Value::Float),
+            Self::Bool(values) => values.get(key).copied().map(Value::Bool),
+            Self::String(values) => values.get(key).cloned().map(Value::String),
+            Self::Bytes(values) => values.get(key).cloned().map(Value::Bytes),
+        }
+    }
+    /// set the value of the given element, returning the previous value, if any; the value
+    /// is returned as an error if it does not match the kind of the column.
+    pub fn insert(&mut self, key: I, value: Value) -> Result<Option<Value>, Value> {
+        let prev = match (self, value) {
+            (Self::Int(values), Value::Int(v)) => values.insert(key, v).map(Value::Int),
+            (Self::Float(values), Value::Float(v)) => values.insert(key, v).map(Value::Float),
+            (Self::Bool(values), Value::Bool(v)) => values.insert(key, v).map(Value::Bool),
+            (Self::String(values), Value::String(v)) => values.insert(key, v).map(Value::String),
+            (Self::Bytes(values), Value::Bytes(v)) => values.insert(key, v).map(Value::Bytes),
+            (_, value) => return Err(value),
+        };
+        Ok(prev)
+    }
+    /// remove the value of the given element, returning it
+    pub fn remove(&mut self, key: &I) -> Option<Value> {
+        match self {
+            Self::Int(values) => values.remove(key).map(Value::Int),
+            Self::Float(values) => values.remove(key).map(Value::Float),
+            Self::Bool(values) => values.remove(key).map(Value::Bool),
+            Self::String(values) => values.remove(key).map(Value::String),
+            Self::Bytes(values) => values.remove(key).map(Value::Bytes),
+        }
+    }
+    /// retains only the values whose elements satisfy the given predicate
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&I) -> bool,
+    {
+        match self {
+            Self::Int(values) => values.retain(|k, _| f(k)),
+            Self::Float(values) => values.retain(|k, _| f(k)),
+            Self::Bool(values) => values.retain(|k, _| f(k)),
+            Self::String(values) => values.retain(|k, _| f(k)),
+            Self::Bytes(values) => values.retain(|k, _| f(k)),
+        }
+    }
+}
+
+impl<I, S> core::fmt::Debug for Column<I, S>
+where
+    I: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut map = f.debug_map();
+        match self {
+            Self::Int(values) => map.entries(values.iter()),
+            Self::Float(values) => map.entries(values.iter()),
+            Self::Bool(values) => map.entries(values.iter()),
+            Self::String(values) => map.entries(values.iter()),
+            Self::Bytes(values) => map.entries(values.iter()),
+        };
+        map.finish()
+    }
+}
+
+macro_rules! impl_property {
+    ($($variant:ident($T:ty)),* $(,)?) => {
+        $(
+            impl Property for $T {
+                seal!();
+
+                const KIND: ValueKind = ValueKind::$variant;
+
+                fn values<I, S>(column: &Column<I, S>) -> Option<&HashMap<I, Self, S>> {
+                    match column {
+                        Column::$variant(values) => Some(values),
+                        _ => None,
+                    }
+                }
+
+                fn values_mut<I, S>(column: &mut Column<I, S>) -> Option<&mut HashMap<I, Self, S>> {
+                    match column {
+                        Column::$variant(values) => Some(values),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_property! {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+/// the values of each column are serialized as a sequence of entries, since the indices of
+/// the elements may not be used as the keys of every format (e.g. JSON)
+#[cfg(feature = "serde")]
+mod entries {
+    use alloc::vec::Vec;
+    use core::hash::{BuildHasher, Hash};
+    use hashbrown::HashMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<K, V, S, Ser>(
+        map: &HashMap<K, V, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        Ser: Serializer,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub fn deserialize<'de, K, V, S, D>(deserializer: D) -> Result<HashMap<K, V, S>, D::Error>
+    where
+        K: Eq + Hash + Deserialize<'de>,
+        V: Deserialize<'de>,
+        S: BuildHasher + Default,
+        D: Deserializer<'de>,
+    {
+        let entries = Vec::<(K, V)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
@@ -0,0 +1,271 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use super::{EdgeProperties, NodeProperties, Property, Value};
+use crate::HyperMap;
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use hashbrown::DefaultHashBuilder;
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, RawIndex, Udx, VertexId};
+use rshyper_core::{Error, GraphType, Node, Result, Weight};
+
+/// a type alias for a [directed](rshyper_core::Directed) [`PropertyHyperMap`]
+pub type DiPropertyHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    PropertyHyperMap<N, E, DiAttrs<Idx>, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`PropertyHyperMap`]
+pub type UnPropertyHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    PropertyHyperMap<N, E, UnAttrs<Idx>, S>;
+
+/// The [`PropertyHyperMap`] is a _property graph_ whose vertices and edges may be annotated
+/// with any number of named, typed properties alongside their weights.
+///
+/// ## Overview
+///
+/// The graph wraps a standard [`HyperMap`] alongside two [`PropertyTable`](super::PropertyTable)s,
+/// storing the properties of the vertices and edges respectively. Since the properties are
+/// stored in columns created at runtime, the schema of the graph may change without altering
+/// the types of its weights. Removing an element from the graph also removes its properties,
+/// including those of any edges removed alongside a vertex.
+pub struct PropertyHyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the underlying hypergraph
+    pub(crate) graph: HyperMap<N, E, A, S>,
+    /// the properties of the vertices
+    pub(crate) nodes: NodeProperties<A::Ix, S>,
+    /// the properties of the edges
+    pub(crate) edges: EdgeProperties<A::Ix, S>,
+}
+
+impl<N, E, A, K, Ix, S> PropertyHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`PropertyHyperMap`]
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            graph: HyperMap::new(),
+            nodes: NodeProperties::new(),
+            edges: EdgeProperties::new(),
+        }
+    }
+    /// returns an immutable reference to the underlying [`HyperMap`]
+    pub const fn graph(&self) -> &HyperMap<N, E, A, S> {
+        &self.graph
+    }
+    /// returns an immutable reference to the properties of the vertices
+    pub const fn node_properties(&self) -> &NodeProperties<Ix, S> {
+        &self.nodes
+    }
+    /// returns an immutable reference to the properties of the edges
+    pub const fn edge_properties(&self) -> &EdgeProperties<Ix, S> {
+        &self.edges
+    }
+    /// consumes the instance to return the underlying [`HyperMap`], discarding the properties
+    /// of its elements
+    pub fn into_graph(self) -> HyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.graph.order()
+    }
+    /// returns the number of edges within the graph
+    pub fn size(&self) -> usize {
+        self.graph.size()
+    }
+}
+
+impl<N, E, A, K, Ix, S> PropertyHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// create a new instance from the given graph and properties; any properties of elements
+    /// that are missing from the graph are discarded.
+    pub fn from_parts(
+        graph: HyperMap<N, E, A, S>,
+        mut nodes: NodeProperties<Ix, S>,
+        mut edges: EdgeProperties<Ix, S>,
+    ) -> Self {
+        nodes.retain(|id| graph.contains_node(id));
+        edges.retain(|id| graph.contains_edge(id));
+        Self {
+            graph,
+            nodes,
+            edges,
+        }
+    }
+    /// add a new node with the given weight to the graph
+    pub fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        self.graph.add_node(weight)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.graph.add_vertex()
+    }
+    /// add a new edge composed of the given vertices
+    pub fn add_edge<I>(&mut self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        self.graph.add_edge(vertices, weight)
+    }
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, K, Ix, S>> {
+        self.graph.get_edge(index)
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.graph.get_node(index)
+    }
+    /// returns the named property of the node with the given index as the given type, if the
+    /// node has one
+    pub fn get_node_property<T: Property>(
+        &self,
+        index: &VertexId<Ix>,
+        name: &str,
+    ) -> Result<Option<&T>> {
+        self.nodes.get(name, index)
+    }
+    /// returns the named property of the edge with the given index as the given type, if the
+    /// edge has one
+    pub fn get_edge_property<T: Property>(
+        &self,
+        index: &EdgeId<Ix>,
+        name: &str,
+    ) -> Result<Option<&T>> {
+        self.edges.get(name, index)
+    }
+    /// set the named property of the node with the given index, returning the previous value
+    pub fn set_node_property<V>(
+        &mut self,
+        index: &VertexId<Ix>,
+        name: &str,
+        value: V,
+    ) -> Result<Option<Value>>
+    where
+        V: Into<Value>,
+    {
+        if !self.contains_node(index) {
+            return Err(Error::NodeNotFound);
+        }
+        self.nodes.set(name, *index, value)
+    }
+    /// set the named property of the edge with the given index, returning the previous value
+    pub fn set_edge_property<V>(
+        &mut self,
+        index: &EdgeId<Ix>,
+        name: &str,
+        value: V,
+    ) -> Result<Option<Value>>
+    where
+        V: Into<Value>,
+    {
+        if !self.contains_edge(index) {
+            return Err(Error::EdgeNotFound);
+        }
+        self.edges.set(name, *index, value)
+    }
+    /// remove the named property of the node with the given index, returning it
+    pub fn remove_node_property(&mut self, index: &VertexId<Ix>, name: &str) -> Option<Value> {
+        self.nodes.remove(name, index)
+    }
+    /// remove the named property of the edge with the given index, returning it
+    pub fn remove_edge_property(&mut self, index: &EdgeId<Ix>, name: &str) -> Option<Value> {
+        self.edges.remove(name, index)
+    }
+    /// removes the edge with the given index alongside its properties
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>> {
+        let edge = self.graph.remove_edge(index)?;
+        self.edges.remove_row(index);
+        Ok(edge)
+    }
+    /// removes the node with the given index alongside its properties and each of the edges
+    /// containing it
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let removed = self
+            .graph
+            .find_edges_with_node(index)
+            .copied()
+            .collect::<Vec<_>>();
+        let node = self.graph.remove_node(index)?;
+        self.nodes.remove_row(index);
+        for edge in removed {
+            self.edges.remove_row(&edge);
+        }
+        Ok(node)
+    }
+}
+
+impl<N, E, A, S> Clone for PropertyHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    A::Ix: Clone,
+    S: BuildHasher + Clone,
+    HyperMap<N, E, A, S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+        }
+    }
+}
+
+impl<N, E, A, S> Default for PropertyHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for PropertyHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PropertyHyperMap")
+            .field("graph", &self.graph)
+            .field("nodes", &self.nodes)
+            .field("edges", &self.edges)
+            .finish()
+    }
+}
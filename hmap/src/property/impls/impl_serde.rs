@@ -0,0 +1,73 @@
+/*
+    appellation: impl_serde <module>
+    authors: @FL03
+*/
+use crate::HyperMap;
+use crate::property::{EdgeProperties, NodeProperties, PropertyHyperMap};
+use core::hash::BuildHasher;
+use rshyper::idx::HyperIndex;
+use rshyper::{GraphProps, GraphType};
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+impl<N, E, A, S> Serialize for PropertyHyperMap<N, E, A, S>
+where
+    A: GraphProps + Serialize,
+    E: Serialize,
+    N: Serialize,
+    S: BuildHasher + Default,
+    A::Ix: HyperIndex + Serialize,
+    A::Kind: Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PropertyHyperMap", 3)?;
+        state.serialize_field("graph", self.graph())?;
+        state.serialize_field("nodes", self.node_properties())?;
+        state.serialize_field("edges", self.edge_properties())?;
+        state.end()
+    }
+}
+
+/// any properties of elements that are missing from the deserialized graph are discarded
+impl<'de, N, E, A, S> Deserialize<'de> for PropertyHyperMap<N, E, A, S>
+where
+    A: GraphProps + DeserializeOwned,
+    E: DeserializeOwned,
+    N: DeserializeOwned,
+    S: BuildHasher + Clone + Default,
+    A::Ix: HyperIndex + DeserializeOwned,
+    A::Kind: GraphType + DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Repr {
+            graph,
+            nodes,
+            edges,
+        } = Repr::deserialize(deserializer)?;
+        Ok(Self::from_parts(graph, nodes, edges))
+    }
+}
+
+/// the serialized representation of the [`PropertyHyperMap`]
+#[derive(serde_derive::Deserialize)]
+#[serde(
+    rename = "PropertyHyperMap",
+    bound(
+        deserialize = "HyperMap<N, E, A, S>: Deserialize<'de>, NodeProperties<A::Ix, S>: Deserialize<'de>, EdgeProperties<A::Ix, S>: Deserialize<'de>"
+    )
+)]
+struct Repr<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    graph: HyperMap<N, E, A, S>,
+    nodes: NodeProperties<A::Ix, S>,
+    edges: EdgeProperties<A::Ix, S>,
+}
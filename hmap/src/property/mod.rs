@@ -0,0 +1,31 @@
+/*
+    appellation: property <module>
+    authors: @FL03
+*/
+//! this module implements the [`PropertyHyperMap`], a property graph attaching any number of
+//! named, typed properties to the vertices and edges of a hypergraph. The properties are
+//! stored within a [`PropertyTable`], a columnar store composed of [`Column`]s of [`Value`]s.
+#[doc(inline)]
+pub use self::{column::*, graph::*, table::*, value::*};
+
+mod column;
+mod graph;
+mod table;
+mod value;
+
+mod impls {
+    pub mod impl_hyper_graph;
+    #[cfg(feature = "serde")]
+    pub mod impl_serde;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::column::*;
+    #[doc(inline)]
+    pub use super::graph::*;
+    #[doc(inline)]
+    pub use super::table::*;
+    #[doc(inline)]
+    pub use super::value::*;
+}
@@ -0,0 +1,186 @@
+/*
+    appellation: table <module>
+    authors: @FL03
+*/
+use super::{Column, Property, Value, ValueKind};
+use alloc::string::String;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::idx::{EdgeId, VertexId};
+use rshyper_core::{Error, Result};
+
+/// a type alias for a [`PropertyTable`] whose rows are the vertices of a graph
+pub type NodeProperties<Ix, S = DefaultHashBuilder> = PropertyTable<VertexId<Ix>, S>;
+/// a type alias for a [`PropertyTable`] whose rows are the edges of a graph
+pub type EdgeProperties<Ix, S = DefaultHashBuilder> = PropertyTable<EdgeId<Ix>, S>;
+
+/// The [`PropertyTable`] is a columnar store of named, typed properties for some collection of
+/// elements keyed by `I`. Each property is stored within its own [`Column`], whose kind is
+/// determined when the column is first created, allowing the schema of the table to change at
+/// runtime.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Deserialize, serde_derive::Serialize),
+    serde(
+        transparent,
+        bound(
+            serialize = "I: Eq + Hash + serde::Serialize, S: BuildHasher",
+            deserialize = "I: Eq + Hash + serde::Deserialize<'de>, S: BuildHasher + Default"
+        )
+    )
+)]
+pub struct PropertyTable<I, S = DefaultHashBuilder> {
+    pub(crate) columns: HashMap<String, Column<I, S>, S>,
+}
+
+impl<I, S> PropertyTable<I, S>
+where
+    S: BuildHasher,
+{
+    /// returns a new, empty instance of the [`PropertyTable`]
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self {
+            columns: HashMap::default(),
+        }
+    }
+    /// returns an immutable reference to the columns of the table
+    pub const fn columns(&self) -> &HashMap<String, Column<I, S>, S> {
+        &self.columns
+    }
+    /// returns true if the table contains no columns
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+    /// returns the number of columns within the table
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+    /// returns an iterator over the names of the columns
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.columns.keys().map(String::as_str)
+    }
+    /// returns true if the table contains a column with the given name
+    pub fn contains_column(&self, name: &str) -> bool {
+        self.columns.contains_key(name)
+    }
+    /// returns an immutable reference to the column with the given name
+    pub fn column(&self, name: &str) -> Result<&Column<I, S>> {
+        self.columns.get(name).ok_or(Error::PropertyNotFound)
+    }
+    /// returns a mutable reference to the column with the given name
+    pub fn column_mut(&mut self, name: &str) -> Result<&mut Column<I, S>> {
+        self.columns.get_mut(name).ok_or(Error::PropertyNotFound)
+    }
+    /// returns the values of the column with the given name as the given type, enabling the
+    /// column to be iterated in bulk
+    pub fn values<T: Property>(&self, name: &str) -> Result<&HashMap<I, T, S>> {
+        self.column(name)?
+            .values()
+            .ok_or(Error::PropertyKindMismatch)
+    }
+    /// returns a mutable reference to the values of the column with the given name as the
+    /// given type
+    pub fn values_mut<T: Property>(&mut self, name: &str) -> Result<&mut HashMap<I, T, S>> {
+        self.column_mut(name)?
+            .values_mut()
+            .ok_or(Error::PropertyKindMismatch)
+    }
+    /// add a new, empty column with the given name and kind, returning a mutable reference to
+    /// it; if the column already exists, it is returned so long as it is of the same kind.
+    pub fn add_column(&mut self, name: &str, kind: ValueKind) -> Result<&mut Column<I, S>>
+    where
+        S: Default,
+    {
+        let column = self
+            .columns
+            .entry_ref(name)
+            .or_insert_with(|| Column::new(kind));
+        if column.kind() != kind {
+            return Err(Error::PropertyKindMismatch);
+        }
+        Ok(column)
+    }
+    /// remove the column with the given name, returning it
+    pub fn remove_column(&mut self, name: &str) -> Option<Column<I, S>> {
+        self.columns.remove(name)
+    }
+}
+
+impl<I, S> PropertyTable<I, S>
+where
+    I: Eq + Hash,
+    S: BuildHasher,
+{
+    /// returns the value of the named property of the given element as the given type, if
+    /// the element has one
+    pub fn get<T: Property>(&self, name: &str, key: &I) -> Result<Option<&T>> {
+        self.values(name).map(|values| values.get(key))
+    }
+    /// returns a copy of the value of the named property of the given element, if any
+    pub fn get_value(&self, name: &str, key: &I) -> Option<Value> {
+        self.columns.get(name).and_then(|column| column.get(key))
+    }
+    /// set the named property of the given element, creating the column if necessary and
+    /// returning the previous value, if any.
+    ///
+    /// **Note:** an error is returned if the column is of a different kind than the value.
+    pub fn set<V>(&mut self, name: &str, key: I, value: V) -> Result<Option<Value>>
+    where
+        S: Default,
+        V: Into<Value>,
+    {
+        let value = value.into();
+        self.add_column(name, value.kind())?
+            .insert(key, value)
+            .map_err(|_| Error::PropertyKindMismatch)
+    }
+    /// remove the named property of the given element, returning it
+    pub fn remove(&mut self, name: &str, key: &I) -> Option<Value> {
+        self.columns
+            .get_mut(name)
+            .and_then(|column| column.remove(key))
+    }
+    /// remove every property of the given element
+    pub fn remove_row(&mut self, key: &I) {
+        for column in self.columns.values_mut() {
+            column.remove(key);
+        }
+    }
+    /// returns an iterator over each of the properties of the given element
+    pub fn row<'a>(&'a self, key: &'a I) -> impl Iterator<Item = (&'a str, Value)> + 'a {
+        self.columns
+            .iter()
+            .filter_map(move |(name, column)| column.get(key).map(|v| (name.as_str(), v)))
+    }
+    /// retains only the properties of the elements satisfying the given predicate
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&I) -> bool,
+    {
+        for column in self.columns.values_mut() {
+            column.retain(&mut f);
+        }
+    }
+}
+
+impl<I, S> Default for PropertyTable<I, S>
+where
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, S> core::fmt::Debug for PropertyTable<I, S>
+where
+    I: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.columns.iter()).finish()
+    }
+}
@@ -0,0 +1,105 @@
+/*
+    appellation: value <module>
+    authors: @FL03
+*/
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The [`Value`] enumerates the kinds of values that may be stored within a property
+/// [`Column`](super::Column), with the [`ValueKind`] describing the kind of each column.
+#[derive(Clone, Debug, PartialEq, PartialOrd, strum::EnumDiscriminants, strum::EnumIs)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case"),
+    strum_discriminants(derive(serde::Deserialize, serde::Serialize))
+)]
+#[strum_discriminants(
+    name(ValueKind),
+    derive(
+        Hash,
+        Ord,
+        PartialOrd,
+        strum::AsRefStr,
+        strum::Display,
+        strum::EnumCount,
+        strum::EnumIs,
+        strum::EnumIter,
+        strum::EnumString,
+        strum::VariantNames,
+    ),
+    strum(serialize_all = "snake_case")
+)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+impl Value {
+    /// returns the [`ValueKind`] of the value
+    pub fn kind(&self) -> ValueKind {
+        ValueKind::from(self)
+    }
+    /// returns the value as an integer, if it is one
+    pub const fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+    /// returns the value as a float, if it is one
+    pub const fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+    /// returns the value as a boolean, if it is one
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+    /// returns the value as a string slice, if it is a string
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// returns the value as a byte slice, if it is a sequence of bytes
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_value {
+    ($($variant:ident($T:ty)),* $(,)?) => {
+        $(
+            impl From<$T> for Value {
+                fn from(value: $T) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_value! {
+    Int(i64),
+    Int(i32),
+    Float(f64),
+    Float(f32),
+    Bool(bool),
+    String(String),
+    String(&str),
+    Bytes(Vec<u8>),
+    Bytes(&[u8]),
+}
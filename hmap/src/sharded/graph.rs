@@ -0,0 +1,481 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use crate::{EdgeMap, HyperMap, NodeMap};
+use core::hash::BuildHasher;
+use hashbrown::DefaultHashBuilder;
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::{Edge, HashEdge};
+use rshyper_core::idx::{AtomicIndexFrame, EdgeId, HyperIndex, IndexTracker, Udx, VertexId};
+use rshyper_core::{Error, GraphType, Mode, Node, Result, VertexSet, Weight};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// a type alias for a lock-guarded shard of the nodes within a [`ShardedHyperMap`]
+pub type NodeShard<N, Ix, S> = RwLock<NodeMap<N, Ix, S>>;
+/// a type alias for a lock-guarded shard of the edges within a [`ShardedHyperMap`]
+pub type EdgeShard<E, K, Ix, S> = RwLock<EdgeMap<E, K, Ix, S>>;
+/// a type alias for the collection of node shards within a [`ShardedHyperMap`]
+pub type NodeShards<N, Ix, S> = Box<[NodeShard<N, Ix, S>]>;
+/// a type alias for the collection of edge shards within a [`ShardedHyperMap`]
+pub type EdgeShards<E, K, Ix, S> = Box<[EdgeShard<E, K, Ix, S>]>;
+
+/// a type alias for a [directed](rshyper_core::Directed) [`ShardedHyperMap`]
+pub type DiShardedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    ShardedHyperMap<N, E, DiAttrs<Idx>, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`ShardedHyperMap`]
+pub type UnShardedHyperMap<N, E, Idx = Udx, S = DefaultHashBuilder> =
+    ShardedHyperMap<N, E, UnAttrs<Idx>, S>;
+
+/// The [`ShardedHyperMap`] is a thread-safe hypergraph designed for workloads where many
+/// threads insert into, and read from, the same graph at once.
+///
+/// ## Overview
+///
+/// The nodes and edges of the graph are partitioned into a fixed number of _shards_, each
+/// guarded by its own [`RwLock`], so that writers only contend with one another when they
+/// touch the same shard. Indices are allocated through an [`AtomicIndexFrame`] rather than
+/// the `&mut` cursor of an [`IndexTracker`], allowing every method to operate on a shared
+/// reference; the graph is therefore [`Sync`] and may be shared between threads directly
+/// or through an [`Arc`](std::sync::Arc).
+///
+/// Since the entries live behind locks, accessors either clone the requested value or accept
+/// a closure that is invoked while the lock is held. Once ingestion has finished, the graph
+/// can be converted into a [`HyperMap`] using [`into_hyper_map`](Self::into_hyper_map).
+///
+/// ### Lock ordering
+///
+/// Node shards are always acquired before edge shards and multiple node shards are acquired
+/// in ascending order; adding an edge holds read locks on the shards of its vertices while
+/// the edge is inserted, ensuring that an edge can never be created for a vertex that is
+/// concurrently being removed.
+pub struct ShardedHyperMap<N = (), E = (), A = UnAttrs<Udx>, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the lock-striped shards containing the edges of the hypergraph
+    pub(crate) edges: EdgeShards<E, A::Kind, A::Ix, S>,
+    /// the lock-striped shards containing the nodes of the hypergraph
+    pub(crate) nodes: NodeShards<N, A::Ix, S>,
+    /// the attributes of a graph define its _kind_ and the type of index used
+    pub(crate) attrs: A,
+    /// the atomic allocator used to generate new indices
+    pub(crate) position: AtomicIndexFrame<A::Ix>,
+    /// the hasher used to select the shard of a particular entry
+    pub(crate) hash_builder: S,
+}
+
+impl<N, E, A, K, Ix, S> ShardedHyperMap<N, E, A, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: HyperIndex,
+{
+    /// returns a new, empty instance using a number of shards proportional to the available
+    /// parallelism of the system
+    pub fn new() -> Self
+    where
+        S: Clone + Default,
+    {
+        Self::with_shards(Self::default_shard_count())
+    }
+    /// returns a new, empty instance partitioned into (at least) the given number of shards
+    pub fn with_shards(shards: usize) -> Self
+    where
+        S: Clone + Default,
+    {
+        Self::with_shards_and_hasher(shards, S::default())
+    }
+    /// returns a new, empty instance partitioned into (at least) the given number of shards
+    /// and configured with the given [`BuildHasher`]
+    pub fn with_shards_and_hasher(shards: usize, hash_builder: S) -> Self
+    where
+        S: Clone,
+    {
+        let shards = shards.max(1).next_power_of_two();
+        let edges = (0..shards)
+            .map(|_| RwLock::new(EdgeMap::with_hasher(hash_builder.clone())))
+            .collect();
+        let nodes = (0..shards)
+            .map(|_| RwLock::new(NodeMap::with_hasher(hash_builder.clone())))
+            .collect();
+        Self {
+            edges,
+            nodes,
+            attrs: A::new(),
+            position: AtomicIndexFrame::zero(),
+            hash_builder,
+        }
+    }
+    /// returns the default number of shards; four times the available parallelism
+    pub fn default_shard_count() -> usize {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            * 4
+    }
+    /// returns the [`Mode`] of the hypergraph
+    pub fn mode(&self) -> Mode {
+        self.attrs.mode()
+    }
+    /// returns true if the hypergraph is directed
+    pub fn is_directed(&self) -> bool {
+        self.attrs.is_directed()
+    }
+    /// returns true if the hypergraph is undirected
+    pub fn is_undirected(&self) -> bool {
+        self.attrs.is_undirected()
+    }
+    /// returns the number of shards used to partition both the nodes and the edges
+    pub fn shard_count(&self) -> usize {
+        self.nodes.len()
+    }
+    /// returns an immutable reference to the index allocator of the hypergraph
+    pub const fn position(&self) -> &AtomicIndexFrame<Ix> {
+        &self.position
+    }
+    /// returns the number of nodes within the hypergraph
+    pub fn order(&self) -> usize {
+        self.nodes.iter().map(|shard| read(shard).len()).sum()
+    }
+    /// returns the number of edges within the hypergraph
+    pub fn size(&self) -> usize {
+        self.edges.iter().map(|shard| read(shard).len()).sum()
+    }
+    /// returns true if the hypergraph has no nodes nor any edges
+    pub fn is_empty(&self) -> bool {
+        self.nodes.iter().all(|shard| read(shard).is_empty())
+            && self.edges.iter().all(|shard| read(shard).is_empty())
+    }
+    /// add a new node with the given weight, returning its index
+    pub fn add_node(&self, Weight(weight): Weight<N>) -> Result<VertexId<Ix>> {
+        let id = self.position.next_node()?;
+        write(self.node_shard(&id)).insert(id, Node::new(id, weight));
+        #[cfg(feature = "tracing")]
+        tracing::trace!("added node {id} to the sharded hypergraph");
+        Ok(id)
+    }
+    /// add a new node using the logical default for its weight, returning its index
+    pub fn add_vertex(&self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.add_node(Default::default())
+    }
+    /// add a new hyperedge composed of the given vertices and weight, returning its index;
+    /// an error is returned if the domain is empty or if any of the vertices do not exist.
+    pub fn add_edge<I>(&self, vertices: I, weight: Weight<E>) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        S: Default,
+    {
+        let domain = vertices.into_iter().collect::<VertexSet<Ix, S>>();
+        if domain.is_empty() {
+            return Err(Error::EmptyHyperedge);
+        }
+        // acquire the node shards in ascending order to prevent deadlocks
+        let mut shards = domain.iter().map(|v| self.shard_of(v)).collect::<Vec<_>>();
+        shards.sort_unstable();
+        shards.dedup();
+        let guards = shards
+            .iter()
+            .map(|&i| (i, read(&self.nodes[i])))
+            .collect::<Vec<_>>();
+        // verify that each vertex exists while the shards are locked
+        for v in domain.iter() {
+            let shard = self.shard_of(v);
+            let exists = guards
+                .iter()
+                .find(|(i, _)| *i == shard)
+                .is_some_and(|(_, guard)| guard.contains_key(v));
+            if !exists {
+                return Err(Error::NodeNotFound);
+            }
+        }
+        let id = self.position.next_edge()?;
+        write(self.edge_shard(&id)).insert(id, Edge::from_parts(id, domain, weight));
+        drop(guards);
+        #[cfg(feature = "tracing")]
+        tracing::trace!("added edge {id} to the sharded hypergraph");
+        Ok(id)
+    }
+    /// add a new hyperedge composed of the given vertices using the logical default for its
+    /// weight
+    pub fn add_link<I>(&self, vertices: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        E: Default,
+        S: Default,
+    {
+        self.add_edge(vertices, Default::default())
+    }
+    /// returns true if the hypergraph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        read(self.edge_shard(index)).contains_key(index)
+    }
+    /// returns true if the hypergraph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        read(self.node_shard(index)).contains_key(index)
+    }
+    /// invokes the given function with an immutable reference to the edge, returning its
+    /// result; the shard containing the edge is read-locked for the duration of the call.
+    pub fn with_edge<F, R>(&self, index: &EdgeId<Ix>, f: F) -> Result<R>
+    where
+        F: FnOnce(&HashEdge<E, K, Ix, S>) -> R,
+    {
+        read(self.edge_shard(index))
+            .get(index)
+            .map(f)
+            .ok_or(Error::EdgeNotFound)
+    }
+    /// invokes the given function with a mutable reference to the edge, returning its result;
+    /// the shard containing the edge is write-locked for the duration of the call.
+    pub fn with_edge_mut<F, R>(&self, index: &EdgeId<Ix>, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut HashEdge<E, K, Ix, S>) -> R,
+    {
+        write(self.edge_shard(index))
+            .get_mut(index)
+            .map(f)
+            .ok_or(Error::EdgeNotFound)
+    }
+    /// invokes the given function with an immutable reference to the node, returning its
+    /// result; the shard containing the node is read-locked for the duration of the call.
+    pub fn with_node<F, R>(&self, index: &VertexId<Ix>, f: F) -> Result<R>
+    where
+        F: FnOnce(&Node<N, Ix>) -> R,
+    {
+        read(self.node_shard(index))
+            .get(index)
+            .map(f)
+            .ok_or(Error::NodeNotFound)
+    }
+    /// invokes the given function with a mutable reference to the node, returning its result;
+    /// the shard containing the node is write-locked for the duration of the call.
+    pub fn with_node_mut<F, R>(&self, index: &VertexId<Ix>, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut Node<N, Ix>) -> R,
+    {
+        write(self.node_shard(index))
+            .get_mut(index)
+            .map(f)
+            .ok_or(Error::NodeNotFound)
+    }
+    /// returns a copy of the edge associated with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>>
+    where
+        E: Clone,
+        K: Clone,
+        S: Clone,
+    {
+        self.with_edge(index, Clone::clone)
+    }
+    /// returns a copy of the domain of the given edge
+    pub fn get_domain(&self, index: &EdgeId<Ix>) -> Result<VertexSet<Ix, S>>
+    where
+        S: Clone,
+    {
+        self.with_edge(index, |edge| edge.domain().clone())
+    }
+    /// returns a copy of the weight of the given edge
+    pub fn get_edge_weight(&self, index: &EdgeId<Ix>) -> Result<Weight<E>>
+    where
+        E: Clone,
+    {
+        self.with_edge(index, |edge| edge.weight().clone())
+    }
+    /// returns a copy of the node associated with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<Node<N, Ix>>
+    where
+        N: Clone,
+    {
+        self.with_node(index, Clone::clone)
+    }
+    /// returns a copy of the weight of the given node
+    pub fn get_node_weight(&self, index: &VertexId<Ix>) -> Result<Weight<N>>
+    where
+        N: Clone,
+    {
+        self.with_node(index, |node| node.weight().clone())
+    }
+    /// returns the number of edges containing the given vertex
+    pub fn get_node_degree(&self, index: &VertexId<Ix>) -> usize {
+        self.edges
+            .iter()
+            .map(|shard| {
+                read(shard)
+                    .values()
+                    .filter(|edge| edge.contains(index))
+                    .count()
+            })
+            .sum()
+    }
+    /// returns the indices of every edge containing the given vertex
+    pub fn find_edges_with_node(&self, index: &VertexId<Ix>) -> Vec<EdgeId<Ix>> {
+        self.edges
+            .iter()
+            .flat_map(|shard| {
+                read(shard)
+                    .iter()
+                    .filter_map(|(id, edge)| edge.contains(index).then_some(*id))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+    /// update the weight of the given edge, returning the previous value
+    pub fn set_edge_weight(&self, index: &EdgeId<Ix>, weight: Weight<E>) -> Result<Weight<E>> {
+        self.with_edge_mut(index, |edge| core::mem::replace(edge.weight_mut(), weight))
+    }
+    /// update the weight of the given node, returning the previous value
+    pub fn set_node_weight(&self, index: &VertexId<Ix>, weight: Weight<N>) -> Result<Weight<N>> {
+        self.with_node_mut(index, |node| core::mem::replace(node.weight_mut(), weight))
+    }
+    /// remove the edge associated with the given index from the hypergraph
+    pub fn remove_edge(&self, index: &EdgeId<Ix>) -> Result<HashEdge<E, K, Ix, S>> {
+        write(self.edge_shard(index))
+            .remove(index)
+            .ok_or(Error::EdgeNotFound)
+    }
+    /// remove the given node, along with every edge containing it, from the hypergraph
+    pub fn remove_node(&self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        // hold the node shard while removing the edges so that no new edge may reference
+        // the node in the meantime
+        let mut nodes = write(self.node_shard(index));
+        let node = nodes.remove(index).ok_or(Error::NodeNotFound)?;
+        for shard in self.edges.iter() {
+            write(shard).retain(|_, edge| !edge.contains(index));
+        }
+        drop(nodes);
+        Ok(node)
+    }
+    /// remove every node and edge from the hypergraph; the index allocator is left untouched
+    pub fn clear(&self) {
+        for shard in self.nodes.iter() {
+            write(shard).clear();
+        }
+        for shard in self.edges.iter() {
+            write(shard).clear();
+        }
+    }
+    /// returns the indices of every node within the hypergraph
+    pub fn vertices(&self) -> Vec<VertexId<Ix>> {
+        self.nodes
+            .iter()
+            .flat_map(|shard| read(shard).keys().copied().collect::<Vec<_>>())
+            .collect()
+    }
+    /// returns the indices of every edge within the hypergraph
+    pub fn edge_keys(&self) -> Vec<EdgeId<Ix>> {
+        self.edges
+            .iter()
+            .flat_map(|shard| read(shard).keys().copied().collect::<Vec<_>>())
+            .collect()
+    }
+    /// consumes the sharded hypergraph to create a [`HyperMap`]; the history of the
+    /// resulting graph is reconstructed from the ordering of the indices.
+    pub fn into_hyper_map(self) -> Result<HyperMap<N, E, A, S>>
+    where
+        S: Clone,
+    {
+        let Self {
+            edges,
+            nodes,
+            position,
+            hash_builder,
+            ..
+        } = self;
+        let mut graph = HyperMap::with_hasher(hash_builder);
+        for shard in nodes.into_vec() {
+            graph
+                .nodes_mut()
+                .extend(shard.into_inner().unwrap_or_else(PoisonError::into_inner));
+        }
+        for shard in edges.into_vec() {
+            graph
+                .edges_mut()
+                .extend(shard.into_inner().unwrap_or_else(PoisonError::into_inner));
+        }
+        let mut verts = graph.nodes().keys().copied().collect::<Vec<_>>();
+        let mut links = graph.edges().keys().copied().collect::<Vec<_>>();
+        verts.sort();
+        links.sort();
+        graph
+            .set_history(
+                IndexTracker::from_cursor(position.into_frame()?)
+                    .with_nodes(verts)
+                    .with_edges(links),
+            )
+            .reindex();
+        Ok(graph)
+    }
+    /// creates a [`ShardedHyperMap`] from the given [`HyperMap`]
+    pub fn from_hyper_map(graph: HyperMap<N, E, A, S>) -> Result<Self>
+    where
+        S: Clone,
+    {
+        let sharded = Self::with_shards_and_hasher(
+            Self::default_shard_count(),
+            graph.nodes().hasher().clone(),
+        );
+        sharded.position.store(*graph.position())?;
+        let HyperMap { edges, nodes, .. } = graph;
+        for (id, node) in nodes {
+            write(sharded.node_shard(&id)).insert(id, node);
+        }
+        for (id, edge) in edges {
+            write(sharded.edge_shard(&id)).insert(id, edge);
+        }
+        Ok(sharded)
+    }
+    /// returns the position of the shard responsible for the given index
+    fn shard_of<T>(&self, index: &T) -> usize
+    where
+        T: core::hash::Hash,
+    {
+        // the number of shards is always a power of two
+        (self.hash_builder.hash_one(index) as usize) & (self.shard_count() - 1)
+    }
+    /// returns the shard containing the given edge
+    fn edge_shard(&self, index: &EdgeId<Ix>) -> &EdgeShard<E, K, Ix, S> {
+        &self.edges[self.shard_of(index)]
+    }
+    /// returns the shard containing the given node
+    fn node_shard(&self, index: &VertexId<Ix>) -> &NodeShard<N, Ix, S> {
+        &self.nodes[self.shard_of(index)]
+    }
+}
+
+impl<N, E, A, S> Default for ShardedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Clone + Default,
+    A::Ix: HyperIndex,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, S> core::fmt::Debug for ShardedHyperMap<N, E, A, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ShardedHyperMap")
+            .field("shards", &self.nodes.len())
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+/// acquires a read lock, recovering the guard if the lock has been poisoned
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+/// acquires a write lock, recovering the guard if the lock has been poisoned
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
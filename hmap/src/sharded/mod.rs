@@ -0,0 +1,16 @@
+/*
+    appellation: sharded <module>
+    authors: @FL03
+*/
+//! this module implements the [`ShardedHyperMap`], a thread-safe hypergraph whose nodes and
+//! edges are partitioned into lock-striped shards, supporting concurrent insertions and reads
+//! from many threads.
+#[doc(inline)]
+pub use self::graph::*;
+
+mod graph;
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+}
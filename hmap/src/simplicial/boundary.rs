@@ -0,0 +1,179 @@
+/*
+    appellation: boundary <module>
+    authors: @FL03
+*/
+use rshyper_core::idx::{EdgeId, RawIndex, Udx};
+use rshyper_core::{Error, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// a type alias for a [`BoundaryMatrix`] whose coefficients are taken from the field `Z/2`
+pub type Z2BoundaryMatrix<Ix = Udx> = BoundaryMatrix<bool, Ix>;
+/// a type alias for a [`BoundaryMatrix`] whose coefficients are (signed) integers
+pub type IntBoundaryMatrix<Ix = Udx> = BoundaryMatrix<i64, Ix>;
+
+/// The [`BoundaryMatrix`] is a dense representation of the `k`-th boundary operator of a
+/// [`SimplicialComplex`](super::SimplicialComplex), mapping the `k`-simplices (columns) onto
+/// the `(k-1)`-simplices (rows) composing their boundary.
+///
+/// Both the rows and columns are labeled by the ids of their simplices and ordered
+/// lexicographically according to their (sorted) vertices. The entries are stored in a
+/// row-major order.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BoundaryMatrix<T, Ix = Udx>
+where
+    Ix: RawIndex,
+{
+    /// the `(k-1)`-simplices labeling the rows of the matrix
+    pub(crate) rows: Vec<EdgeId<Ix>>,
+    /// the `k`-simplices labeling the columns of the matrix
+    pub(crate) cols: Vec<EdgeId<Ix>>,
+    /// the entries of the matrix in row-major order
+    pub(crate) data: Vec<T>,
+}
+
+impl<T, Ix> BoundaryMatrix<T, Ix>
+where
+    Ix: RawIndex,
+{
+    /// returns a new matrix with the given labels whose entries are all zero
+    pub(crate) fn zeros(rows: Vec<EdgeId<Ix>>, cols: Vec<EdgeId<Ix>>) -> Self
+    where
+        T: Clone + Default,
+    {
+        let data = alloc::vec![T::default(); rows.len() * cols.len()];
+        Self { rows, cols, data }
+    }
+    /// returns the simplices labeling the rows of the matrix
+    pub fn rows(&self) -> &[EdgeId<Ix>] {
+        &self.rows
+    }
+    /// returns the simplices labeling the columns of the matrix
+    pub fn cols(&self) -> &[EdgeId<Ix>] {
+        &self.cols
+    }
+    /// returns the entries of the matrix in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+    /// returns the shape of the matrix as a `(rows, cols)` tuple
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows.len(), self.cols.len())
+    }
+    /// returns true if the matrix has no entries
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    /// returns an immutable reference to the entry at the given row and column, if any
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if col >= self.cols.len() {
+            return None;
+        }
+        self.data.get(row * self.cols.len() + col)
+    }
+    /// returns the entries of the given row
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        let n = self.cols.len();
+        self.data.get(row * n..(row + 1) * n)
+    }
+    /// returns a mutable reference to the entry at the given row and column, if any
+    pub(crate) fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if col >= self.cols.len() {
+            return None;
+        }
+        self.data.get_mut(row * self.cols.len() + col)
+    }
+}
+
+impl<Ix> BoundaryMatrix<bool, Ix>
+where
+    Ix: RawIndex,
+{
+    /// computes the rank of the matrix over `Z/2` using gaussian elimination
+    pub fn rank(&self) -> usize {
+        let (m, n) = self.shape();
+        if m == 0 || n == 0 {
+            return 0;
+        }
+        // pack each row into words of bits before eliminating
+        let words = n.div_ceil(64);
+        let mut rows = (0..m)
+            .map(|r| {
+                let mut bits = alloc::vec![0u64; words];
+                for (c, &entry) in self.row(r).unwrap_or_default().iter().enumerate() {
+                    if entry {
+                        bits[c / 64] |= 1 << (c % 64);
+                    }
+                }
+                bits
+            })
+            .collect::<Vec<_>>();
+        let mut rank = 0;
+        for c in 0..n {
+            let (w, bit) = (c / 64, 1u64 << (c % 64));
+            let Some(pivot) = (rank..m).find(|&r| rows[r][w] & bit != 0) else {
+                continue;
+            };
+            rows.swap(rank, pivot);
+            let (head, tail) = rows.split_at_mut(rank + 1);
+            let pivot = &head[rank];
+            for row in tail.iter_mut().filter(|row| row[w] & bit != 0) {
+                row.iter_mut().zip(pivot).for_each(|(a, b)| *a ^= b);
+            }
+            rank += 1;
+        }
+        rank
+    }
+}
+
+impl<Ix> BoundaryMatrix<i64, Ix>
+where
+    Ix: RawIndex,
+{
+    /// computes the rank of the matrix over the rationals using the fraction-free Bareiss
+    /// elimination, whose intermediate entries are minors of the matrix; an
+    /// [`ArithmeticOverflow`](Error::ArithmeticOverflow) error is returned should any of them
+    /// exceed the range of an `i128`.
+    pub fn rank(&self) -> Result<usize> {
+        let (m, n) = self.shape();
+        if m == 0 || n == 0 {
+            return Ok(0);
+        }
+        let mut rows = (0..m)
+            .map(|r| {
+                self.row(r)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|&x| x as i128)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // the previous pivot, which exactly divides every updated entry
+        let (mut rank, mut prev) = (0, 1i128);
+        for c in 0..n {
+            let Some(pivot) = (rank..m).find(|&r| rows[r][c] != 0) else {
+                continue;
+            };
+            rows.swap(rank, pivot);
+            let (head, tail) = rows.split_at_mut(rank + 1);
+            let pivot = &head[rank];
+            let p = pivot[c];
+            // every remaining row is updated, even those already vanishing in this column
+            for row in tail.iter_mut() {
+                let a = row[c];
+                for (x, &y) in row.iter_mut().zip(pivot).skip(c) {
+                    *x = x
+                        .checked_mul(p)
+                        .zip(y.checked_mul(a))
+                        .and_then(|(lhs, rhs)| lhs.checked_sub(rhs))
+                        .ok_or_else(Error::arithmetic_overflow)?
+                        / prev;
+                }
+            }
+            prev = p;
+            rank += 1;
+        }
+        Ok(rank)
+    }
+}
@@ -0,0 +1,420 @@
+/*
+    appellation: complex <module>
+    authors: @FL03
+*/
+use super::{BoundaryMatrix, IntBoundaryMatrix, Z2BoundaryMatrix};
+use core::convert::Infallible;
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::edge::Edge;
+use rshyper_core::idx::{EdgeId, HyperIndex, IndexError, IndexFrame, RawIndex, Udx, VertexId};
+use rshyper_core::{Error, Result, Undirected, Weight};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// a type alias for a simplex, represented as an unweighted, [`Undirected`] [`Edge`] whose
+/// domain is the sorted list of its vertices
+pub type Simplex<Ix = Udx> = Edge<(), Vec<VertexId<Ix>>, Undirected, Ix>;
+/// a type alias for the map of simplices within a [`SimplicialComplex`]
+pub type SimplexMap<Ix = Udx, S = DefaultHashBuilder> = HashMap<EdgeId<Ix>, Simplex<Ix>, S>;
+
+/// The [`SimplicialComplex`] is a hypergraph whose edges, or _simplices_, are closed under
+/// taking subsets; in other words, every non-empty subset (or _face_) of a simplex is itself a
+/// simplex of the complex.
+///
+/// ## Overview
+///
+/// The complex enforces this _downward closure_ upon insertion, meaning that inserting a
+/// simplex automatically inserts each of its missing faces, including the `0`-simplices
+/// associated with its vertices. Conversely, removing a simplex removes each of the simplices
+/// containing it, or its _cofaces_. Each simplex is identified by an [`EdgeId`] and stores its
+/// vertices in sorted order, providing a canonical orientation used when computing the
+/// [boundary matrices](SimplicialComplex::boundary_matrix) of the complex.
+///
+/// The dimension of a simplex is one less than the number of its vertices, so vertices are
+/// `0`-simplices, edges are `1`-simplices, triangles are `2`-simplices, and so on.
+#[derive(Clone, Default)]
+pub struct SimplicialComplex<Ix = Udx, S = DefaultHashBuilder>
+where
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    /// the simplices of the complex, each identified by an [`EdgeId`]
+    pub(crate) simplices: SimplexMap<Ix, S>,
+    /// associates the sorted vertices of each simplex with its id
+    pub(crate) lookup: HashMap<Vec<VertexId<Ix>>, EdgeId<Ix>, S>,
+    /// associates each simplex with the simplices of one greater dimension containing it
+    pub(crate) cofaces: HashMap<EdgeId<Ix>, Vec<EdgeId<Ix>>, S>,
+    /// tracks the next available indices for simplices and vertices
+    pub(crate) position: IndexFrame<Ix>,
+}
+
+impl<Ix, S> SimplicialComplex<Ix, S>
+where
+    Ix: HyperIndex,
+    S: BuildHasher + Clone,
+{
+    /// returns a new, empty complex using the default [`BuildHasher`]
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self::with_hasher(S::default())
+    }
+    /// returns a new, empty complex configured with the given [`BuildHasher`]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            simplices: HashMap::with_hasher(hash_builder.clone()),
+            lookup: HashMap::with_hasher(hash_builder.clone()),
+            cofaces: HashMap::with_hasher(hash_builder),
+            position: IndexFrame::zero(),
+        }
+    }
+    /// returns an immutable reference to the simplices of the complex
+    pub const fn simplices(&self) -> &SimplexMap<Ix, S> {
+        &self.simplices
+    }
+    /// returns an immutable reference to the position of the complex, indicating the next
+    /// available indices
+    pub const fn position(&self) -> &IndexFrame<Ix> {
+        &self.position
+    }
+    /// returns true if the complex contains no simplices
+    pub fn is_empty(&self) -> bool {
+        self.simplices.is_empty()
+    }
+    /// returns the total number of simplices within the complex
+    pub fn len(&self) -> usize {
+        self.simplices.len()
+    }
+    /// returns the dimension of the complex, defined to be the largest dimension of any of its
+    /// simplices, or [`None`] if the complex is empty
+    pub fn dimension(&self) -> Option<usize> {
+        self.simplices.values().map(|s| s.len() - 1).max()
+    }
+    /// returns true if the complex contains a simplex with the given id
+    pub fn contains(&self, index: &EdgeId<Ix>) -> bool {
+        self.simplices.contains_key(index)
+    }
+    /// returns true if the complex contains a simplex composed of the given vertices
+    pub fn contains_simplex<I>(&self, vertices: I) -> bool
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        self.find_simplex(vertices).is_some()
+    }
+    /// returns true if the given vertex is a `0`-simplex of the complex
+    pub fn contains_vertex(&self, vertex: &VertexId<Ix>) -> bool {
+        self.lookup.contains_key([*vertex].as_slice())
+    }
+    /// returns the id of the simplex composed of the given vertices, if any
+    pub fn find_simplex<I>(&self, vertices: I) -> Option<&EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        self.lookup.get(&canonicalize(vertices))
+    }
+    /// returns an immutable reference to the simplex with the given id
+    pub fn get_simplex(&self, index: &EdgeId<Ix>) -> Result<&Simplex<Ix>> {
+        self.simplices.get(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns the dimension of the simplex with the given id
+    pub fn get_dimension(&self, index: &EdgeId<Ix>) -> Result<usize> {
+        self.get_simplex(index).map(|s| s.len() - 1)
+    }
+    /// returns the (sorted) vertices of the complex
+    pub fn vertices(&self) -> Vec<VertexId<Ix>> {
+        let mut vertices = self
+            .simplices
+            .values()
+            .filter(|s| s.len() == 1)
+            .map(|s| s.domain()[0])
+            .collect::<Vec<_>>();
+        vertices.sort_unstable();
+        vertices
+    }
+    /// returns the ids of the `k`-simplices of the complex, ordered lexicographically by their
+    /// vertices
+    pub fn k_simplices(&self, k: usize) -> Vec<EdgeId<Ix>> {
+        let mut simplices = self
+            .simplices
+            .values()
+            .filter(|s| s.len() == k + 1)
+            .collect::<Vec<_>>();
+        simplices.sort_unstable_by(|a, b| a.domain().cmp(b.domain()));
+        simplices.into_iter().map(|s| *s.id()).collect()
+    }
+    /// returns the number of `k`-simplices within the complex
+    pub fn count_simplices(&self, k: usize) -> usize {
+        self.simplices.values().filter(|s| s.len() == k + 1).count()
+    }
+    /// returns the `(k-1)`-dimensional faces of the `k`-simplex with the given id; the `i`-th
+    /// face is obtained by omitting the `i`-th vertex of the simplex. The faces of a
+    /// `0`-simplex are empty.
+    pub fn faces(&self, index: &EdgeId<Ix>) -> Result<Vec<EdgeId<Ix>>> {
+        let simplex = self.get_simplex(index)?;
+        if simplex.len() == 1 {
+            return Ok(Vec::new());
+        }
+        facets(simplex.domain())
+            .map(|face| self.lookup.get(&face).copied().ok_or(Error::EdgeNotFound))
+            .collect()
+    }
+    /// returns the `(k+1)`-dimensional cofaces of the `k`-simplex with the given id, i.e. the
+    /// simplices of one greater dimension containing it
+    pub fn cofaces(&self, index: &EdgeId<Ix>) -> Result<Vec<EdgeId<Ix>>> {
+        if !self.contains(index) {
+            return Err(Error::EdgeNotFound);
+        }
+        Ok(self.cofaces.get(index).cloned().unwrap_or_default())
+    }
+    /// add a new, isolated vertex to the complex, returning its id
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>> {
+        let vertex = self.position.next_node()?;
+        self.insert_simplex([vertex])?;
+        Ok(vertex)
+    }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, level = "trace", target = "simplicial")
+    )]
+    /// insert the simplex composed of the given vertices along with each of its missing faces,
+    /// returning its id; if the simplex already exists, its id is returned instead. Any
+    /// vertices that are not yet part of the complex are added as `0`-simplices, while an
+    /// error is returned if one of the vertices is the largest index representable by `Ix`.
+    pub fn insert_simplex<I>(&mut self, vertices: I) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let domain = canonicalize(vertices);
+        if domain.is_empty() {
+            #[cfg(feature = "tracing")]
+            tracing::error!("attempted to insert an empty simplex");
+            return Err(Error::EmptyHyperedge);
+        }
+        // ensure the next vertex index remains unused
+        if let Some(&last) = domain.last() {
+            if last >= *self.position.node() {
+                let next = last
+                    .to_u128()
+                    .and_then(|n| n.checked_add(1))
+                    .and_then(Ix::from_u128)
+                    .ok_or(IndexError::IndexOutOfBounds)?;
+                self.position.set_node(VertexId::new(next));
+            }
+        }
+        self.insert_closure(domain)
+    }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, level = "trace", target = "simplicial")
+    )]
+    /// removes the simplex with the given id alongside every simplex containing it, preserving
+    /// the downward closure of the complex; returns the removed simplices, ordered by their
+    /// dimension.
+    pub fn remove_simplex(&mut self, index: &EdgeId<Ix>) -> Result<Vec<Simplex<Ix>>> {
+        if !self.contains(index) {
+            return Err(Error::EdgeNotFound);
+        }
+        // collect the simplex and its cofaces, level by level
+        let mut removed = Vec::new();
+        let mut level = alloc::vec![*index];
+        while !level.is_empty() {
+            let mut next = level
+                .iter()
+                .filter_map(|id| self.cofaces.get(id))
+                .flatten()
+                .copied()
+                .collect::<Vec<_>>();
+            next.sort_unstable();
+            next.dedup();
+            for id in level {
+                if let Some(simplex) = self.detach(&id) {
+                    removed.push(simplex);
+                }
+            }
+            level = next;
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!("removed {} simplices from the complex", removed.len());
+        Ok(removed)
+    }
+    /// returns the `k`-skeleton of the complex, i.e. the subcomplex composed of every simplex
+    /// whose dimension is at most `k`; the ids of the simplices are preserved.
+    pub fn skeleton(&self, k: usize) -> Self {
+        let mut skeleton = self.clone();
+        skeleton.simplices.retain(|_, s| s.len() <= k + 1);
+        skeleton
+            .lookup
+            .retain(|vertices, _| vertices.len() <= k + 1);
+        let simplices = &skeleton.simplices;
+        skeleton.cofaces.retain(|id, cofaces| {
+            cofaces.retain(|c| simplices.contains_key(c));
+            simplices.contains_key(id) && !cofaces.is_empty()
+        });
+        skeleton
+    }
+    /// computes the `k`-th boundary matrix of the complex with integer coefficients; the entry
+    /// associated with the `i`-th face of a `k`-simplex is `(-1)^i`. The boundary of the
+    /// `0`-simplices is the zero map, represented by a matrix without any rows.
+    pub fn boundary_matrix(&self, k: usize) -> IntBoundaryMatrix<Ix> {
+        self.build_boundary(k, |i| if i % 2 == 0 { 1 } else { -1 })
+    }
+    /// computes the `k`-th boundary matrix of the complex with coefficients in `Z/2`
+    pub fn boundary_matrix_z2(&self, k: usize) -> Z2BoundaryMatrix<Ix> {
+        self.build_boundary(k, |_| true)
+    }
+    /// returns the `k`-th betti number of the complex with coefficients in `Z/2`
+    pub fn betti_number(&self, k: usize) -> usize {
+        let rank = |k| self.boundary_matrix_z2(k).rank();
+        let boundary = if k == 0 { 0 } else { rank(k) };
+        self.count_simplices(k) - boundary - rank(k + 1)
+    }
+    /// returns the betti numbers of the complex with coefficients in `Z/2`, from dimension
+    /// `0` up to the dimension of the complex
+    pub fn betti_numbers(&self) -> Vec<usize> {
+        let Ok(ranks) = self.ranks(|k| Ok::<_, Infallible>(self.boundary_matrix_z2(k).rank()));
+        self.betti_from_ranks(&ranks)
+    }
+    /// returns the rational betti numbers of the complex, computed from the ranks of its
+    /// integer boundary matrices, from dimension `0` up to the dimension of the complex;
+    /// fails should the elimination of any of the matrices overflow.
+    pub fn rational_betti_numbers(&self) -> Result<Vec<usize>> {
+        let ranks = self.ranks(|k| self.boundary_matrix(k).rank())?;
+        Ok(self.betti_from_ranks(&ranks))
+    }
+    /// returns the euler characteristic of the complex, i.e. the alternating sum of the number
+    /// of simplices of each dimension
+    pub fn euler_characteristic(&self) -> isize {
+        self.simplices
+            .values()
+            .map(|s| if s.len() % 2 == 1 { 1 } else { -1 })
+            .sum()
+    }
+    /// inserts the given (canonical) simplex after recursively inserting its missing faces
+    fn insert_closure(&mut self, domain: Vec<VertexId<Ix>>) -> Result<EdgeId<Ix>> {
+        if let Some(&id) = self.lookup.get(&domain) {
+            return Ok(id);
+        }
+        // the faces of an existing simplex are guaranteed to exist
+        let faces = if domain.len() > 1 {
+            facets(&domain)
+                .map(|face| self.insert_closure(face))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            Vec::new()
+        };
+        let id = self.position.next_edge()?;
+        for face in faces {
+            self.cofaces.entry(face).or_default().push(id);
+        }
+        self.lookup.insert(domain.clone(), id);
+        self.simplices
+            .insert(id, Simplex::from_parts(id, domain, Weight(())));
+        Ok(id)
+    }
+    /// removes a single simplex from the complex without visiting its cofaces
+    fn detach(&mut self, index: &EdgeId<Ix>) -> Option<Simplex<Ix>> {
+        let simplex = self.simplices.remove(index)?;
+        self.lookup.remove(simplex.domain());
+        self.cofaces.remove(index);
+        if simplex.len() > 1 {
+            for face in facets(simplex.domain()) {
+                if let Some(&face) = self.lookup.get(&face) {
+                    if let Some(cofaces) = self.cofaces.get_mut(&face) {
+                        cofaces.retain(|c| c != index);
+                    }
+                }
+            }
+        }
+        Some(simplex)
+    }
+    /// assembles the `k`-th boundary matrix using the given function to compute the entry
+    /// associated with the `i`-th face of each simplex
+    fn build_boundary<T, F>(&self, k: usize, entry: F) -> BoundaryMatrix<T, Ix>
+    where
+        T: Clone + Default,
+        F: Fn(usize) -> T,
+    {
+        let cols = self.k_simplices(k);
+        if k == 0 {
+            return BoundaryMatrix::zeros(Vec::new(), cols);
+        }
+        let rows = self.k_simplices(k - 1);
+        let positions = rows
+            .iter()
+            .enumerate()
+            .map(|(r, id)| (*id, r))
+            .collect::<HashMap<_, _>>();
+        let mut matrix = BoundaryMatrix::zeros(rows, cols.clone());
+        for (c, id) in cols.iter().enumerate() {
+            for (i, face) in self.faces(id).unwrap_or_default().iter().enumerate() {
+                if let Some(cell) = positions.get(face).and_then(|&r| matrix.get_mut(r, c)) {
+                    *cell = entry(i);
+                }
+            }
+        }
+        matrix
+    }
+    /// computes the rank of each boundary matrix from `0` up to one more than the dimension
+    /// of the complex
+    fn ranks<F, E>(&self, rank: F) -> core::result::Result<Vec<usize>, E>
+    where
+        F: Fn(usize) -> core::result::Result<usize, E>,
+    {
+        match self.dimension() {
+            None => Ok(Vec::new()),
+            Some(dim) => (0..=dim + 1)
+                .map(|k| if k == 0 { Ok(0) } else { rank(k) })
+                .collect(),
+        }
+    }
+    /// computes the betti numbers using the given ranks of the boundary matrices
+    fn betti_from_ranks(&self, ranks: &[usize]) -> Vec<usize> {
+        (0..ranks.len().saturating_sub(1))
+            .map(|k| self.count_simplices(k) - ranks[k] - ranks[k + 1])
+            .collect()
+    }
+}
+
+impl<Ix, S> core::fmt::Debug for SimplicialComplex<Ix, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SimplicialComplex")
+            .field("position", &self.position)
+            .field("simplices", &self.simplices)
+            .finish()
+    }
+}
+
+/// sorts and deduplicates the given vertices, producing the canonical form of a simplex
+fn canonicalize<Ix, I>(vertices: I) -> Vec<VertexId<Ix>>
+where
+    Ix: HyperIndex,
+    I: IntoIterator<Item = VertexId<Ix>>,
+{
+    let mut domain = vertices.into_iter().collect::<Vec<_>>();
+    domain.sort_unstable();
+    domain.dedup();
+    domain
+}
+
+/// returns an iterator over the codimension-1 faces of the given (canonical) simplex, where
+/// the `i`-th face omits the `i`-th vertex
+fn facets<Ix>(domain: &[VertexId<Ix>]) -> impl Iterator<Item = Vec<VertexId<Ix>>> + '_
+where
+    Ix: HyperIndex,
+{
+    (0..domain.len()).map(move |i| {
+        domain
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, v)| *v)
+            .collect()
+    })
+}
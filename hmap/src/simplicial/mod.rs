@@ -0,0 +1,18 @@
+/*
+    appellation: simplicial <module>
+    authors: @FL03
+*/
+//! this module implements the [`SimplicialComplex`], a hypergraph whose simplices are closed
+//! under taking faces, alongside the [`BoundaryMatrix`] used to compute its homology.
+#[doc(inline)]
+pub use self::{boundary::*, complex::*};
+
+mod boundary;
+mod complex;
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::boundary::*;
+    #[doc(inline)]
+    pub use super::complex::*;
+}
@@ -0,0 +1,277 @@
+/*
+    appellation: graph <module>
+    authors: @FL03
+*/
+use super::TemporalView;
+use crate::HyperMap;
+use core::hash::BuildHasher;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::attrs::{DiAttrs, GraphProps, UnAttrs};
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, RawIndex, Udx, VertexId};
+use rshyper_core::{Error, GraphType, Interval, Node, Result, Weight};
+
+/// a type alias for a map associating each edge with the interval during which it is active
+pub type IntervalMap<Ix, T, S = DefaultHashBuilder> = HashMap<EdgeId<Ix>, Interval<T>, S>;
+/// a type alias for an edge paired with the interval during which it is active
+pub type TimedEdge<E, K, Ix, T, S = DefaultHashBuilder> = (HashEdge<E, K, Ix, S>, Interval<T>);
+/// a type alias for a [directed](rshyper_core::Directed) [`TemporalHyperMap`]
+pub type DiTemporalHyperMap<N, E, T = u64, Idx = Udx, S = DefaultHashBuilder> =
+    TemporalHyperMap<N, E, DiAttrs<Idx>, T, S>;
+/// a type alias for an [undirected](rshyper_core::Undirected) [`TemporalHyperMap`]
+pub type UnTemporalHyperMap<N, E, T = u64, Idx = Udx, S = DefaultHashBuilder> =
+    TemporalHyperMap<N, E, UnAttrs<Idx>, T, S>;
+
+/// The [`TemporalHyperMap`] is a hypergraph whose edges are each associated with an
+/// [`Interval`] of time during which they are active; edges inserted at a single point in time
+/// are represented using an _instant_.
+///
+/// ## Overview
+///
+/// The graph wraps a standard [`HyperMap`], recording the interval of each edge alongside it
+/// rather than squashing time into the weights of the edges. Since each insertion creates a
+/// new edge, the same set of vertices may interact repeatedly over time. The graph may be
+/// inspected at a particular point in time using a [`snapshot`](TemporalHyperMap::snapshot),
+/// or over some span of time using a [`window`](TemporalHyperMap::window), both of which
+/// produce a [`TemporalView`] of the edges that are active at the time.
+pub struct TemporalHyperMap<N = (), E = (), A = UnAttrs<Udx>, T = u64, S = DefaultHashBuilder>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the underlying hypergraph
+    pub(crate) graph: HyperMap<N, E, A, S>,
+    /// the interval during which each edge is active
+    pub(crate) intervals: IntervalMap<A::Ix, T, S>,
+}
+
+impl<N, E, A, K, Ix, T, S> TemporalHyperMap<N, E, A, T, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: RawIndex,
+{
+    /// returns a new, empty instance of the [`TemporalHyperMap`]
+    pub fn new() -> Self
+    where
+        Ix: Default,
+        S: Default,
+    {
+        Self {
+            graph: HyperMap::new(),
+            intervals: IntervalMap::default(),
+        }
+    }
+    /// returns an immutable reference to the underlying [`HyperMap`]
+    pub const fn graph(&self) -> &HyperMap<N, E, A, S> {
+        &self.graph
+    }
+    /// returns an immutable reference to the intervals of the edges
+    pub const fn intervals(&self) -> &IntervalMap<Ix, T, S> {
+        &self.intervals
+    }
+    /// consumes the instance to return the underlying [`HyperMap`], discarding the intervals
+    pub fn into_graph(self) -> HyperMap<N, E, A, S> {
+        self.graph
+    }
+    /// returns true if the graph contains no nodes nor edges
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+    /// returns the number of nodes within the graph
+    pub fn order(&self) -> usize {
+        self.graph.order()
+    }
+    /// returns the number of edges within the graph
+    pub fn size(&self) -> usize {
+        self.graph.size()
+    }
+}
+
+impl<N, E, A, K, Ix, T, S> TemporalHyperMap<N, E, A, T, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher + Clone + Default,
+    K: GraphType,
+    Ix: HyperIndex,
+    T: Copy + Ord,
+{
+    /// add a new node with the given weight to the graph
+    pub fn add_node(&mut self, weight: Weight<N>) -> Result<VertexId<Ix>> {
+        self.graph.add_node(weight)
+    }
+    /// add a new vertex to the graph using the logical default for its weight
+    pub fn add_vertex(&mut self) -> Result<VertexId<Ix>>
+    where
+        N: Default,
+    {
+        self.graph.add_vertex()
+    }
+    /// add a new edge composed of the given vertices that occurred at the given instant
+    pub fn add_edge_at<I>(&mut self, vertices: I, weight: Weight<E>, time: T) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        self.add_edge_during(vertices, weight, Interval::instant(time))
+    }
+    /// add a new edge composed of the given vertices that is active during the given interval
+    pub fn add_edge_during<I>(
+        &mut self,
+        vertices: I,
+        weight: Weight<E>,
+        interval: Interval<T>,
+    ) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+    {
+        let id = self.graph.add_edge(vertices, weight)?;
+        self.intervals.insert(id, interval);
+        Ok(id)
+    }
+    /// add a new, unweighted edge composed of the given vertices that occurred at the given
+    /// instant
+    pub fn add_link_at<I>(&mut self, vertices: I, time: T) -> Result<EdgeId<Ix>>
+    where
+        I: IntoIterator<Item = VertexId<Ix>>,
+        E: Default,
+    {
+        self.add_edge_at(vertices, Default::default(), time)
+    }
+    /// returns true if the graph contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.contains_edge(index)
+    }
+    /// returns true if the graph contains a node with the given index
+    pub fn contains_node(&self, index: &VertexId<Ix>) -> bool {
+        self.graph.contains_node(index)
+    }
+    /// returns an immutable reference to the edge with the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Result<&HashEdge<E, K, Ix, S>> {
+        self.graph.get_edge(index)
+    }
+    /// returns an immutable reference to the interval of the edge with the given index
+    pub fn get_edge_interval(&self, index: &EdgeId<Ix>) -> Result<&Interval<T>> {
+        self.intervals.get(index).ok_or(Error::EdgeNotFound)
+    }
+    /// returns an immutable reference to the node with the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Result<&Node<N, Ix>> {
+        self.graph.get_node(index)
+    }
+    /// update the interval of the edge with the given index, returning the previous one
+    pub fn set_edge_interval(
+        &mut self,
+        index: &EdgeId<Ix>,
+        interval: Interval<T>,
+    ) -> Result<Interval<T>> {
+        self.intervals
+            .get_mut(index)
+            .map(|prev| core::mem::replace(prev, interval))
+            .ok_or(Error::EdgeNotFound)
+    }
+    /// removes the edge with the given index, returning it alongside its interval
+    pub fn remove_edge(&mut self, index: &EdgeId<Ix>) -> Result<TimedEdge<E, K, Ix, T, S>> {
+        let edge = self.graph.remove_edge(index)?;
+        let interval = self.intervals.remove(index).ok_or(Error::EdgeNotFound)?;
+        Ok((edge, interval))
+    }
+    /// removes the node with the given index alongside each of the edges containing it
+    pub fn remove_node(&mut self, index: &VertexId<Ix>) -> Result<Node<N, Ix>> {
+        let node = self.graph.remove_node(index)?;
+        let graph = &self.graph;
+        self.intervals.retain(|id, _| graph.contains_edge(id));
+        Ok(node)
+    }
+    /// returns the interval spanning the activity of every edge within the graph, or
+    /// [`None`] if the graph has no edges
+    pub fn span(&self) -> Option<Interval<T>> {
+        let start = self.intervals.values().map(|i| *i.start()).min()?;
+        let end = self.intervals.values().map(|i| *i.end()).max()?;
+        Some(Interval::new(start, end))
+    }
+    /// returns an iterator over the edges that are active at the given time
+    pub fn active_edges(&self, time: T) -> impl Iterator<Item = &EdgeId<Ix>> {
+        self.intervals
+            .iter()
+            .filter(move |(_, interval)| interval.contains(&time))
+            .map(|(id, _)| id)
+    }
+    /// returns a [`TemporalView`] of the graph at the given instant
+    pub fn snapshot(&self, time: T) -> TemporalView<'_, N, E, A, T, S> {
+        TemporalView::new(self, Interval::instant(time))
+    }
+    /// returns a [`TemporalView`] of the edges active at any point between the given bounds
+    pub fn window(&self, start: T, end: T) -> TemporalView<'_, N, E, A, T, S> {
+        TemporalView::new(self, Interval::new(start, end))
+    }
+    /// returns an iterator over consecutive, half-open windows `[start, start + width)`, the
+    /// first of which starts with the earliest activity of the graph; each window is offset
+    /// from the last by the given `step`, so windows whose `step` equals their `width` tile
+    /// the activity without sharing any point in time. The iterator ends once the windows pass
+    /// the latest activity of the graph or the bounds of the next window can no longer be
+    /// represented by `T`.
+    pub fn sliding_windows(
+        &self,
+        width: T,
+        step: T,
+    ) -> impl Iterator<Item = TemporalView<'_, N, E, A, T, S>>
+    where
+        T: num_traits::CheckedAdd,
+    {
+        let span = self.span();
+        let mut next = span.map(|span| *span.start());
+        core::iter::from_fn(move || {
+            let (span, start) = (span?, next?);
+            if start > *span.end() {
+                return None;
+            }
+            let end = start.checked_add(&width)?;
+            // stop once the windows fail to progress
+            next = start.checked_add(&step).filter(|after| *after > start);
+            Some(TemporalView::half_open(self, Interval::new(start, end)))
+        })
+    }
+}
+
+impl<N, E, A, T, S> Clone for TemporalHyperMap<N, E, A, T, S>
+where
+    A: GraphProps,
+    A::Ix: Clone,
+    S: BuildHasher + Clone,
+    T: Clone,
+    HyperMap<N, E, A, S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            intervals: self.intervals.clone(),
+        }
+    }
+}
+
+impl<N, E, A, T, S> Default for TemporalHyperMap<N, E, A, T, S>
+where
+    A: GraphProps,
+    S: BuildHasher + Default,
+    A::Ix: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, A, T, S> core::fmt::Debug for TemporalHyperMap<N, E, A, T, S>
+where
+    A: GraphProps,
+    E: core::fmt::Debug,
+    N: core::fmt::Debug,
+    T: core::fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TemporalHyperMap")
+            .field("graph", &self.graph)
+            .field("intervals", &self.intervals)
+            .finish()
+    }
+}
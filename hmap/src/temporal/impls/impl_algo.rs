@@ -0,0 +1,26 @@
+/*
+    appellation: impl_algo <module>
+    authors: @FL03
+*/
+use crate::temporal::TemporalHyperMap;
+use core::hash::BuildHasher;
+use rshyper_algo::{TemporalBreadthFirst, TemporalDijkstra};
+use rshyper_core::{GraphProps, HyperIndex};
+
+/// implementations for the time-respecting algorithms on the temporal hypergraph
+impl<N, E, A, T, S> TemporalHyperMap<N, E, A, T, S>
+where
+    S: BuildHasher + Clone + Default,
+    A: GraphProps,
+    A::Ix: HyperIndex,
+    T: Copy + Ord + num_traits::Bounded,
+{
+    /// returns the [`TemporalBreadthFirst`] operator configured with the current hypergraph
+    pub fn temporal_bft(&self) -> TemporalBreadthFirst<'_, N, E, A, Self, S> {
+        TemporalBreadthFirst::new(self)
+    }
+    /// returns the [`TemporalDijkstra`] operator configured with the current hypergraph
+    pub fn temporal_dijkstra(&self) -> TemporalDijkstra<'_, N, E, A, Self, S> {
+        TemporalDijkstra::new(self)
+    }
+}
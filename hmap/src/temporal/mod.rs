@@ -0,0 +1,25 @@
+/*
+    appellation: temporal <module>
+    authors: @FL03
+*/
+//! this module implements the [`TemporalHyperMap`], a hypergraph whose edges are each active
+//! during some [`Interval`](rshyper_core::Interval) of time, alongside the [`TemporalView`]
+//! used to inspect the graph at a particular point, or window, in time.
+#[doc(inline)]
+pub use self::{graph::*, view::*};
+
+mod graph;
+mod view;
+
+mod impls {
+    #[cfg(feature = "algo")]
+    pub mod impl_algo;
+    pub mod impl_hyper_graph;
+}
+
+pub(crate) mod prelude {
+    #[doc(inline)]
+    pub use super::graph::*;
+    #[doc(inline)]
+    pub use super::view::*;
+}
@@ -0,0 +1,169 @@
+/*
+    appellation: view <module>
+    authors: @FL03
+*/
+use super::TemporalHyperMap;
+use crate::{EdgeMap, HyperMap};
+use core::hash::BuildHasher;
+use rshyper_core::attrs::GraphProps;
+use rshyper_core::edge::HashEdge;
+use rshyper_core::idx::{EdgeId, HyperIndex, VertexId};
+use rshyper_core::{GraphType, Interval};
+
+/// The [`TemporalView`] is a read-only view of a [`TemporalHyperMap`] restricted to the edges
+/// that are active at some point during its window; a _snapshot_ is simply a view whose
+/// window is an instant. The window is closed unless the view was created as
+/// [`half_open`](TemporalView::half_open), in which case its end is excluded.
+pub struct TemporalView<'a, N, E, A, T, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+{
+    /// the temporal hypergraph being viewed
+    pub(crate) graph: &'a TemporalHyperMap<N, E, A, T, S>,
+    /// the span of time covered by the view
+    pub(crate) window: Interval<T>,
+    /// true if the end of the window is excluded from the view
+    pub(crate) half_open: bool,
+}
+
+impl<'a, N, E, A, K, Ix, T, S> TemporalView<'a, N, E, A, T, S>
+where
+    A: GraphProps<Ix = Ix, Kind = K>,
+    S: BuildHasher,
+    K: GraphType,
+    Ix: HyperIndex,
+    T: Copy + Ord,
+{
+    /// returns a new view of the graph covering the given (closed) window
+    pub const fn new(graph: &'a TemporalHyperMap<N, E, A, T, S>, window: Interval<T>) -> Self {
+        Self {
+            graph,
+            window,
+            half_open: false,
+        }
+    }
+    /// returns a new view of the graph covering the given window, excluding its end
+    pub const fn half_open(
+        graph: &'a TemporalHyperMap<N, E, A, T, S>,
+        window: Interval<T>,
+    ) -> Self {
+        Self {
+            graph,
+            window,
+            half_open: true,
+        }
+    }
+    /// returns an immutable reference to the temporal hypergraph being viewed
+    pub const fn graph(&self) -> &'a TemporalHyperMap<N, E, A, T, S> {
+        self.graph
+    }
+    /// returns the window of time covered by the view
+    pub const fn window(&self) -> &Interval<T> {
+        &self.window
+    }
+    /// returns true if the end of the window is excluded from the view
+    pub const fn is_half_open(&self) -> bool {
+        self.half_open
+    }
+    /// returns true if the given interval shares at least one point in time with the window
+    pub fn overlaps(&self, interval: &Interval<T>) -> bool {
+        if self.half_open {
+            interval.start() < self.window.end() && self.window.start() <= interval.end()
+        } else {
+            interval.overlaps(&self.window)
+        }
+    }
+    /// returns true if the edge with the given index is active during the window
+    pub fn is_active(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph
+            .intervals()
+            .get(index)
+            .is_some_and(|interval| self.overlaps(interval))
+    }
+    /// returns true if the view contains an edge with the given index
+    pub fn contains_edge(&self, index: &EdgeId<Ix>) -> bool {
+        self.graph.graph().contains_edge(index) && self.is_active(index)
+    }
+    /// returns an iterator over the edges that are active during the window
+    pub fn edges(&self) -> impl Iterator<Item = (&'a EdgeId<Ix>, &'a HashEdge<E, K, Ix, S>)> {
+        let view = self.clone();
+        let intervals = self.graph.intervals();
+        self.graph
+            .graph()
+            .edges()
+            .iter()
+            .filter(move |(id, _)| intervals.get(*id).is_some_and(|i| view.overlaps(i)))
+    }
+    /// returns an iterator over the indices of the edges that are active during the window
+    pub fn edge_keys(&self) -> impl Iterator<Item = &'a EdgeId<Ix>> {
+        self.edges().map(|(id, _)| id)
+    }
+    /// returns an iterator over the active edges containing the given vertex
+    pub fn find_edges_with_node(
+        &self,
+        index: &VertexId<Ix>,
+    ) -> impl Iterator<Item = &'a EdgeId<Ix>> {
+        let index = *index;
+        self.edges()
+            .filter(move |(_, edge)| edge.contains(&index))
+            .map(|(id, _)| id)
+    }
+    /// returns the number of edges that are active during the window
+    pub fn size(&self) -> usize {
+        self.edges().count()
+    }
+    /// materializes the view as a standalone [`HyperMap`] containing every node of the graph
+    /// alongside the edges that are active during the window; the indices of the components
+    /// are preserved.
+    pub fn to_hyper_map(&self) -> HyperMap<N, E, A, S>
+    where
+        E: Clone,
+        N: Clone,
+        K: Clone,
+        S: Clone + Default,
+    {
+        let source = self.graph.graph();
+        let hasher = source.edges().hasher().clone();
+        let mut edges = EdgeMap::with_hasher(hasher.clone());
+        edges.extend(self.edges().map(|(id, edge)| (*id, edge.clone())));
+        let mut history = source.history().clone();
+        history.retain_edges(|id| edges.contains_key(id));
+        let mut graph = HyperMap::with_hasher(hasher);
+        graph
+            .set_nodes(source.nodes().clone())
+            .set_edges(edges)
+            .set_history(history)
+            .reindex();
+        graph
+    }
+}
+
+impl<N, E, A, T, S> Clone for TemporalView<'_, N, E, A, T, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph,
+            window: self.window.clone(),
+            half_open: self.half_open,
+        }
+    }
+}
+
+impl<N, E, A, T, S> core::fmt::Debug for TemporalView<'_, N, E, A, T, S>
+where
+    A: GraphProps,
+    S: BuildHasher,
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TemporalView")
+            .field("window", &self.window)
+            .field("half_open", &self.half_open)
+            .finish()
+    }
+}
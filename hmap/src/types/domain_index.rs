@@ -0,0 +1,179 @@
+/*
+    appellation: domain_index <module>
+    authors: @FL03
+*/
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{HashMap, HashSet};
+use rshyper_core::idx::{EdgeId, RawIndex, VertexId};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The [`DomainIndex`] associates the _hash_ of an edge's domain with the indices of the edges
+/// composed of that exact set of vertices, enabling the [`HyperMap`](crate::HyperMap) to look
+/// up an edge by its vertices in (amortized) constant time.
+///
+/// The hash of a domain is computed by combining the hashes of its vertices using a
+/// commutative operation, ensuring that it is independent of the order in which the vertices
+/// are visited. Since different domains may share the same hash, each entry retains a bucket
+/// of candidates that must be verified against the actual domains of the graph.
+///
+/// Whenever the domain of a single edge is exposed mutably, the edge is
+/// [released](DomainIndex::release) from the buckets and held as _pending_ until it is
+/// [restored](DomainIndex::restore) using its current domain; lookups verify the pending edges
+/// directly. The index is only considered _stale_ once the edges of the graph are exposed
+/// wholesale, in which case it is never consulted until it is [rebuilt](DomainIndex::rebuild).
+#[derive(Clone, Debug, Default)]
+pub struct DomainIndex<Ix>
+where
+    Ix: RawIndex,
+{
+    /// maps the hash of a domain onto the edges composed of it
+    pub(crate) buckets: HashMap<u64, Vec<EdgeId<Ix>>>,
+    /// maps each edge onto the hash of its domain
+    pub(crate) hashes: HashMap<EdgeId<Ix>, u64>,
+    /// the edges whose domains may have changed since they were recorded
+    pub(crate) pending: HashSet<EdgeId<Ix>>,
+    /// whether the index is out-of-sync with the graph
+    pub(crate) stale: bool,
+}
+
+impl<Ix> DomainIndex<Ix>
+where
+    Ix: RawIndex,
+{
+    /// returns true if the index is out-of-sync with the graph and should not be used
+    pub const fn is_stale(&self) -> bool {
+        self.stale
+    }
+    /// mark the index as being out-of-sync with the graph
+    pub const fn invalidate(&mut self) {
+        self.stale = true;
+    }
+}
+
+impl<Ix> DomainIndex<Ix>
+where
+    Ix: RawIndex + Eq + Hash,
+{
+    /// returns a new, empty index
+    pub fn new() -> Self {
+        Self {
+            buckets: HashMap::default(),
+            hashes: HashMap::default(),
+            pending: HashSet::default(),
+            stale: false,
+        }
+    }
+    /// returns the number of edges recorded by the index
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+    /// returns true if the index contains no edges
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+    /// computes the order-independent hash of a domain composed of the given vertices; the
+    /// vertices are assumed to be unique.
+    pub fn hash_domain<'a, I>(&self, vertices: I) -> u64
+    where
+        I: IntoIterator<Item = &'a VertexId<Ix>>,
+    {
+        vertices.into_iter().fold(0u64, |acc, v| {
+            acc.wrapping_add(mix(self.buckets.hasher().hash_one(v)))
+        })
+    }
+    /// returns the edges whose domains may have changed since they were recorded; these are
+    /// excluded from the buckets of the index until they are restored.
+    pub const fn pending(&self) -> &HashSet<EdgeId<Ix>> {
+        &self.pending
+    }
+    /// returns the candidates sharing the hash of the given domain
+    pub fn candidates(&self, hash: u64) -> &[EdgeId<Ix>] {
+        self.buckets
+            .get(&hash)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+    /// record the edge with the given index and domain
+    pub fn insert<'a, I>(&mut self, id: EdgeId<Ix>, domain: I)
+    where
+        I: IntoIterator<Item = &'a VertexId<Ix>>,
+        Ix: Clone,
+    {
+        let hash = self.hash_domain(domain);
+        self.pending.remove(&id);
+        if let Some(prev) = self.hashes.insert(id.clone(), hash) {
+            self.detach(&id, prev);
+        }
+        self.buckets.entry(hash).or_default().push(id);
+    }
+    /// remove the edge with the given index from the index
+    pub fn remove(&mut self, id: &EdgeId<Ix>) {
+        if let Some(hash) = self.hashes.remove(id) {
+            self.detach(id, hash);
+        }
+        self.pending.remove(id);
+    }
+    /// removes the edge with the given index from its bucket, holding it as pending until it
+    /// is [restored](DomainIndex::restore); this is used whenever the domain of the edge is
+    /// about to be exposed mutably.
+    pub fn release(&mut self, id: &EdgeId<Ix>) {
+        if let Some((id, hash)) = self.hashes.remove_entry(id) {
+            self.detach(&id, hash);
+            self.pending.insert(id);
+        }
+    }
+    /// records each of the pending edges once again, using the given function to fetch their
+    /// current domain; edges without a domain are assumed to have been removed.
+    pub fn restore<'a, F, D>(&mut self, mut domain: F)
+    where
+        F: FnMut(&EdgeId<Ix>) -> Option<D>,
+        D: IntoIterator<Item = &'a VertexId<Ix>>,
+        Ix: Clone,
+    {
+        for id in core::mem::take(&mut self.pending) {
+            if let Some(vertices) = domain(&id) {
+                self.insert(id, vertices);
+            }
+        }
+    }
+    /// remove every entry from the index, marking it as up-to-date
+    pub fn clear(&mut self) {
+        self.buckets.clear();
+        self.hashes.clear();
+        self.pending.clear();
+        self.stale = false;
+    }
+    /// rebuild the index from the given edges
+    pub fn rebuild<'a, I, D>(&mut self, edges: I)
+    where
+        I: IntoIterator<Item = (&'a EdgeId<Ix>, D)>,
+        D: IntoIterator<Item = &'a VertexId<Ix>>,
+        Ix: Clone,
+    {
+        self.clear();
+        for (id, domain) in edges {
+            self.insert(id.clone(), domain);
+        }
+    }
+    /// removes the edge from the bucket of the given hash
+    fn detach(&mut self, id: &EdgeId<Ix>, hash: u64) {
+        if let Some(bucket) = self.buckets.get_mut(&hash) {
+            bucket.retain(|e| e != id);
+            if bucket.is_empty() {
+                self.buckets.remove(&hash);
+            }
+        }
+    }
+}
+
+/// scrambles the bits of the given hash (`splitmix64` finalizer) so that the wrapping sum of
+/// several hashes remains well-distributed.
+const fn mix(mut x: u64) -> u64 {
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
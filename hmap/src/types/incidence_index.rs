@@ -0,0 +1,164 @@
+/*
+    appellation: incidence_index <module>
+    authors: @FL03
+*/
+use core::hash::Hash;
+use hashbrown::{HashMap, HashSet};
+use rshyper_core::idx::{EdgeId, RawIndex, VertexId};
+
+/// a type alias for the set of edges recorded by the [`IncidenceIndex`] for a single vertex
+pub type Incidence<Ix> = HashSet<EdgeId<Ix>>;
+
+/// The [`IncidenceIndex`] associates each vertex with the set of edges whose domain contains
+/// it, allowing the [`HyperMap`](crate::HyperMap) to answer containment queries (i.e. subsets,
+/// supersets, and intersections) by only visiting the edges that are incident to the vertices
+/// of interest rather than scanning the entire graph.
+///
+/// Vertices that are not contained by any edge are never recorded by the index.
+///
+/// Like the [`DomainIndex`](crate::DomainIndex), an edge whose domain is exposed mutably is
+/// [released](IncidenceIndex::release) from the index and held as _pending_ until it is
+/// [restored](IncidenceIndex::restore), with queries verifying the pending edges directly; the
+/// index is only considered _stale_ once the edges of the graph are exposed wholesale, in
+/// which case it is never consulted until it is [rebuilt](IncidenceIndex::rebuild).
+#[derive(Clone, Debug, Default)]
+pub struct IncidenceIndex<Ix>
+where
+    Ix: RawIndex,
+{
+    /// maps each vertex onto the edges containing it
+    pub(crate) incidence: HashMap<VertexId<Ix>, Incidence<Ix>>,
+    /// the edges whose domains may have changed since they were recorded
+    pub(crate) pending: Incidence<Ix>,
+    /// whether the index is out-of-sync with the graph
+    pub(crate) stale: bool,
+}
+
+impl<Ix> IncidenceIndex<Ix>
+where
+    Ix: RawIndex,
+{
+    /// returns true if the index is out-of-sync with the graph and should not be used
+    pub const fn is_stale(&self) -> bool {
+        self.stale
+    }
+    /// mark the index as being out-of-sync with the graph
+    pub const fn invalidate(&mut self) {
+        self.stale = true;
+    }
+}
+
+impl<Ix> IncidenceIndex<Ix>
+where
+    Ix: RawIndex + Eq + Hash,
+{
+    /// returns a new, empty index
+    pub fn new() -> Self {
+        Self {
+            incidence: HashMap::default(),
+            pending: HashSet::default(),
+            stale: false,
+        }
+    }
+    /// returns the number of vertices recorded by the index
+    pub fn len(&self) -> usize {
+        self.incidence.len()
+    }
+    /// returns true if the index contains no vertices
+    pub fn is_empty(&self) -> bool {
+        self.incidence.is_empty()
+    }
+    /// returns the set of edges containing the given vertex, if any
+    pub fn get(&self, vertex: &VertexId<Ix>) -> Option<&Incidence<Ix>> {
+        self.incidence.get(vertex)
+    }
+    /// returns the edges whose domains may have changed since they were recorded; these are
+    /// excluded from the incidence of every vertex until they are restored.
+    pub const fn pending(&self) -> &Incidence<Ix> {
+        &self.pending
+    }
+    /// returns the number of edges containing the given vertex
+    pub fn degree(&self, vertex: &VertexId<Ix>) -> usize {
+        self.get(vertex).map(HashSet::len).unwrap_or_default()
+    }
+    /// record the edge with the given index as being incident to each vertex of its domain
+    pub fn insert<'a, I>(&mut self, id: EdgeId<Ix>, domain: I)
+    where
+        I: IntoIterator<Item = &'a VertexId<Ix>>,
+        Ix: Clone,
+    {
+        self.pending.remove(&id);
+        for vertex in domain {
+            self.incidence
+                .entry(vertex.clone())
+                .or_default()
+                .insert(id.clone());
+        }
+    }
+    /// remove the edge with the given index from the incidence of each vertex of its domain
+    pub fn remove<'a, I>(&mut self, id: &EdgeId<Ix>, domain: I)
+    where
+        I: IntoIterator<Item = &'a VertexId<Ix>>,
+    {
+        for vertex in domain {
+            if let Some(edges) = self.incidence.get_mut(vertex) {
+                edges.remove(id);
+                if edges.is_empty() {
+                    self.incidence.remove(vertex);
+                }
+            }
+        }
+        self.pending.remove(id);
+    }
+    /// removes the edge with the given index from the incidence of each vertex of its
+    /// (recorded) domain, holding it as pending until it is
+    /// [restored](IncidenceIndex::restore); this is used whenever the domain of the edge is
+    /// about to be exposed mutably.
+    pub fn release<'a, I>(&mut self, id: &EdgeId<Ix>, domain: I)
+    where
+        I: IntoIterator<Item = &'a VertexId<Ix>>,
+    {
+        for vertex in domain {
+            if let Some(edges) = self.incidence.get_mut(vertex) {
+                if let Some(id) = edges.take(id) {
+                    self.pending.insert(id);
+                }
+                if edges.is_empty() {
+                    self.incidence.remove(vertex);
+                }
+            }
+        }
+    }
+    /// records each of the pending edges once again, using the given function to fetch their
+    /// current domain; edges without a domain are assumed to have been removed.
+    pub fn restore<'a, F, D>(&mut self, mut domain: F)
+    where
+        F: FnMut(&EdgeId<Ix>) -> Option<D>,
+        D: IntoIterator<Item = &'a VertexId<Ix>>,
+        Ix: Clone,
+    {
+        for id in core::mem::take(&mut self.pending) {
+            if let Some(vertices) = domain(&id) {
+                self.insert(id, vertices);
+            }
+        }
+    }
+    /// remove every entry from the index, marking it as up-to-date
+    pub fn clear(&mut self) {
+        self.incidence.clear();
+        self.pending.clear();
+        self.stale = false;
+    }
+    /// rebuild the index from the given edges
+    pub fn rebuild<'a, I, D>(&mut self, edges: I)
+    where
+        I: IntoIterator<Item = (&'a EdgeId<Ix>, D)>,
+        D: IntoIterator<Item = &'a VertexId<Ix>>,
+        Ix: Clone,
+    {
+        self.clear();
+        for (id, domain) in edges {
+            self.insert(id.clone(), domain);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+/*
+    appellation: index_remap <module>
+    authors: @FL03
+*/
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::idx::{EdgeId, RawIndex, VertexId};
+
+/// The [`IndexRemap`] records the mapping of each of the previous indices of a
+/// [`HyperMap`](crate::HyperMap) onto the new indices assigned to them by a
+/// [compaction](crate::HyperMap::compact).
+#[derive(Clone, Debug)]
+pub struct IndexRemap<Ix, S = DefaultHashBuilder>
+where
+    Ix: RawIndex,
+{
+    /// maps each of the previous vertex indices onto their new values
+    pub(crate) nodes: HashMap<VertexId<Ix>, VertexId<Ix>, S>,
+    /// maps each of the previous edge indices onto their new values
+    pub(crate) edges: HashMap<EdgeId<Ix>, EdgeId<Ix>, S>,
+}
+
+impl<Ix, S> IndexRemap<Ix, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    /// returns a new, empty mapping
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self {
+            nodes: HashMap::default(),
+            edges: HashMap::default(),
+        }
+    }
+    /// returns an immutable reference to the mapping of the vertex indices
+    pub const fn nodes(&self) -> &HashMap<VertexId<Ix>, VertexId<Ix>, S> {
+        &self.nodes
+    }
+    /// returns an immutable reference to the mapping of the edge indices
+    pub const fn edges(&self) -> &HashMap<EdgeId<Ix>, EdgeId<Ix>, S> {
+        &self.edges
+    }
+    /// returns true if the mapping contains no indices
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.edges.is_empty()
+    }
+}
+
+impl<Ix, S> IndexRemap<Ix, S>
+where
+    Ix: RawIndex + Eq + Hash,
+    S: BuildHasher,
+{
+    /// returns the new index of the vertex previously identified by the given index
+    pub fn get_node(&self, index: &VertexId<Ix>) -> Option<&VertexId<Ix>> {
+        self.nodes.get(index)
+    }
+    /// returns the new index of the edge previously identified by the given index
+    pub fn get_edge(&self, index: &EdgeId<Ix>) -> Option<&EdgeId<Ix>> {
+        self.edges.get(index)
+    }
+    /// returns true if none of the indices were changed by the mapping
+    pub fn is_identity(&self) -> bool {
+        self.nodes.iter().all(|(prev, next)| prev == next)
+            && self.edges.iter().all(|(prev, next)| prev == next)
+    }
+}
+
+impl<Ix, S> Default for IndexRemap<Ix, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,219 @@
+/*
+    appellation: interner <module>
+    authors: @FL03
+*/
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{DefaultHashBuilder, HashMap};
+use rshyper_core::idx::{RawIndex, Udx, VertexId};
+
+/// The [`Interner`] is a bidirectional index associating user-defined keys, or _labels_, with
+/// the indices of the vertices they name. Each label names at most one vertex, and each vertex
+/// is named by at most one label. The index is maintained by the [`HyperMap`](crate::HyperMap)
+/// whenever its vertices are [named](crate::HyperMap::add_named_node).
+pub struct Interner<K, Ix = Udx, S = DefaultHashBuilder>
+where
+    Ix: RawIndex,
+{
+    /// the index of the vertex named by each label
+    pub(crate) ids: HashMap<K, VertexId<Ix>, S>,
+    /// the label naming each vertex
+    pub(crate) labels: HashMap<VertexId<Ix>, K, S>,
+}
+
+impl<K, Ix, S> Interner<K, Ix, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher,
+{
+    /// returns a new, empty instance of the [`Interner`]
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self {
+            ids: HashMap::default(),
+            labels: HashMap::default(),
+        }
+    }
+    /// returns an immutable reference to the map of labels to vertices
+    pub const fn ids(&self) -> &HashMap<K, VertexId<Ix>, S> {
+        &self.ids
+    }
+    /// returns true if the interner contains no labels
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+    /// returns the number of labels within the interner
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+    /// returns an iterator over the labels and the vertices they name
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &VertexId<Ix>)> {
+        self.ids.iter()
+    }
+    /// remove every label from the interner
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.labels.clear();
+    }
+    /// replaces the index of every named vertex using the given mapping; the mapping is
+    /// expected to be injective over the named vertices.
+    pub(crate) fn remap<F>(&mut self, mut f: F)
+    where
+        Ix: Eq + Hash,
+        S: Default,
+        F: FnMut(&VertexId<Ix>) -> VertexId<Ix>,
+    {
+        for vertex in self.ids.values_mut() {
+            *vertex = f(vertex);
+        }
+        self.labels = self
+            .labels
+            .drain()
+            .map(|(vertex, label)| (f(&vertex), label))
+            .collect();
+    }
+}
+
+impl<K, Ix, S> Interner<K, Ix, S>
+where
+    K: Eq + Hash,
+    Ix: RawIndex + Eq + Hash,
+    S: BuildHasher,
+{
+    /// returns true if the interner contains the given label
+    pub fn contains<Q>(&self, label: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.ids.contains_key(label)
+    }
+    /// returns the index of the vertex named by the given label, if any
+    pub fn get<Q>(&self, label: &Q) -> Option<&VertexId<Ix>>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.ids.get(label)
+    }
+    /// returns the label naming the given vertex, if any
+    pub fn label_of(&self, vertex: &VertexId<Ix>) -> Option<&K> {
+        self.labels.get(vertex)
+    }
+    /// associate the given label with the given vertex, replacing any previous associations
+    /// of either the label or the vertex.
+    pub fn insert(&mut self, label: K, vertex: VertexId<Ix>)
+    where
+        K: Clone,
+        Ix: Copy,
+    {
+        self.remove_vertex(&vertex);
+        self.remove(&label);
+        self.labels.insert(vertex, label.clone());
+        self.ids.insert(label, vertex);
+    }
+    /// remove the given label from the interner, returning the vertex it named
+    pub fn remove<Q>(&mut self, label: &Q) -> Option<VertexId<Ix>>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let vertex = self.ids.remove(label)?;
+        self.labels.remove(&vertex);
+        Some(vertex)
+    }
+    /// remove the label naming the given vertex, returning it
+    pub fn remove_vertex(&mut self, vertex: &VertexId<Ix>) -> Option<K> {
+        let label = self.labels.remove(vertex)?;
+        self.ids.remove(&label);
+        Some(label)
+    }
+    /// consumes the interner to create another whose vertex indices are of type `I2`,
+    /// converting each index using the given function
+    pub(crate) fn try_map_index<I2, F, Err>(self, mut f: F) -> Result<Interner<K, I2, S>, Err>
+    where
+        I2: RawIndex + Copy + Eq + Hash,
+        K: Clone,
+        S: Default,
+        F: FnMut(VertexId<Ix>) -> Result<VertexId<I2>, Err>,
+    {
+        let ids = self
+            .ids
+            .into_iter()
+            .map(|(label, vertex)| f(vertex).map(|vertex| (label, vertex)))
+            .collect::<Result<HashMap<_, _, S>, Err>>()?;
+        let labels = ids
+            .iter()
+            .map(|(label, vertex)| (*vertex, label.clone()))
+            .collect();
+        Ok(Interner { ids, labels })
+    }
+    /// retains only the labels whose vertices satisfy the given predicate
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&VertexId<Ix>) -> bool,
+    {
+        let (ids, labels) = (&mut self.ids, &mut self.labels);
+        labels.retain(|vertex, label| {
+            let keep = f(vertex);
+            if !keep {
+                ids.remove(label);
+            }
+            keep
+        });
+    }
+}
+
+impl<K, Ix, S> Clone for Interner<K, Ix, S>
+where
+    K: Clone,
+    Ix: RawIndex + Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            labels: self.labels.clone(),
+        }
+    }
+}
+
+impl<K, Ix, S> Default for Interner<K, Ix, S>
+where
+    Ix: RawIndex,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, Ix, S> core::fmt::Debug for Interner<K, Ix, S>
+where
+    K: core::fmt::Debug,
+    Ix: RawIndex,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.ids.iter()).finish()
+    }
+}
+
+impl<K, Ix, S> FromIterator<(K, VertexId<Ix>)> for Interner<K, Ix, S>
+where
+    K: Clone + Eq + Hash,
+    Ix: RawIndex + Copy + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, VertexId<Ix>)>,
+    {
+        let mut interner = Self::new();
+        for (label, vertex) in iter {
+            interner.insert(label, vertex);
+        }
+        interner
+    }
+}
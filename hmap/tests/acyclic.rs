@@ -0,0 +1,93 @@
+/*
+    Appellation: acyclic <module>
+    Contrib: @FL03
+*/
+use rshyper_algo::Error;
+use rshyper_core::idx::VertexId;
+use rshyper_core::{EdgeId, Result};
+use rshyper_hmap::HyperMap;
+
+/// a test case composed of the order, edges and expected (alpha, beta, gamma, berge)-acyclicity
+type Case<'a> = (usize, &'a [&'a [usize]], [bool; 4]);
+
+/// build an undirected hypergraph from the given edges over vertices `0..order`
+fn from_edges(order: usize, edges: &[&[usize]]) -> HyperMap<usize, usize> {
+    let mut graph = HyperMap::undirected();
+    let v = (0..order)
+        .map(|_| graph.add_vertex().unwrap())
+        .collect::<Vec<_>>();
+    for edge in edges {
+        graph.add_link(edge.iter().map(|&i| v[i])).unwrap();
+    }
+    graph
+}
+
+#[test]
+fn test_acyclicity_hierarchy() {
+    let cases: [Case; 5] = [
+        // a tree of edges sharing single vertices
+        (5, &[&[0, 1, 2], &[2, 3], &[3, 4]], [true; 4]),
+        // two edges sharing two vertices form a Berge-cycle
+        (3, &[&[0, 1, 2], &[0, 1]], [true, true, true, false]),
+        // a gamma-cycle that is still beta-acyclic
+        (
+            3,
+            &[&[0, 1, 2], &[0, 1], &[1, 2]],
+            [true, true, false, false],
+        ),
+        // a cycle covered by a single edge is only alpha-acyclic
+        (
+            3,
+            &[&[0, 1, 2], &[0, 1], &[1, 2], &[0, 2]],
+            [true, false, false, false],
+        ),
+        // a triangle is cyclic in every sense
+        (3, &[&[0, 1], &[1, 2], &[0, 2]], [false; 4]),
+    ];
+    for (order, edges, expected) in cases {
+        let graph = from_edges(order, edges);
+        let acyclicity = graph.acyclicity();
+        let res = [
+            acyclicity.is_alpha_acyclic(),
+            acyclicity.is_beta_acyclic(),
+            acyclicity.is_gamma_acyclic(),
+            acyclicity.is_berge_acyclic(),
+        ];
+        assert_eq!(res, expected, "unexpected acyclicity for {edges:?}");
+    }
+}
+
+#[test]
+fn test_join_tree() -> Result<()> {
+    let graph = from_edges(6, &[&[0, 1, 2], &[1, 2, 3], &[2, 3, 4], &[3, 5], &[1, 2]]);
+    let tree = graph.acyclicity().join_tree()?;
+    assert_eq!(tree.len(), 5);
+    assert!(tree.root().is_some());
+    // every edge except the root has a parent, and precedes it within the order
+    let order = tree.order();
+    for (i, e) in order.iter().enumerate() {
+        match tree.parent(e) {
+            Some(p) => assert!(order[i + 1..].contains(p)),
+            None => assert_eq!(Some(e), tree.root()),
+        }
+    }
+    // the edges containing each vertex form a connected subtree
+    for v in (0..6).map(VertexId::from) {
+        let members = graph
+            .find_edges_with_node(&v)
+            .copied()
+            .collect::<Vec<EdgeId>>();
+        let tops = members
+            .iter()
+            .filter(|e| tree.parent(e).is_none_or(|p| !members.contains(p)))
+            .count();
+        assert_eq!(tops, 1, "the edges containing {v} are disconnected");
+    }
+    // cyclic hypergraphs have no join tree
+    let cyclic = from_edges(3, &[&[0, 1], &[1, 2], &[0, 2]]);
+    assert!(matches!(
+        cyclic.acyclicity().join_tree(),
+        Err(Error::NotAcyclic)
+    ));
+    Ok(())
+}
@@ -0,0 +1,80 @@
+/*
+    Appellation: builder <module>
+    Contrib: @FL03
+*/
+use rshyper_core::Result;
+use rshyper_hmap::{HyperMap, HyperMapBuilder};
+
+#[cfg(feature = "rayon")]
+/// generates a collection of records whose vertices are identified by string keys
+fn sample_records(n: usize) -> Vec<(Vec<String>, usize)> {
+    (0..n)
+        .map(|i| {
+            let vertices = (0..(i % 4) + 1)
+                .map(|j| format!("v{}", (i * 7 + j * 3) % 97))
+                .collect();
+            (vertices, i)
+        })
+        .collect()
+}
+
+#[test]
+fn test_builder() -> Result<()> {
+    // build a graph from a small set of records
+    let records = vec![
+        (vec!["a", "b", "c"], 1),
+        (vec!["c", "d", "d"], 2),
+        (vec!["e"], 3),
+    ];
+    let (graph, keys) = HyperMap::<(), usize>::builder().build(records)?;
+    assert_eq!(graph.order(), 5);
+    assert_eq!(graph.size(), 3);
+    // vertices are assigned ids by their first appearance
+    assert_eq!(*keys["a"], 0);
+    assert_eq!(*keys["d"], 3);
+    assert_eq!(*keys["e"], 4);
+    // edges are assigned ids by the position of their record
+    let e1 = graph
+        .iter_edge_keys()
+        .copied()
+        .find(|id| **id == 1)
+        .unwrap();
+    assert_eq!(graph.get_edge_order(&e1)?, 2);
+    assert_eq!(graph.get_edge_weight(&e1)?.get(), &2);
+    // the history reflects the dense ids
+    assert_eq!(graph.history().nodes().len(), 5);
+    assert_eq!(**graph.position().edge(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_builder_empty_record() {
+    let records = vec![(vec![0usize, 1], 0), (vec![], 1)];
+    let res = HyperMapBuilder::<(), usize>::new().build(records);
+    assert!(res.is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_builder() -> Result<()> {
+    let records = sample_records(10_000);
+    // build the graph sequentially and in parallel
+    let (seq, seq_keys) = HyperMap::<(), usize>::builder().build(records.clone())?;
+    let (par, par_keys) = HyperMap::<(), usize>::builder().par_build(records)?;
+    assert_eq!(seq.order(), par.order());
+    assert_eq!(seq.size(), par.size());
+    assert_eq!(seq_keys, par_keys);
+    // verify the results are identical across thread counts
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .expect("failed to build the thread pool");
+    let (alt, _) =
+        pool.install(|| HyperMap::<(), usize>::builder().par_build(sample_records(10_000)))?;
+    for (id, edge) in seq.edges() {
+        assert_eq!(par.get_domain(id)?, edge.domain());
+        assert_eq!(alt.get_domain(id)?, edge.domain());
+        assert_eq!(par.get_edge_weight(id)?, edge.weight());
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+/*
+    Appellation: coloring <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::VertexId;
+use rshyper_hmap::HyperMap;
+
+/// build an undirected hypergraph from the given edges over vertices `0..order`
+fn from_edges(order: usize, edges: &[&[usize]]) -> HyperMap<usize, usize> {
+    let mut graph = HyperMap::undirected();
+    let v = (0..order)
+        .map(|_| graph.add_vertex().unwrap())
+        .collect::<Vec<_>>();
+    for edge in edges {
+        graph.add_link(edge.iter().map(|&i| v[i])).unwrap();
+    }
+    graph
+}
+
+/// converts the given raw indices into vertices
+fn vertices(set: &[usize]) -> Vec<VertexId> {
+    set.iter().copied().map(VertexId::from).collect()
+}
+
+#[test]
+fn test_weak_coloring() {
+    // a cycle of three edges may be weakly colored using two colors, with an isolated vertex
+    let graph = from_edges(7, &[&[0, 1, 2], &[2, 3, 4], &[4, 5, 0]]);
+    let coloring = graph.coloring();
+    for res in [coloring.greedy_weak(), coloring.dsatur_weak()] {
+        assert!(coloring.is_weak_coloring(&res));
+        assert!(!coloring.is_strong_coloring(&res));
+        assert_eq!(res.len(), 7);
+        assert_eq!(res.num_colors(), 2);
+        assert_eq!(res.classes().concat().len(), 7);
+    }
+}
+
+#[test]
+fn test_strong_coloring() {
+    // the vertices of an edge require distinct colors, while the rest may reuse them
+    let graph = from_edges(7, &[&[0, 1, 2], &[2, 3, 4], &[4, 5, 0]]);
+    let coloring = graph.coloring();
+    for res in [coloring.greedy_strong(), coloring.dsatur_strong()] {
+        assert!(coloring.is_strong_coloring(&res));
+        assert!(coloring.is_weak_coloring(&res));
+        assert_eq!(res.num_colors(), 3);
+        assert_eq!(res.get(&VertexId::from(6)), Some(0));
+    }
+    // an odd cycle of binary edges requires three colors in either sense
+    let graph = from_edges(5, &[&[0, 1], &[1, 2], &[2, 3], &[3, 4], &[0, 4]]);
+    let coloring = graph.coloring();
+    assert_eq!(coloring.dsatur_weak().num_colors(), 3);
+    assert_eq!(coloring.dsatur_strong().num_colors(), 3);
+    // the empty hypergraph requires no colors
+    let graph = from_edges(0, &[]);
+    let res = graph.coloring().dsatur_strong();
+    assert!(res.is_empty());
+    assert_eq!(res.num_colors(), 0);
+}
+
+#[test]
+fn test_independent_sets() {
+    let graph = from_edges(7, &[&[0, 1, 2], &[2, 3, 4], &[4, 5, 0]]);
+    let sets = graph.independent_sets();
+    // a weakly independent set contains no edge entirely
+    let maximal = sets.maximal_weak();
+    assert!(sets.is_weakly_independent(&maximal));
+    let maximum = sets.maximum_weak();
+    assert!(sets.is_weakly_independent(&maximum));
+    assert_eq!(maximum.len(), 5);
+    assert!(maximum.len() >= maximal.len());
+    // a strongly independent set contains, at most, a single vertex of each edge
+    let maximal = sets.maximal_strong();
+    assert!(sets.is_strongly_independent(&maximal));
+    let maximum = sets.maximum_strong();
+    assert!(sets.is_strongly_independent(&maximum));
+    assert_eq!(maximum, vertices(&[1, 3, 5, 6]));
+    // either notion rejects sets containing an entire edge
+    assert!(!sets.is_weakly_independent(&vertices(&[0, 1, 2])));
+    assert!(!sets.is_strongly_independent(&vertices(&[0, 1])));
+}
+
+#[test]
+fn test_maximum_strong_independent_set() {
+    // a star of paths whose ends are joined by a single edge
+    let graph = from_edges(
+        7,
+        &[
+            &[0, 1],
+            &[0, 2],
+            &[0, 3],
+            &[1, 4],
+            &[2, 5],
+            &[3, 6],
+            &[4, 5, 6],
+        ],
+    );
+    let sets = graph.independent_sets();
+    let maximum = sets.maximum_strong();
+    assert!(sets.is_strongly_independent(&maximum));
+    assert_eq!(maximum.len(), 3);
+    // the weak notion admits every vertex but those of a minimum transversal
+    let maximum = sets.maximum_weak();
+    assert!(sets.is_weakly_independent(&maximum));
+    assert_eq!(maximum.len(), 3);
+}
@@ -0,0 +1,148 @@
+/*
+    Appellation: community <module>
+    Contrib: @FL03
+*/
+use rshyper_algo::{LabelStream, ModularityKind};
+use rshyper_core::idx::VertexId;
+use rshyper_hmap::HyperMap;
+
+/// build an undirected hypergraph from the given edges over vertices `0..order`
+fn from_edges(order: usize, edges: &[&[usize]]) -> HyperMap<usize, usize> {
+    let mut graph = HyperMap::undirected();
+    let v = (0..order)
+        .map(|_| graph.add_vertex().unwrap())
+        .collect::<Vec<_>>();
+    for edge in edges {
+        graph.add_link(edge.iter().map(|&i| v[i])).unwrap();
+    }
+    graph
+}
+
+/// converts the given raw indices into vertices
+fn vertices(set: &[usize]) -> Vec<VertexId> {
+    set.iter().copied().map(VertexId::from).collect()
+}
+
+/// two dense clusters of four vertices joined by a single edge
+fn clusters() -> HyperMap<usize, usize> {
+    from_edges(
+        8,
+        &[
+            &[0, 1, 2],
+            &[1, 2, 3],
+            &[0, 2, 3],
+            &[0, 1, 3],
+            &[4, 5, 6],
+            &[5, 6, 7],
+            &[4, 6, 7],
+            &[4, 5, 7],
+            &[3, 4],
+        ],
+    )
+}
+
+#[test]
+fn test_modularity() {
+    let graph = clusters();
+    let modularity = graph.modularity();
+    assert_eq!(modularity.kind(), ModularityKind::Strict);
+    // a single community contains every edge, exactly as expected by the null model
+    let whole = modularity
+        .score(&[vertices(&[0, 1, 2, 3, 4, 5, 6, 7])])
+        .unwrap();
+    assert!(whole.abs() < 1e-9);
+    // splitting the clusters contains each edge but the bridge
+    let split = [vertices(&[0, 1, 2, 3]), vertices(&[4, 5, 6, 7])];
+    let res = modularity.evaluate(&split).unwrap();
+    assert_eq!(res.partition(), &split);
+    assert!(res.modularity() > 0.5);
+    let total = res.scores().iter().sum::<f64>();
+    assert!((total - res.modularity()).abs() < 1e-9);
+    // unlisted vertices are treated as singleton communities
+    let res = modularity.evaluate(&[vertices(&[0, 1, 2, 3])]).unwrap();
+    assert_eq!(res.len(), 5);
+    assert_eq!(res.community_of(&VertexId::from(5)), Some(2));
+    // the majority variant also counts the edges mostly contained by a community
+    let majority = graph.modularity().with_kind(ModularityKind::Majority);
+    let uneven = [vertices(&[0, 1, 2]), vertices(&[3, 4, 5, 6, 7])];
+    let res = majority.evaluate(&uneven).unwrap();
+    assert!(res.scores()[0] > modularity.evaluate(&uneven).unwrap().scores()[0]);
+    // unknown vertices are rejected
+    assert!(modularity.score(&[vertices(&[8])]).is_err());
+}
+
+#[test]
+fn test_louvain() {
+    let graph = clusters();
+    let expected = [vertices(&[0, 1, 2, 3]), vertices(&[4, 5, 6, 7])];
+    let modularity = graph.modularity();
+    let res = modularity.louvain();
+    assert_eq!(res.partition(), &expected);
+    assert!((res.modularity() - modularity.score(&expected).unwrap()).abs() < 1e-9);
+    // the majority variant expects far more edges of a large community, favoring pairs
+    let majority = graph.modularity().with_kind(ModularityKind::Majority);
+    let res = majority.louvain();
+    assert!(res.modularity() > majority.score(&expected).unwrap());
+    let total = res.scores().iter().sum::<f64>();
+    assert!((total - res.modularity()).abs() < 1e-9);
+    // isolated vertices remain in communities of their own
+    let graph = from_edges(3, &[&[0, 1]]);
+    let res = graph.modularity().louvain();
+    assert_eq!(res.partition(), &[vertices(&[0, 1]), vertices(&[2])]);
+    // the empty hypergraph has no communities
+    let graph = from_edges(0, &[]);
+    let res = graph.modularity().louvain();
+    assert!(res.is_empty());
+    assert_eq!(res.modularity(), 0.0);
+}
+
+#[test]
+fn test_label_propagation() {
+    // disconnected clusters never share a label
+    let graph = from_edges(7, &[&[0, 1, 2], &[1, 2], &[3, 4, 5], &[4, 5, 6], &[3, 6]]);
+    let res = graph.label_propagation().communities();
+    assert_eq!(
+        res.partition(),
+        &[vertices(&[0, 1, 2]), vertices(&[3, 4, 5, 6])]
+    );
+    let total = res.scores().iter().sum::<f64>();
+    assert!((total - res.modularity()).abs() < 1e-9);
+    // the communities of connected clusters partition the vertices
+    let graph = clusters();
+    let res = graph
+        .label_propagation()
+        .with_max_iterations(10)
+        .communities();
+    assert_eq!(res.partition().concat().len(), 8);
+    // isolated vertices remain in communities of their own
+    let graph = from_edges(3, &[&[0, 1]]);
+    let res = graph.label_propagation().communities();
+    assert_eq!(res.partition(), &[vertices(&[0, 1]), vertices(&[2])]);
+}
+
+#[test]
+fn test_label_stream() {
+    let mut stream = LabelStream::<usize>::new();
+    assert!(stream.insert_vertex(VertexId::from(6)));
+    assert!(!stream.insert_vertex(VertexId::from(6)));
+    for edge in [[0, 1, 2], [3, 4, 5], [0, 1, 2], [3, 4, 5]] {
+        stream.insert_edge(edge.map(VertexId::from));
+    }
+    assert_eq!((stream.order(), stream.size()), (7, 4));
+    let res = stream.communities();
+    assert_eq!(
+        res.partition(),
+        &[vertices(&[0, 1, 2]), vertices(&[3, 4, 5]), vertices(&[6])]
+    );
+    assert_eq!(stream.propagate(), 1);
+    // a newly observed vertex joins the community of its only neighbor
+    let graph = clusters();
+    let mut stream = graph.label_propagation().stream();
+    stream.insert_edge([VertexId::from(0), VertexId::from(8)]);
+    assert!(stream.contains(&VertexId::from(8)));
+    assert_eq!(
+        stream.label(&VertexId::from(8)),
+        stream.label(&VertexId::from(0))
+    );
+    assert_eq!(stream.label(&VertexId::from(9)), None);
+}
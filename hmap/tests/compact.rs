@@ -0,0 +1,73 @@
+/*
+    Appellation: compact <module>
+    Contrib: @FL03
+*/
+use rshyper_core::{EdgeId, Error, Result, VertexId, Weight};
+use rshyper_hmap::HyperMap;
+
+#[test]
+fn test_compact() -> Result<()> {
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    let v0 = graph.add_node(Weight(10))?;
+    let v1 = graph.add_node(Weight(11))?;
+    let v2 = graph.add_node(Weight(12))?;
+    let v3 = graph.add_node(Weight(13))?;
+    let e0 = graph.add_edge([v0, v1], Weight(1))?;
+    let e1 = graph.add_edge([v1, v2, v3], Weight(2))?;
+    let e2 = graph.add_edge([v2, v3], Weight(3))?;
+    // removing the first vertex also removes the first edge, leaving gaps in both
+    graph.remove_node(&v0)?;
+    assert!(!graph.contains_edge(&e0));
+    let remap = graph.compact()?;
+    // the remaining elements are renumbered densely, in the order they were created
+    assert_eq!(remap.get_node(&v1), Some(&VertexId::from(0)));
+    assert_eq!(remap.get_node(&v3), Some(&VertexId::from(2)));
+    assert_eq!(remap.get_node(&v0), None);
+    assert_eq!(remap.get_edge(&e1), Some(&EdgeId::from(0)));
+    assert_eq!(remap.get_edge(&e2), Some(&EdgeId::from(1)));
+    assert!(!remap.is_identity());
+    // weights follow their elements while the domains are rewritten
+    assert_eq!(graph.get_node(&VertexId::from(0))?.weight(), &Weight(11));
+    assert_eq!(graph.get_edge_weight(&EdgeId::from(1))?, &Weight(3));
+    let domain = graph.get_domain(&EdgeId::from(0))?;
+    assert!(
+        [0, 1, 2]
+            .iter()
+            .all(|&i| domain.contains(&VertexId::from(i)))
+    );
+    assert_eq!(graph.find_edges_with_node(&VertexId::from(2)).count(), 2);
+    // the history is rewritten, continuing from the new indices
+    assert_eq!(graph.history().nodes().len(), 3);
+    assert_eq!(graph.add_vertex()?, VertexId::from(3));
+    assert_eq!(graph.add_link([VertexId::from(3)])?, EdgeId::from(2));
+    // compacting a dense graph leaves its indices unchanged
+    assert!(graph.compact()?.is_identity());
+    Ok(())
+}
+
+#[test]
+fn test_compact_with_order() -> Result<()> {
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    let v0 = graph.add_node(Weight(0))?;
+    let v1 = graph.add_node(Weight(1))?;
+    let v2 = graph.add_node(Weight(2))?;
+    let v3 = graph.add_node(Weight(3))?;
+    graph.add_link([v2, v3])?;
+    graph.add_link([v3, v1])?;
+    // renumber the vertices in the order they are discovered by a breadth-first traversal
+    let order = graph.bft().search(v2)?;
+    let remap = graph.compact_with(order.clone())?;
+    for (i, v) in order.iter().enumerate() {
+        assert_eq!(remap.get_node(v), Some(&VertexId::from(i)));
+    }
+    // vertices missing from the order are numbered afterwards
+    assert_eq!(remap.get_node(&v0), Some(&VertexId::from(3)));
+    assert_eq!(graph.get_node(&VertexId::from(0))?.weight(), &Weight(2));
+    // unknown vertices are rejected without modifying the graph
+    assert!(matches!(
+        graph.compact_with([VertexId::from(7)]),
+        Err(Error::NodeNotFound)
+    ));
+    assert_eq!(graph.get_node(&VertexId::from(3))?.weight(), &Weight(0));
+    Ok(())
+}
@@ -0,0 +1,77 @@
+/*
+    Appellation: cores <module>
+    Contrib: @FL03
+*/
+use rshyper_core::{EdgeId, VertexId};
+use rshyper_hmap::HyperMap;
+
+/// build an undirected hypergraph from the given edges over vertices `0..order`
+fn from_edges(order: usize, edges: &[&[usize]]) -> HyperMap<usize, usize> {
+    let mut graph = HyperMap::undirected();
+    let v = (0..order)
+        .map(|_| graph.add_vertex().unwrap())
+        .collect::<Vec<_>>();
+    for edge in edges {
+        graph.add_link(edge.iter().map(|&i| v[i])).unwrap();
+    }
+    graph
+}
+
+#[test]
+fn test_core_numbers() {
+    // four triples over the vertices `0..4`, a tail of edges and an isolated vertex
+    let graph = from_edges(
+        8,
+        &[
+            &[0, 1, 2],
+            &[0, 1, 3],
+            &[0, 2, 3],
+            &[1, 2, 3],
+            &[3, 4],
+            &[4, 5, 6],
+        ],
+    );
+    let cores = graph.core_decomposition();
+    let numbers = cores.core_numbers();
+    let expected = [3, 3, 3, 3, 1, 1, 1, 0];
+    for (v, k) in expected.into_iter().enumerate() {
+        assert_eq!(
+            numbers[&VertexId::from(v)],
+            k,
+            "unexpected core number of {v}"
+        );
+    }
+    assert_eq!(cores.degeneracy(), 3);
+    // the k-core retains the triples alongside their vertices
+    let core = cores.k_core(2);
+    assert_eq!(core.order(), 4);
+    assert_eq!(core.size(), 4);
+    assert!((0..4).all(|v| core.contains_node(&VertexId::from(v))));
+    assert!((0..4).all(|e| core.contains_edge(&EdgeId::from(e))));
+    assert_eq!(core, cores.k_core(3));
+    assert!(cores.k_core(4).is_empty());
+    // every vertex belongs to the 0-core
+    assert_eq!(cores.k_core(0).order(), 8);
+}
+
+#[test]
+fn test_km_cores() {
+    // the vertex `4` is only contained by the last edge
+    let graph = from_edges(5, &[&[0, 1, 2], &[0, 1, 3], &[2, 3, 4]]);
+    let cores = graph.core_decomposition();
+    // removing `4` destroys the last edge, unravelling the 2-core entirely
+    assert!(cores.k_core(2).is_empty());
+    assert!(cores.core_numbers().values().all(|k| *k == 1));
+    // while the (2, 2)-core retains the last edge through its remaining members
+    let core = cores.km_core(2, 2);
+    assert_eq!(core.order(), 4);
+    assert_eq!(core.size(), 3);
+    let domain = core.get_edge_domain(&EdgeId::from(2)).unwrap();
+    assert_eq!(domain.len(), 2);
+    assert!(!domain.contains(&VertexId::from(4)));
+    let numbers = cores.km_core_numbers(2);
+    assert_eq!(numbers[&VertexId::from(4)], 1);
+    assert!((0..4).all(|v| numbers[&VertexId::from(v)] == 2));
+    // requiring every member of the edges reproduces the k-core
+    assert!(cores.km_core(2, 3).is_empty());
+}
@@ -0,0 +1,148 @@
+/*
+    Appellation: decompose <module>
+    Contrib: @FL03
+*/
+use rshyper_algo::TreeDecomposition;
+use rshyper_core::idx::VertexId;
+use rshyper_core::{HyperGraph, Result};
+use rshyper_hmap::HyperMap;
+use std::collections::HashSet;
+
+/// build an undirected hypergraph from the given edges over vertices `0..order`
+fn from_edges(order: usize, edges: &[&[usize]]) -> HyperMap<usize, usize> {
+    let mut graph = HyperMap::undirected();
+    let v = (0..order)
+        .map(|_| graph.add_vertex().unwrap())
+        .collect::<Vec<_>>();
+    for edge in edges {
+        graph.add_link(edge.iter().map(|&i| v[i])).unwrap();
+    }
+    graph
+}
+
+/// asserts that the given decomposition is a valid generalized hypertree decomposition of the
+/// hypergraph, i.e. every edge is contained by some bag, the bags containing any vertex form a
+/// connected subtree, and each bag is covered by its edges
+fn assert_valid(graph: &HyperMap<usize, usize>, decomposition: &TreeDecomposition) {
+    let bags = decomposition.bags();
+    // exactly one root, with every other bag preceding its parent
+    assert_eq!(bags.iter().filter(|b| b.parent().is_none()).count(), 1);
+    for (i, bag) in bags.iter().enumerate() {
+        if let Some(p) = bag.parent() {
+            assert!(p > i, "bag {i} does not precede its parent {p}");
+        }
+    }
+    for (id, edge) in graph.edges() {
+        let domain = edge.domain();
+        assert!(
+            bags.iter().any(|b| domain.iter().all(|v| b.contains(v))),
+            "edge {id} is not contained by any bag"
+        );
+    }
+    // the bags containing a vertex are connected if exactly one of them lacks a parent
+    // containing the vertex as well
+    for v in graph.nodes().keys() {
+        let tops = bags
+            .iter()
+            .filter(|b| b.contains(v))
+            .filter(|b| b.parent().is_none_or(|p| !bags[p].contains(v)))
+            .count();
+        assert!(tops <= 1, "the bags containing {v} are disconnected");
+    }
+    for bag in bags {
+        let covered = bag
+            .cover()
+            .iter()
+            .flat_map(|e| graph.get_edge_domain(e).unwrap())
+            .collect::<HashSet<_>>();
+        assert!(bag.vertices().iter().all(|v| covered.contains(v)));
+    }
+}
+
+#[test]
+fn test_tree_decomposition() -> Result<()> {
+    // a cycle of four vertices has a treewidth of two
+    let graph = from_edges(4, &[&[0, 1], &[1, 2], &[2, 3], &[0, 3]]);
+    let decomposition = graph.tree_decomposer().tree_decomposition();
+    assert_valid(&graph, &decomposition);
+    assert_eq!(decomposition.width(), 2);
+    assert_eq!(decomposition.cover_width(), 2);
+    // a path of edges is decomposed into bags of its edges
+    let graph = from_edges(5, &[&[0, 1, 2], &[2, 3], &[3, 4]]);
+    let decomposition = graph.tree_decomposer().tree_decomposition();
+    assert_valid(&graph, &decomposition);
+    assert_eq!(decomposition.width(), 2);
+    assert_eq!(decomposition.cover_width(), 1);
+    // the empty hypergraph has an empty decomposition
+    let graph = from_edges(0, &[]);
+    assert!(graph.tree_decomposer().tree_decomposition().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_tree_decomposition_from_order() -> Result<()> {
+    let graph = from_edges(4, &[&[0, 1], &[1, 2], &[2, 3], &[0, 3]]);
+    let decomposer = graph.tree_decomposer();
+    // eliminating the vertices along the cycle still produces bags of three vertices
+    let order = [0, 1, 2, 3].map(VertexId::from);
+    let decomposition = decomposer.tree_decomposition_from_order(order)?;
+    assert_valid(&graph, &decomposition);
+    assert_eq!(decomposition.width(), 2);
+    // missing vertices are eliminated last
+    let decomposition = decomposer.tree_decomposition_from_order([VertexId::from(2)])?;
+    assert_valid(&graph, &decomposition);
+    // unknown vertices are rejected
+    assert!(
+        decomposer
+            .tree_decomposition_from_order([VertexId::from(7)])
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_generalized_hypertree_decomposition() -> Result<()> {
+    // alpha-acyclic hypergraphs have a generalized hypertree width of one
+    let graph = from_edges(6, &[&[0, 1, 2], &[1, 2, 3], &[2, 3, 4], &[4, 5]]);
+    let decomposer = graph.tree_decomposer();
+    let decomposition = decomposer.generalized_hypertree_decomposition(1).unwrap();
+    assert_valid(&graph, &decomposition);
+    assert_eq!(decomposition.cover_width(), 1);
+    assert_eq!(decomposer.generalized_hypertree_width(), Some(1));
+    // a cycle of six vertices requires bags covered by two edges
+    let graph = from_edges(6, &[&[0, 1], &[1, 2], &[2, 3], &[3, 4], &[4, 5], &[0, 5]]);
+    let decomposer = graph.tree_decomposer();
+    assert!(decomposer.generalized_hypertree_decomposition(1).is_none());
+    let decomposition = decomposer.generalized_hypertree_decomposition(2).unwrap();
+    assert_valid(&graph, &decomposition);
+    assert!(decomposition.cover_width() <= 2);
+    assert_eq!(decomposer.generalized_hypertree_width(), Some(2));
+    // the exact search is skipped for hypergraphs exceeding the limit
+    let decomposer = graph.tree_decomposer().with_exact_limit(4);
+    assert_eq!(decomposer.exact_limit(), 4);
+    assert_eq!(decomposer.generalized_hypertree_width(), None);
+    Ok(())
+}
+
+#[test]
+fn test_exact_generalized_hypertree_width() {
+    // a 3x3 grid of vertices whose edges are its rows and columns; any bag covered by two
+    // edges is too small to separate the grid, giving a generalized hypertree width of three
+    let graph = from_edges(
+        9,
+        &[
+            &[0, 1, 2],
+            &[3, 4, 5],
+            &[6, 7, 8],
+            &[0, 3, 6],
+            &[1, 4, 7],
+            &[2, 5, 8],
+        ],
+    );
+    let decomposer = graph.tree_decomposer();
+    assert_eq!(decomposer.generalized_hypertree_width(), Some(3));
+    let decomposition = decomposer.generalized_hypertree_decomposition(3).unwrap();
+    assert_valid(&graph, &decomposition);
+    assert!(decomposition.cover_width() <= 3);
+    assert!(decomposer.generalized_hypertree_decomposition(2).is_none());
+}
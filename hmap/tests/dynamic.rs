@@ -0,0 +1,92 @@
+/*
+    Appellation: dynamic <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::VertexId;
+use rshyper_core::{Error, HyperGraph, Mode, OrientedHyperGraph, Result, Weight};
+use rshyper_hmap::dynamic::DynamicHyperMap;
+
+/// a type alias for the dynamic graph used throughout the tests
+type Graph = DynamicHyperMap<usize, usize>;
+
+/// collects and sorts the given vertices
+fn sorted<I: IntoIterator<Item = VertexId>>(iter: I) -> Vec<VertexId> {
+    let mut res = iter.into_iter().collect::<Vec<_>>();
+    res.sort();
+    res
+}
+
+#[test]
+fn test_dynamic_mode() -> Result<()> {
+    let mut graph = Graph::directed();
+    let v = [0, 1, 2, 3].map(|i| graph.add_node(Weight(i)).unwrap());
+    let e0 = graph.add_arc([v[0]], [v[1], v[2]], Weight(1))?;
+    let e1 = graph.add_link([v[2], v[3]])?;
+    assert!(graph.is_directed());
+    assert!(graph.is_oriented(&e0) && !graph.is_oriented(&e1));
+    // oriented edges may only be used to move from their tail onto their head
+    assert_eq!(sorted(graph.find_successors(&v[0])), vec![v[1], v[2]]);
+    assert!(graph.find_node_neighbors(&v[1])?.is_empty());
+    assert_eq!(sorted(graph.find_predecessors(&v[2])), vec![v[0], v[3]]);
+    assert_eq!((graph.out_degree(&v[2]), graph.in_degree(&v[2])), (1, 2));
+    // switching the mode retains the orientations
+    graph.set_mode(Mode::Undirected);
+    assert_eq!(graph.find_node_neighbors(&v[1])?.len(), 2);
+    graph.set_mode(Mode::Directed);
+    assert!(graph.find_node_neighbors(&v[1])?.is_empty());
+    // reversing swaps the tail and head of each oriented edge
+    graph.reverse();
+    assert_eq!(sorted(graph.find_successors(&v[1])), vec![v[0]]);
+    // invalid orientations are rejected
+    assert!(matches!(
+        graph.add_arc([], [v[0]], Weight(0)),
+        Err(Error::InvalidOrientation)
+    ));
+    assert!(matches!(
+        graph.set_orientation(&e1, [v[2]], [v[0]]),
+        Err(Error::InvalidOrientation)
+    ));
+    // mutating the domain of an edge drops its orientation
+    graph.set_orientation(&e1, [v[2]], [v[3]])?;
+    graph.get_edge_domain_mut(&e1)?.remove(&v[3]);
+    assert!(!graph.is_oriented(&e1) && graph.is_oriented(&e0));
+    graph.set_orientation(&e1, [v[2]], [v[2]])?;
+    // while editing the weight of an edge retains it
+    *HyperGraph::get_edge_mut(&mut graph, &e1)?.weight_mut() = Weight(7);
+    assert!(graph.is_oriented(&e1));
+    // removals cascade to the orientations
+    graph.remove_node(&v[0])?;
+    assert!(!graph.is_oriented(&e0) && graph.orientations().len() == 1);
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_conversions() -> Result<()> {
+    let mut graph = Graph::undirected();
+    let v = [3, 1, 2, 0].map(|i| graph.add_node(Weight(i)).unwrap());
+    graph.add_link([v[0], v[1], v[2]])?;
+    graph.add_link([v[2], v[3]])?;
+    // orient each edge from the members with the least weight
+    graph.orient_by_key(|_, w| *w.get())?;
+    assert_eq!(graph.mode(), Mode::Directed);
+    assert_eq!(sorted(graph.find_successors(&v[1])), vec![v[0], v[2]]);
+    assert_eq!(sorted(graph.find_successors(&v[3])), vec![v[2]]);
+    assert!(graph.find_node_neighbors(&v[0])?.is_empty());
+    // algorithms respect the current mode
+    assert_eq!(graph.oriented_bft().search(v[3])?.len(), 2);
+    assert!(matches!(
+        graph.oriented_bft().find_path(v[0], v[3]),
+        Err(rshyper_algo::Error::PathNotFound)
+    ));
+    // re-orienting the graph discards the orientations of edges left unoriented
+    graph.orient_by_key(|id, _| *id == v[3])?;
+    assert_eq!(graph.orientations().len(), 1);
+    graph.orient_by_key(|_, _| 0)?;
+    assert!(graph.orientations().is_empty());
+    graph.orient_by_key(|_, w| *w.get())?;
+    // symmetrizing the graph discards the orientations
+    graph.symmetrize();
+    assert_eq!(graph.mode(), Mode::Undirected);
+    assert_eq!(graph.oriented_bft().find_path(v[0], v[3])?.len(), 3);
+    Ok(())
+}
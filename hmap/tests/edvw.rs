@@ -0,0 +1,129 @@
+/*
+    Appellation: edvw <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::{EdgeId, VertexId};
+use rshyper_core::{Error, HyperGraph, Result, Weight};
+use rshyper_hmap::edvw::UnEdvwHyperMap;
+
+/// a type alias for the graph used throughout the tests
+type Graph = UnEdvwHyperMap<usize, f64>;
+
+#[test]
+fn test_edvw_incidences() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = [(); 3].map(|_| graph.add_vertex().unwrap());
+    let e0 = graph.add_edge(
+        [
+            (v[0], Weight(1.0)),
+            (v[1], Weight(2.0)),
+            (v[2], Weight(3.0)),
+        ],
+        Weight(1.0),
+    )?;
+    let e1 = graph.add_link([(v[1], Weight(4.0)), (v[2], Weight(5.0))])?;
+    assert_eq!((graph.order(), graph.size()), (3, 2));
+    // the same vertex carries a different weight within each of its edges
+    assert_eq!(graph.get_incidence_weight(&e0, &v[1])?, &Weight(2.0));
+    assert_eq!(graph.get_incidence_weight(&e1, &v[1])?, &Weight(4.0));
+    assert!(matches!(
+        graph.get_incidence_weight(&e1, &v[0]),
+        Err(Error::NodeNotFound)
+    ));
+    assert!(matches!(
+        graph.get_incidence_weight(&EdgeId::from(9), &v[0]),
+        Err(Error::EdgeNotFound)
+    ));
+    // update the weight of a single incidence
+    let prev = graph.set_incidence_weight(&e0, &v[1], Weight(6.0))?;
+    assert_eq!(prev, Weight(2.0));
+    let edge = graph.get_edge(&e0)?;
+    assert_eq!(edge.domain().len(), 3);
+    assert_eq!(edge.domain().get(&v[1]), Some(&Weight(6.0)));
+    // every weighted vertex must exist within the graph
+    assert!(matches!(
+        graph.add_link([(v[0], Weight(1.0)), (VertexId::from(9), Weight(1.0))]),
+        Err(Error::NodeNotFound)
+    ));
+    assert_eq!(graph.size(), 2);
+    // materializing the graph discards the weights of the incidences
+    let plain = graph.to_hyper_map();
+    assert_eq!((plain.order(), plain.size()), (3, 2));
+    assert_eq!(plain.get_domain(&e1)?.len(), 2);
+    let reweighted = Graph::from_hyper_map(plain, 1.0);
+    assert_eq!(reweighted.get_incidence_weight(&e0, &v[1])?, &Weight(1.0));
+    // the weighted domain is the only record of the members of an edge
+    let domain = HyperGraph::get_edge_domain_mut(&mut graph, &e1)?;
+    domain.insert(v[0], Weight(7.0));
+    assert_eq!(graph.get_incidence_weight(&e1, &v[0])?, &Weight(7.0));
+    assert_eq!(HyperGraph::get_edge_domain(&graph, &e1)?.len(), 3);
+    assert!(graph.find_edges_with_node(&v[0]).any(|id| *id == e1));
+    *HyperGraph::get_edge_weight_mut(&mut graph, &e0)? = Weight(2.0);
+    // removing an edge returns its incidences
+    let removed = graph.remove_edge(&e1)?;
+    assert_eq!(removed.domain().len(), 3);
+    assert_eq!(removed.domain().get(&v[2]), Some(&Weight(5.0)));
+    assert!(graph.get_domain(&e1).is_err());
+    // removing a node removes the edges containing it
+    graph.remove_node(&v[0])?;
+    assert!(graph.edges().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_edvw_dijkstra() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = [(); 4].map(|_| graph.add_vertex().unwrap());
+    graph.add_edge([(v[0], Weight(1.0)), (v[1], Weight(1.0))], Weight(1.0))?;
+    graph.add_edge([(v[1], Weight(1.0)), (v[3], Weight(10.0))], Weight(1.0))?;
+    graph.add_edge(
+        [
+            (v[0], Weight(1.0)),
+            (v[2], Weight(1.0)),
+            (v[3], Weight(0.5)),
+        ],
+        Weight(3.0),
+    )?;
+    // ignoring the incidences, the cheapest route passes through the second vertex
+    let path = graph.dijkstra().find_path(v[0], v[3]).unwrap();
+    assert_eq!(path, vec![v[0], v[1], v[3]]);
+    // honoring the incidences, the heavily weighted entry into the last vertex is avoided
+    let path = graph.dijkstra().find_incidence_path(v[0], v[3]).unwrap();
+    assert_eq!(path, vec![v[0], v[3]]);
+    Ok(())
+}
+
+#[test]
+fn test_edvw_random_walk() -> Result<()> {
+    let mut graph = Graph::new();
+    let v: [VertexId; 3] = [(); 3].map(|_| graph.add_vertex().unwrap());
+    graph.add_edge(
+        [
+            (v[0], Weight(1.0)),
+            (v[1], Weight(2.0)),
+            (v[2], Weight(1.0)),
+        ],
+        Weight(1.0),
+    )?;
+    graph.add_edge([(v[1], Weight(1.0)), (v[2], Weight(3.0))], Weight(1.0))?;
+    let walker = graph.random_walk();
+    // without the incidences, each vertex of an edge is equally likely
+    let uniform = walker.transitions(&v[0]).unwrap();
+    assert!(uniform.iter().all(|(_, p)| (p - 1.0 / 3.0).abs() < 1e-9));
+    // with the incidences, the vertices are chosen in proportion to their weights
+    let weighted = walker.incidence_transitions(&v[1]).unwrap();
+    let expected = [(v[0], 0.125), (v[1], 0.375), (v[2], 0.5)];
+    assert_eq!(weighted.len(), expected.len());
+    for ((a, p), (b, q)) in weighted.iter().zip(expected.iter()) {
+        assert_eq!(a, b);
+        assert!((p - q).abs() < 1e-9);
+    }
+    // a deterministic sampler exposes the difference between the walks
+    let mut rng = || 0.3;
+    assert_eq!(walker.walk(v[0], 2, &mut rng).unwrap(), vec![v[0]; 3]);
+    assert_eq!(
+        walker.incidence_walk(v[0], 2, &mut rng).unwrap(),
+        vec![v[0], v[1], v[1]]
+    );
+    Ok(())
+}
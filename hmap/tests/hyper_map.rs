@@ -159,3 +159,190 @@ fn test_seq_iter() -> Result<()> {
     // finish
     Ok(())
 }
+
+#[test]
+fn test_find_edge_by_domain() -> Result<()> {
+    // initialize a new, undirected hash-graph
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    // add some vertices
+    let v0 = graph.add_vertex()?;
+    let v1 = graph.add_vertex()?;
+    let v2 = graph.add_vertex()?;
+    // create some edges with the vertices
+    let e0 = graph.add_link([v0, v1, v2])?;
+    let e1 = graph.add_link([v1, v2])?;
+    // the order of the vertices is irrelevant
+    assert_eq!(graph.find_edge_by_domain([v2, v0, v1]), Some(&e0));
+    assert_eq!(graph.find_edge_by_domain([v2, v1]), Some(&e1));
+    assert_eq!(graph.find_edge_by_domain([v0, v1]), None);
+    // removing an edge removes it from the index
+    graph.remove_edge(&e1)?;
+    assert_eq!(graph.find_edge_by_domain([v1, v2]), None);
+    // updating the weight of an edge leaves the index untouched
+    graph.set_edge_weight(&e0, Weight(1))?;
+    assert!(graph.domain_index().pending().is_empty());
+    // modifying a domain directly releases the edge from the index
+    graph.get_domain_mut(&e0)?.remove(&v0);
+    assert!(!graph.domain_index().is_stale());
+    assert!(graph.domain_index().pending().contains(&e0));
+    assert_eq!(graph.find_edge_by_domain([v1, v2]), Some(&e0));
+    assert_eq!(graph.find_edge_by_domain([v0, v1, v2]), None);
+    // the next insertion records the current domain of the edge
+    let e2 = graph.add_link([v0, v2])?;
+    assert!(graph.domain_index().pending().is_empty());
+    assert_eq!(graph.find_edge_by_domain([v1, v2]), Some(&e0));
+    assert_eq!(graph.find_edge_by_domain([v2, v0]), Some(&e2));
+    // exposing every edge invalidates the index until the next insertion
+    graph.edges_mut();
+    assert!(graph.domain_index().is_stale());
+    assert_eq!(graph.find_edge_by_domain([v1, v2]), Some(&e0));
+    graph.add_link([v0, v1])?;
+    assert!(!graph.domain_index().is_stale());
+    assert_eq!(graph.find_edge_by_domain([v1, v2]), Some(&e0));
+    // iterating mutably over the edges only exposes their weights
+    for (_, mut edge) in graph.iter_edges_mut() {
+        edge.set_weight(Weight(2));
+    }
+    assert!(graph.domain_index().pending().is_empty());
+    assert!(graph.incidence_index().pending().is_empty());
+    // retaining edges re-records the domains of the edges that were kept
+    graph.retain_edges(|id, edge| {
+        if *id == e0 {
+            edge.domain_mut().insert(v0);
+        }
+        *id != e2
+    });
+    assert!(graph.domain_index().pending().is_empty());
+    assert!(graph.incidence_index().pending().is_empty());
+    assert_eq!(graph.find_edge_by_domain([v0, v1, v2]), Some(&e0));
+    assert_eq!(graph.find_edge_by_domain([v0, v2]), None);
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_add_edge_or_merge() -> Result<()> {
+    // initialize a new, undirected hash-graph
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    // add some vertices
+    let v0 = graph.add_vertex()?;
+    let v1 = graph.add_vertex()?;
+    let v2 = graph.add_vertex()?;
+    // insert an edge before attempting to insert a duplicate
+    let e0 = graph.add_edge([v0, v1], Weight(10))?;
+    let e1 = graph.add_edge_unique([v1, v0], Weight(20))?;
+    assert_eq!(e0, e1);
+    assert_eq!(graph.size(), 1);
+    assert_eq!(graph.get_edge_weight(&e0)?, &10);
+    // merging the weights of duplicate edges
+    let e2 = graph.add_edge_or_merge([v0, v1], Weight(5))?;
+    assert_eq!(e0, e2);
+    assert_eq!(graph.get_edge_weight(&e0)?, &15);
+    let e3 = graph.add_edge_or_merge_with([v1, v0], Weight(2), |a, b| a * b)?;
+    assert_eq!(e0, e3);
+    assert_eq!(graph.get_edge_weight(&e0)?, &30);
+    // edges with new domains are inserted as usual
+    let e4 = graph.add_edge_or_merge([v0, v1, v2], Weight(1))?;
+    assert_ne!(e0, e4);
+    assert_eq!(graph.size(), 2);
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_containment_queries() -> Result<()> {
+    // initialize a new, undirected hash-graph
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    // add some vertices
+    let v0 = graph.add_vertex()?;
+    let v1 = graph.add_vertex()?;
+    let v2 = graph.add_vertex()?;
+    let v3 = graph.add_vertex()?;
+    // create some edges with the vertices
+    let e0 = graph.add_link([v0, v1])?;
+    let e1 = graph.add_link([v0, v1, v2])?;
+    let e2 = graph.add_link([v2, v3])?;
+    let e3 = graph.add_link([v1])?;
+    // edges composed entirely of the given vertices
+    let subsets = graph.find_subset_edges([v0, v1, v2]);
+    assert_eq!(subsets, HashSet::from([e0, e1, e3]));
+    assert!(graph.find_subset_edges([v3]).is_empty());
+    // edges containing all of the given vertices
+    let supersets = graph.find_superset_edges([v0, v1]);
+    assert_eq!(supersets, HashSet::from([e0, e1]));
+    assert_eq!(graph.find_superset_edges([v2]), HashSet::from([e1, e2]));
+    assert!(graph.find_superset_edges([v0, v3]).is_empty());
+    // edges sharing at least `k` vertices with the given vertices
+    let overlap = graph.find_intersecting_edges([v1, v2], 1);
+    assert_eq!(overlap, HashSet::from([e0, e1, e2, e3]));
+    assert_eq!(
+        graph.find_intersecting_edges([v1, v2], 2),
+        HashSet::from([e1])
+    );
+    assert!(graph.find_intersecting_edges([v1, v2], 3).is_empty());
+    // removing an edge removes it from the index
+    graph.remove_edge(&e1)?;
+    assert_eq!(graph.find_superset_edges([v0, v1]), HashSet::from([e0]));
+    // removing a node removes its edges from the index
+    graph.remove_node(&v3)?;
+    assert!(graph.find_superset_edges([v2]).is_empty());
+    // modifying a domain directly releases the edge from the index
+    graph.get_domain_mut(&e0)?.insert(v2);
+    assert!(!graph.incidence_index().is_stale());
+    assert!(graph.incidence_index().pending().contains(&e0));
+    assert_eq!(graph.find_superset_edges([v2]), HashSet::from([e0]));
+    assert_eq!(graph.find_subset_edges([v0, v1]), HashSet::from([e3]));
+    assert_eq!(
+        graph.find_intersecting_edges([v1, v2], 2),
+        HashSet::from([e0])
+    );
+    // the next insertion records the current domain of the edge
+    let e4 = graph.add_link([v0])?;
+    assert!(graph.incidence_index().pending().is_empty());
+    assert_eq!(graph.incidence_index().degree(&v2), 1);
+    assert_eq!(graph.find_superset_edges([v0]), HashSet::from([e0, e4]));
+    assert_eq!(
+        graph.find_subset_edges([v0, v1, v2]),
+        HashSet::from([e0, e3, e4])
+    );
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_index_conversion() -> Result<()> {
+    use rshyper_core::idx::{EdgeId, IndexError, VertexId};
+    use rshyper_hmap::UnHyperMap;
+
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    let v0 = graph.add_node(Weight(10))?;
+    let v1 = graph.add_node(Weight(11))?;
+    let v2 = graph.add_node(Weight(12))?;
+    let e0 = graph.add_edge([v0, v1], Weight(1))?;
+    let e1 = graph.add_edge([v1, v2], Weight(2))?;
+    // narrow the indices of the graph
+    let mut narrow: UnHyperMap<usize, usize, u32> = graph.try_with_index()?;
+    assert_eq!(narrow.order(), 3);
+    assert_eq!(narrow.size(), 2);
+    assert_eq!(narrow.get_node(&VertexId::new(2u32))?.weight(), &Weight(12));
+    assert_eq!(narrow.get_edge_weight(&e1.map(|i| i as u32))?, &Weight(2));
+    assert_eq!(narrow.find_edges_with_node(&VertexId::new(1u32)).count(), 2);
+    // the history and cursor are converted as well
+    assert_eq!(narrow.history().nodes().len(), 3);
+    assert_eq!(narrow.add_vertex()?, VertexId::new(3u32));
+    // indices that overflow the target type are rejected
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    graph.set_position(rshyper_core::idx::IndexFrame::new(
+        EdgeId::default(),
+        VertexId::new(u16::MAX as usize + 1),
+    ));
+    graph.add_node(Weight(0))?;
+    let res = graph.try_with_index::<u16>();
+    assert!(matches!(
+        res,
+        Err(rshyper_core::Error::IndexError(
+            IndexError::IndexOutOfBounds
+        ))
+    ));
+    Ok(())
+}
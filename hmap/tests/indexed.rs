@@ -0,0 +1,131 @@
+/*
+    Appellation: indexed <module>
+    Contrib: @FL03
+*/
+use rshyper_core::{Error, HyperGraph, Result, Weight};
+use rshyper_hmap::indexed::UnIndexedHyperMap;
+
+/// the kinds of nodes used throughout the tests
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Kind {
+    #[default]
+    Gene,
+    Protein,
+}
+
+/// a type alias for the indexed graph used throughout the tests
+type Graph = UnIndexedHyperMap<(Kind, usize), usize>;
+
+#[test]
+fn test_weight_indexes() -> Result<()> {
+    let mut graph = Graph::new();
+    let v0 = graph.add_node(Weight((Kind::Protein, 1)))?;
+    // indexes are built from the existing elements
+    graph.add_node_index("kind", |w: &Weight<(Kind, usize)>| w.0.0);
+    graph.add_edge_index("weight", |w: &Weight<usize>| w.0);
+    let v1 = graph.add_node(Weight((Kind::Gene, 2)))?;
+    let v2 = graph.add_node(Weight((Kind::Protein, 3)))?;
+    let e0 = graph.add_edge([v0, v1], Weight(5))?;
+    let e1 = graph.add_edge([v1, v2], Weight(8))?;
+    let e2 = graph.add_edge([v0, v2], Weight(12))?;
+    // equality lookups
+    let mut proteins = graph.find_nodes_by("kind", &Kind::Protein)?;
+    proteins.sort();
+    assert_eq!(proteins, vec![v0, v2]);
+    // range lookups are ordered by their keys
+    assert_eq!(
+        graph.find_edges_in_range("weight", 5usize..=10)?,
+        vec![e0, e1]
+    );
+    // updating a weight updates the indexes
+    graph.set_edge_weight(&e2, Weight(6))?;
+    assert_eq!(
+        graph.find_edges_in_range("weight", 5usize..=10)?,
+        vec![e0, e2, e1]
+    );
+    graph.set_node_weight(&v1, Weight((Kind::Protein, 2)))?;
+    assert!(graph.find_nodes_by("kind", &Kind::Gene)?.is_empty());
+    // removals update the indexes, including the edges removed alongside a node
+    graph.remove_node(&v0)?;
+    assert_eq!(
+        graph.find_edges_in_range::<usize, _>("weight", ..)?,
+        vec![e1]
+    );
+    assert_eq!(graph.node_index::<Kind>("kind")?.len(), 2);
+    // lookups are validated against the declared indexes
+    assert!(matches!(
+        graph.find_nodes_by("missing", &Kind::Gene),
+        Err(Error::SecondaryIndexNotFound)
+    ));
+    assert!(matches!(
+        graph.find_nodes_by("kind", &0usize),
+        Err(Error::SecondaryIndexKeyMismatch)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_weight_indexes_stale() -> Result<()> {
+    let mut graph = Graph::new();
+    graph.add_edge_index("weight", |w: &Weight<usize>| w.0);
+    let v0 = graph.add_vertex()?;
+    let e0 = graph.add_edge([v0], Weight(1))?;
+    let e1 = graph.add_edge([v0], Weight(5))?;
+    let e2 = graph.add_edge([v0], Weight(20))?;
+    // mutating a weight directly releases only that edge from the indexes
+    *HyperGraph::get_edge_weight_mut(&mut graph, &e0)? = Weight(10);
+    assert!(graph.is_stale());
+    assert_eq!(graph.edge_index::<usize>("weight")?.get_key(&e0), None);
+    assert_eq!(graph.edge_index::<usize>("weight")?.len(), 2);
+    // lookups check the released edge directly, preserving the order of the keys
+    assert_eq!(graph.find_edges_by("weight", &10usize)?, vec![e0]);
+    assert!(graph.find_edges_by("weight", &1usize)?.is_empty());
+    assert_eq!(
+        graph.find_edges_in_range::<usize, _>("weight", ..)?,
+        vec![e1, e0, e2]
+    );
+    // the released edge is re-keyed by the next modification of the graph
+    graph.add_vertex()?;
+    assert!(!graph.is_stale());
+    assert_eq!(graph.edge_index::<usize>("weight")?.get_key(&e0), Some(&10));
+    // rebuilding the indexes also brings them back in sync
+    *HyperGraph::get_edge_weight_mut(&mut graph, &e1)? = Weight(30);
+    graph.reindex();
+    assert!(!graph.is_stale());
+    assert_eq!(graph.edge_index::<usize>("weight")?.get_key(&e1), Some(&30));
+    Ok(())
+}
+
+#[test]
+fn test_weight_indexes_released() -> Result<()> {
+    let mut graph = Graph::new();
+    graph.add_edge_index("weight", |w: &Weight<usize>| w.0);
+    let v0 = graph.add_vertex()?;
+    let e0 = graph.add_edge([v0], Weight(1))?;
+    let e1 = graph.add_edge([v0], Weight(5))?;
+    let e2 = graph.add_edge([v0], Weight(20))?;
+    let e3 = graph.add_edge([v0], Weight(30))?;
+    // release several edges at once, moving them around the remaining keys
+    *HyperGraph::get_edge_weight_mut(&mut graph, &e3)? = Weight(2);
+    *HyperGraph::get_edge_weight_mut(&mut graph, &e0)? = Weight(25);
+    *HyperGraph::get_edge_weight_mut(&mut graph, &e1)? = Weight(10);
+    assert_eq!(graph.edge_index::<usize>("weight")?.len(), 1);
+    // the released edges are merged into the indexed ones by their keys
+    assert_eq!(
+        graph.find_edges_in_range::<usize, _>("weight", ..)?,
+        vec![e3, e1, e2, e0]
+    );
+    assert_eq!(
+        graph.find_edges_in_range::<usize, _>("weight", 2..=20)?,
+        vec![e3, e1, e2]
+    );
+    assert_eq!(graph.find_edges_by("weight", &25usize)?, vec![e0]);
+    // and re-keyed by the next modification of the graph
+    graph.add_vertex()?;
+    assert!(!graph.is_stale());
+    assert_eq!(
+        graph.find_edges_in_range::<usize, _>("weight", ..)?,
+        vec![e3, e1, e2, e0]
+    );
+    Ok(())
+}
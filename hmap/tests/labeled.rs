@@ -0,0 +1,95 @@
+/*
+    Appellation: labeled <module>
+    Contrib: @FL03
+*/
+use rshyper_core::{Error, HyperGraph, Result, Weight};
+use rshyper_hmap::UnHyperMap;
+
+/// a type alias for the labeled graph used throughout the tests
+type Graph = UnHyperMap<usize, usize>;
+
+#[test]
+fn test_labeled_nodes() -> Result<()> {
+    let mut graph = Graph::new();
+    // the labels are only allocated once a vertex is named
+    let anon = graph.add_vertex()?;
+    assert!(graph.labels().is_none());
+    let a = graph.add_named_node("a".to_string(), Weight(1))?;
+    let b = graph.add_named_vertex("b".to_string())?;
+    assert!(graph.is_labeled());
+    // names may be used to look up nodes and create edges
+    assert_eq!(graph.node_by_name("a")?, a);
+    assert_eq!(graph.name_of(&b).map(String::as_str), Some("b"));
+    assert!(graph.name_of(&anon).is_none());
+    let e0 = graph.add_edge_by_names(["a", "b"], Weight(2))?;
+    assert!(graph.get_edge_domain(&e0)?.contains(&a));
+    assert!(matches!(
+        graph.add_edge_by_names(["a", "z"], Weight(0)),
+        Err(Error::NodeNotFound)
+    ));
+    // names are unique
+    assert!(graph.add_named_vertex("a".to_string()).is_err());
+    assert_eq!(graph.intern("a".to_string())?, a);
+    let c = graph.intern("c".to_string())?;
+    assert_eq!(graph.order(), 4);
+    // anonymous nodes may be named afterwards
+    assert!(graph.set_name(&anon, "b".to_string()).is_err());
+    assert_eq!(graph.set_name(&anon, "d".to_string())?, None);
+    assert_eq!(graph.node_by_name("d")?, anon);
+    // removing a node removes its name
+    graph.remove_node(&a)?;
+    assert!(!graph.contains_name("a"));
+    graph.remove_node_by_name("c")?;
+    assert!(!graph.contains_node(&c));
+    assert_eq!(graph.labels().map(|labels| labels.len()), Some(2));
+    assert_eq!(graph.size(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_labeled_compact() -> Result<()> {
+    let mut graph = Graph::new();
+    let a = graph.add_named_vertex("a".to_string())?;
+    let b = graph.add_named_vertex("b".to_string())?;
+    graph.add_edge_by_names(["a", "b"], Weight(1))?;
+    // renumbering the vertices carries their labels along
+    graph.remove_node(&a)?;
+    let remap = graph.compact()?;
+    let b2 = remap.nodes()[&b];
+    assert_ne!(b, b2);
+    assert_eq!(graph.node_by_name("b")?, b2);
+    assert_eq!(graph.name_of(&b2).map(String::as_str), Some("b"));
+    // as does changing the type of the indices
+    let graph = graph.try_with_index::<u32>()?;
+    assert_eq!(graph.node_by_name("b")?, b2.try_cast::<u32>()?);
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_labeled_serde() -> Result<()> {
+    use rshyper_hmap::Interner;
+
+    let mut graph = Graph::new();
+    let a = graph.add_named_vertex("a".to_string())?;
+    let b = graph.add_named_vertex("b".to_string())?;
+    let json = serde_json::to_string(graph.labels().unwrap())?;
+    let labels: Interner<String> = serde_json::from_str(&json)?;
+    assert_eq!(labels.get("a"), Some(&a));
+    assert_eq!(labels.label_of(&b).map(String::as_str), Some("b"));
+    // a vertex may not be named twice, nor may a label be repeated
+    let (va, vb) = (serde_json::to_string(&a)?, serde_json::to_string(&b)?);
+    let json = format!(r#"{{"a":{va},"b":{va}}}"#);
+    assert!(serde_json::from_str::<Interner<String>>(&json).is_err());
+    let json = format!(r#"{{"a":{va},"a":{vb}}}"#);
+    assert!(serde_json::from_str::<Interner<String>>(&json).is_err());
+    // unlabeled graphs omit the labels entirely
+    let json = serde_json::to_string(&Graph::new())?;
+    assert!(!json.contains("labels"));
+    assert!(serde_json::from_str::<Graph>(&json)?.labels().is_none());
+    // while labels naming missing vertices are discarded
+    let json = json.replace(r#""nodes""#, &format!(r#""labels":{{"a":{va}}},"nodes""#));
+    let graph = serde_json::from_str::<Graph>(&json)?;
+    assert!(!graph.contains_name("a"));
+    Ok(())
+}
@@ -0,0 +1,87 @@
+/*
+    Appellation: matching <module>
+    Contrib: @FL03
+*/
+use rshyper_algo::Error;
+use rshyper_core::{EdgeId, Weight};
+use rshyper_hmap::HyperMap;
+
+/// build an undirected hypergraph from the given weighted edges over vertices `0..order`
+fn from_edges(order: usize, edges: &[(&[usize], usize)]) -> HyperMap<usize, usize> {
+    let mut graph = HyperMap::undirected();
+    let v = (0..order)
+        .map(|_| graph.add_vertex().unwrap())
+        .collect::<Vec<_>>();
+    for (edge, weight) in edges {
+        graph
+            .add_edge(edge.iter().map(|&i| v[i]), Weight(*weight))
+            .unwrap();
+    }
+    graph
+}
+
+/// converts the given raw indices into edges
+fn edges(ids: &[usize]) -> Vec<EdgeId> {
+    ids.iter().copied().map(EdgeId::from).collect()
+}
+
+#[test]
+fn test_maximal_matching() {
+    let graph = from_edges(
+        7,
+        &[(&[0, 1, 2], 1), (&[2, 3], 1), (&[3, 4, 5], 1), (&[5, 6], 1)],
+    );
+    let matching = graph.matching();
+    // the smaller edges are selected first, leaving no room for the others
+    let res = matching.maximal();
+    assert_eq!(res, edges(&[1, 3]));
+    assert!(matching.is_matching(&res));
+    assert!(!matching.is_perfect_matching(&res));
+    // overlapping or missing edges are rejected
+    assert!(!matching.is_matching(&edges(&[0, 1])));
+    assert!(!matching.is_matching(&edges(&[7])));
+}
+
+#[test]
+fn test_maximum_weight_matching() {
+    // the heaviest edge blocks both of its neighbors, which together weigh more
+    let graph = from_edges(4, &[(&[0, 1], 3), (&[1, 2], 4), (&[2, 3], 3)]);
+    let matching = graph.matching();
+    let res = matching.maximum_weight();
+    assert_eq!(res, edges(&[0, 2]));
+    assert!(matching.is_perfect_matching(&res));
+    // edges without a positive weight are never selected
+    let graph = from_edges(6, &[(&[0, 1, 2], 0), (&[3, 4, 5], 2), (&[2, 3], 5)]);
+    let res = graph.matching().maximum_weight();
+    assert_eq!(res, edges(&[2]));
+}
+
+#[test]
+fn test_perfect_matching() -> rshyper_algo::Result<()> {
+    // a 3-uniform hypergraph whose perfect matching avoids the first edge
+    let graph = from_edges(
+        6,
+        &[
+            (&[0, 1, 2], 1),
+            (&[0, 1, 3], 1),
+            (&[2, 4, 5], 1),
+            (&[3, 4, 5], 1),
+        ],
+    );
+    let matching = graph.matching();
+    assert_eq!(matching.uniformity(), Some(3));
+    let res = matching.perfect_matching()?.unwrap();
+    assert_eq!(res, edges(&[0, 3]));
+    assert!(matching.is_perfect_matching(&res));
+    // the edges cannot cover a vertex contained by none of them
+    let graph = from_edges(4, &[(&[0, 1], 1), (&[1, 2], 1)]);
+    assert_eq!(graph.matching().perfect_matching()?, None);
+    // perfect matchings are only searched for within uniform hypergraphs
+    let graph = from_edges(5, &[(&[0, 1], 1), (&[2, 3, 4], 1)]);
+    assert_eq!(graph.matching().uniformity(), None);
+    assert!(matches!(
+        graph.matching().perfect_matching(),
+        Err(Error::NotUniform)
+    ));
+    Ok(())
+}
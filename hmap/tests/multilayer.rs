@@ -0,0 +1,105 @@
+/*
+    Appellation: multilayer <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::{EdgeId, LayerId, VertexId};
+use rshyper_core::{Error, Result, Weight};
+use rshyper_hmap::multilayer::UnMultiLayerHyperMap;
+
+/// a type alias for the multilayer graph used throughout the tests
+type Graph = UnMultiLayerHyperMap<usize, usize>;
+/// a type alias for the components initialized by the [`setup`] routine
+type Setup = (Graph, [LayerId; 2], [VertexId; 5], [EdgeId; 3]);
+
+/// initializes a graph with two layers over five shared vertices
+fn setup() -> Result<Setup> {
+    let mut graph = Graph::new();
+    let (l0, l1) = (graph.add_layer(), graph.add_layer());
+    let v = [(); 5].map(|_| graph.add_vertex().unwrap());
+    let e0 = graph.add_edge(l0, [v[0], v[1], v[2]], Weight(1))?;
+    let e1 = graph.add_edge(l1, [v[2], v[3]], Weight(2))?;
+    let e2 = graph.add_edge(l1, [v[3], v[4]], Weight(3))?;
+    Ok((graph, [l0, l1], v, [e0, e1, e2]))
+}
+
+#[test]
+fn test_multilayer_views() -> Result<()> {
+    let (mut graph, [l0, l1], v, [e0, e1, e2]) = setup()?;
+    assert_eq!(graph.layer_count(), 2);
+    assert_eq!(graph.order(), 5);
+    // the aggregate contains the edges of every layer
+    assert_eq!(graph.aggregate().size(), 3);
+    assert_eq!(graph.get_edge_layer(&e1)?, l1);
+    // each view is restricted to the edges of its layer
+    let (first, second) = (graph.layer(l0)?, graph.layer(l1)?);
+    assert_eq!((first.size(), second.size()), (1, 2));
+    assert!(first.contains_edge(&e0) && !first.contains_edge(&e1));
+    assert_eq!(first.get_node_degree(&v[2]), 1);
+    assert_eq!(second.get_node_degree(&v[3]), 2);
+    assert_eq!(second.find_node_neighbors(&v[3])?.len(), 2);
+    let materialized = second.to_hyper_map();
+    assert_eq!((materialized.order(), materialized.size()), (5, 2));
+    // edges may only be added to existing layers
+    assert!(matches!(
+        graph.add_edge(LayerId::new(2), [v[0]], Weight(0)),
+        Err(Error::LayerNotFound)
+    ));
+    // couple the vertex across both layers
+    let c = graph.couple(v[2], [l0, l1])?;
+    assert!(graph.contains_coupling(&c) && !graph.contains_edge(&c));
+    assert_ne!(c, e0);
+    assert!(!graph.aggregate().history().contains_edge(&c));
+    assert_eq!(
+        graph.find_coupled(&l0, &v[2]).collect::<Vec<_>>(),
+        [(l1, v[2])]
+    );
+    // removing a node removes its edges and couplings from every layer
+    graph.remove_node(&v[3])?;
+    assert_eq!(graph.layer(l1)?.size(), 0);
+    assert!(!graph.contains_edge(&e2));
+    graph.remove_node(&v[2])?;
+    assert_eq!(graph.coupling_count(), 0);
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_multilayer_bft() -> Result<()> {
+    let (mut graph, [l0, l1], v, _) = setup()?;
+    // without any couplings, the traversal remains within the starting layer
+    let mut bft = graph.multilayer_bft();
+    let visited = bft.search((l0, v[0])).unwrap();
+    assert_eq!(visited.len(), 3);
+    assert!(visited.iter().all(|(layer, _)| *layer == l0));
+    // couple the shared vertex, allowing the traversal to cross into the second layer
+    graph.couple(v[2], [l0, l1])?;
+    let mut bft = graph.multilayer_bft();
+    let path = bft.find_path((l0, v[0]), (l1, v[4])).unwrap();
+    assert_eq!(
+        path,
+        [(l0, v[0]), (l0, v[2]), (l1, v[2]), (l1, v[3]), (l1, v[4])]
+    );
+    assert_eq!(bft.search((l0, v[0])).unwrap().len(), 6);
+    // restricting the traversal to a single layer prevents crossing over
+    let mut within = graph.multilayer_bft().within([l0]);
+    assert!(within.find_path((l0, v[0]), (l1, v[4])).is_err());
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_multilayer_hasher() -> Result<()> {
+    use std::hash::RandomState;
+    // the layers and couplings share the hasher of the aggregate graph
+    let mut graph = UnMultiLayerHyperMap::<usize, usize, usize, RandomState>::with_layers(2);
+    let (l0, l1) = (LayerId::new(0), LayerId::new(1));
+    let v0 = graph.add_vertex()?;
+    let v1 = graph.add_vertex()?;
+    let e0 = graph.add_link(l0, [v0, v1])?;
+    let c0 = graph.couple(v0, [l0, l1])?;
+    assert!(graph.layer(l0)?.edge_set().contains(&e0));
+    assert!(graph.couplings().contains_key(&c0));
+    assert_eq!(graph.find_coupled(&l0, &v0).collect::<Vec<_>>(), [(l1, v0)]);
+    // finish
+    Ok(())
+}
@@ -0,0 +1,140 @@
+/*
+    Appellation: nested <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::{EdgeId, Grid, VertexId};
+use rshyper_core::{Error, HyperGraph, HyperGraphIterEdge, NestedHyperGraph, Result, Weight};
+use rshyper_hmap::nested::UnNestedHyperMap;
+
+/// a type alias for the nested graph used throughout the tests
+type Graph = UnNestedHyperMap<usize, usize>;
+
+#[test]
+fn test_nested_edges() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = [(); 4].map(|_| graph.add_vertex().unwrap());
+    // a statement about two vertices
+    let e0 = graph.add_edge([v[0].into(), v[1].into()], Weight(1))?;
+    let e1 = graph.add_link([Grid::Vertex(v[2])])?;
+    // a statement about the statements above
+    let e2 = graph.add_link([Grid::Edge(e0), Grid::Edge(e1), Grid::Vertex(v[3])])?;
+    assert_eq!(graph.get_members(&e2)?.len(), 3);
+    assert_eq!(graph.get_edge_domain(&e2)?.len(), 1);
+    assert_eq!(graph.find_edge_parents(&e0).collect::<Vec<_>>(), vec![&e2]);
+    assert!(graph.is_flat(&e0)? && !graph.is_flat(&e2)?);
+    assert_eq!(graph.depth(&e2)?, 1);
+    assert_eq!(graph.flatten(&e2)?.len(), 4);
+    assert!(graph.contains_element(&Grid::Edge(e2)));
+    assert!(!graph.contains_element(&Grid::Raw(0)));
+    // members must exist and edges may not be empty
+    assert!(matches!(
+        graph.add_link([Grid::Edge(EdgeId::from(9))]),
+        Err(Error::EdgeNotFound)
+    ));
+    assert!(matches!(
+        graph.add_link([Grid::Vertex(VertexId::from(9))]),
+        Err(Error::NodeNotFound)
+    ));
+    assert!(matches!(graph.add_link([]), Err(Error::EmptyHyperedge)));
+    // removing an element removes each of the edges containing it
+    let e3 = graph.add_link([Grid::Edge(e2)])?;
+    assert_eq!(graph.depth(&e3)?, 2);
+    graph.remove_node(&v[0])?;
+    assert_eq!(graph.size(), 1);
+    assert!(graph.contains_edge(&e1));
+    assert!(graph.parents().is_empty() && graph.find_edge_parents(&e1).next().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_composite_edges() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = [(); 2].map(|_| graph.add_vertex().unwrap());
+    let e0 = graph.add_link([Grid::Vertex(v[0]), Grid::Vertex(v[1])])?;
+    // edges composed solely of other edges are kept out of the underlying graph
+    let e1 = graph.add_edge([Grid::Edge(e0)], Weight(3))?;
+    assert_ne!(e0, e1);
+    assert_eq!(graph.size(), 2);
+    assert_eq!(graph.graph().size(), 1);
+    assert!(!graph.graph().contains_edge(&e1));
+    // while their indices are still recorded by the history of the graph
+    assert!(graph.graph().history().contains_edge(&e1));
+    assert!(graph.get_edge_domain(&e1)?.is_empty());
+    assert_eq!(graph.edges().count(), 2);
+    assert_eq!(graph.flatten(&e1)?.len(), 2);
+    // their domains may not be modified, while their weights may be
+    assert!(matches!(
+        graph.get_edge_domain_mut(&e1),
+        Err(Error::ImmutableDomain)
+    ));
+    *HyperGraph::get_edge_weight_mut(&mut graph, &e1)? = Weight(4);
+    assert_eq!(HyperGraph::get_edge_weight(&graph, &e1)?, &Weight(4));
+    // new edges never collide with them
+    let e2 = graph.add_link([Grid::Vertex(v[0])])?;
+    assert!(e2 != e0 && e2 != e1);
+    // removing the nested edge removes the composite edge containing it
+    graph.remove_edge(&e0)?;
+    assert!(!graph.contains_edge(&e1) && graph.composites().is_empty());
+    assert!(!graph.graph().history().contains_edge(&e1));
+    assert_eq!(graph.size(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_remove_deeply_nested_edges() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = graph.add_vertex()?;
+    let base = graph.add_link([Grid::Vertex(v)])?;
+    // a chain of edges, each containing the one beneath it
+    let mut top = base;
+    for _ in 0..10_000 {
+        top = graph.add_link([Grid::Edge(top)])?;
+    }
+    assert_eq!(graph.size(), 10_001);
+    // removing the base removes the whole chain without exhausting the stack
+    graph.remove_edge(&base)?;
+    assert!(!graph.contains_edge(&top));
+    assert_eq!(graph.size(), 0);
+    assert!(graph.graph().history().edges().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_depth_of_shared_edges() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = graph.add_vertex()?;
+    // stack diamonds on top of one another such that each level shares the one beneath it
+    let mut top = graph.add_link([Grid::Vertex(v)])?;
+    for _ in 0..64 {
+        let left = graph.add_link([Grid::Edge(top)])?;
+        let right = graph.add_link([Grid::Edge(top)])?;
+        top = graph.add_link([Grid::Edge(left), Grid::Edge(right)])?;
+    }
+    assert_eq!(graph.depth(&top)?, 128);
+    Ok(())
+}
+
+#[test]
+fn test_nested_bft() -> Result<()> {
+    let mut graph = Graph::new();
+    let v: [VertexId; 3] = [(); 3].map(|_| graph.add_vertex().unwrap());
+    let e0 = graph.add_link([Grid::Vertex(v[0]), Grid::Vertex(v[1])])?;
+    let e1 = graph.add_link([Grid::Edge(e0), Grid::Vertex(v[2])])?;
+    let e2 = graph.add_link([Grid::Edge(e1)])?;
+    let mut bft = graph.nested_bft();
+    // every element nested beneath the edge is visited
+    let order = bft.search(e2).unwrap();
+    assert_eq!(order.len(), 6);
+    assert_eq!(order[0], Grid::Edge(e2));
+    assert_eq!(bft.depth_of(&Grid::Vertex(v[0])), Some(3));
+    assert_eq!(
+        bft.find_path(e2, Grid::Vertex(v[1])).unwrap(),
+        vec![e2.into(), e1.into(), e0.into(), v[1].into()]
+    );
+    assert!(bft.find_path(e0, Grid::Vertex(v[2])).is_err());
+    // the descent may be limited to some maximum depth
+    let mut shallow = graph.nested_bft().with_max_depth(2);
+    let order = shallow.search(e2).unwrap();
+    assert!(order.contains(&Grid::Edge(e0)) && !order.contains(&Grid::Vertex(v[0])));
+    Ok(())
+}
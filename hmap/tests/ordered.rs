@@ -0,0 +1,102 @@
+/*
+    Appellation: ordered <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::{EdgeId, VertexId};
+use rshyper_core::{Error, HyperGraph, OrderedHyperGraph, Result, Weight};
+use rshyper_hmap::ordered::UnOrderedHyperMap;
+
+/// a type alias for the ordered graph used throughout the tests
+type Graph = UnOrderedHyperMap<usize, usize>;
+
+#[test]
+fn test_ordered_sequences() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = [(); 4].map(|_| graph.add_vertex().unwrap());
+    // the order and multiplicity of the vertices are preserved
+    let e0 = graph.add_edge([v[2], v[0], v[2], v[1]], Weight(1))?;
+    assert_eq!(graph.get_sequence(&e0)?, &[v[2], v[0], v[2], v[1]]);
+    assert_eq!(graph.get_edge_arity(&e0)?, 4);
+    // while the underlying domain only contains the distinct vertices
+    assert_eq!(graph.get_edge_domain(&e0)?.len(), 3);
+    // the domain is derived from the sequence, so it may not be modified directly
+    assert!(matches!(
+        graph.get_edge_domain_mut(&e0),
+        Err(Error::ImmutableDomain)
+    ));
+    // edges may be treated as tuples
+    assert_eq!(graph.get_member(&e0, 1)?, &v[0]);
+    assert_eq!(graph.get_position(&e0, &v[1])?, 3);
+    assert!(matches!(graph.get_member(&e0, 4), Err(Error::NodeNotFound)));
+    assert!(matches!(
+        graph.get_sequence(&EdgeId::from(7)),
+        Err(Error::EdgeNotFound)
+    ));
+    let edge = graph.get_ordered_edge(&e0)?;
+    assert_eq!(edge.domain(), &vec![v[2], v[0], v[2], v[1]]);
+    // the endpoints of the sequences are indexed
+    let e1 = graph.add_link([v[1], v[3]])?;
+    assert_eq!(graph.find_edges_from(&v[1]).collect::<Vec<_>>(), vec![&e1]);
+    assert_eq!(graph.find_edges_to(&v[1]).collect::<Vec<_>>(), vec![&e0]);
+    // successors follow each occurrence of the vertex
+    let mut succ = graph.find_successors(&v[2]).collect::<Vec<_>>();
+    succ.sort();
+    assert_eq!(succ, vec![v[0], v[1]]);
+    // removals keep the sequences consistent
+    let (_, seq) = graph.remove_edge(&e1)?;
+    assert_eq!(seq, vec![v[1], v[3]]);
+    graph.remove_node(&v[0])?;
+    assert!(graph.sequences().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_ordered_endpoints() -> Result<()> {
+    let mut graph = Graph::new();
+    let v = [(); 4].map(|_| graph.add_vertex().unwrap());
+    let e0 = graph.add_link([v[0], v[1], v[2]])?;
+    let e1 = graph.add_link([v[0], v[3]])?;
+    let e2 = graph.add_link([v[3], v[2], v[1], v[2]])?;
+    let mut from = graph.find_edges_from(&v[0]).collect::<Vec<_>>();
+    from.sort();
+    assert_eq!(from, vec![&e0, &e1]);
+    let mut to = graph.find_edges_to(&v[2]).collect::<Vec<_>>();
+    to.sort();
+    assert_eq!(to, vec![&e0, &e2]);
+    // interior vertices are not endpoints
+    assert_eq!(graph.find_edges_from(&v[1]).count(), 0);
+    // removing an edge drops it from both of its endpoints
+    graph.remove_edge(&e0)?;
+    assert_eq!(graph.find_edges_from(&v[0]).collect::<Vec<_>>(), vec![&e1]);
+    assert_eq!(graph.find_edges_to(&v[2]).collect::<Vec<_>>(), vec![&e2]);
+    // removing a node drops every edge containing it, including where it is interior
+    graph.remove_node(&v[1])?;
+    assert_eq!(graph.find_edges_from(&v[3]).count(), 0);
+    assert_eq!(graph.find_edges_to(&v[2]).count(), 0);
+    assert_eq!(graph.find_edges_to(&v[3]).collect::<Vec<_>>(), vec![&e1]);
+    // the index is carried by clones
+    let clone = graph.clone();
+    assert_eq!(clone.find_edges_from(&v[0]).collect::<Vec<_>>(), vec![&e1]);
+    Ok(())
+}
+
+#[test]
+fn test_ordered_bft() -> Result<()> {
+    let mut graph = Graph::new();
+    let v: [VertexId; 5] = [(); 5].map(|_| graph.add_vertex().unwrap());
+    graph.add_link([v[0], v[1], v[2]])?;
+    graph.add_link([v[3], v[2], v[4]])?;
+    let mut bft = graph.ordered_bft();
+    // the sequences are followed in order, so the third vertex never reaches the fourth
+    let mut order = bft.search(v[0]).unwrap();
+    order.sort();
+    assert_eq!(order, vec![v[0], v[1], v[2], v[4]]);
+    assert_eq!(
+        bft.find_path(v[0], v[4]).unwrap(),
+        vec![v[0], v[1], v[2], v[4]]
+    );
+    assert!(bft.find_path(v[0], v[3]).is_err());
+    // a standard traversal ignores the order of the edges
+    assert!(graph.graph().bft().search(v[0]).unwrap().contains(&v[3]));
+    Ok(())
+}
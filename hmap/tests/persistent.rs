@@ -0,0 +1,97 @@
+/*
+    Appellation: persistent <module>
+    Contrib: @FL03
+*/
+use rshyper_core::{HyperGraph, Result, Weight};
+use rshyper_hmap::HyperMap;
+use rshyper_hmap::persistent::{HamtMap, UnPersistentHyperMap};
+
+#[test]
+fn test_hamt_map() {
+    // initialize a new map
+    let mut map = HamtMap::<usize, usize>::new();
+    for i in 0..1000 {
+        assert!(map.insert(i, i * 2).is_none());
+    }
+    assert_eq!(map.len(), 1000);
+    // take a snapshot of the map before removing some entries
+    let snapshot = map.clone();
+    assert!(snapshot.ptr_eq(&map));
+    for i in (0..1000).step_by(2) {
+        assert_eq!(map.remove(&i), Some(i * 2));
+    }
+    // verify the map was updated while the snapshot was left untouched
+    assert_eq!(map.len(), 500);
+    assert_eq!(snapshot.len(), 1000);
+    assert!(!map.contains_key(&0) && snapshot.contains_key(&0));
+    assert_eq!(map.get(&1), Some(&2));
+    // update a value in-place
+    *map.get_mut(&1).unwrap() = 42;
+    assert_eq!(map.get(&1), Some(&42));
+    assert_eq!(snapshot.get(&1), Some(&2));
+    // verify the iterators visit every entry
+    assert_eq!(map.iter().count(), 500);
+    assert_eq!(
+        snapshot.values().sum::<usize>(),
+        (0..1000).map(|i| i * 2).sum::<usize>()
+    );
+}
+
+#[test]
+fn test_persistent_hyper_map() -> Result<()> {
+    // initialize a new, undirected persistent hypergraph
+    let mut graph = UnPersistentHyperMap::<usize, usize>::new();
+    let v0 = graph.add_node(Weight(10))?;
+    let v1 = graph.add_vertex()?;
+    let v2 = graph.add_vertex()?;
+    let e0 = graph.add_edge([v0, v1], Weight(1))?;
+    // create a new version of the graph with an additional edge
+    let (next, e1) = graph.with_edge([v0, v1, v2], Weight(2))?;
+    assert_eq!(graph.size(), 1);
+    assert_eq!(next.size(), 2);
+    assert!(!graph.contains_edge(&e1) && next.contains_edge(&e1));
+    // removing a node only affects the new version
+    let pruned = next.without_node(&v1)?;
+    assert_eq!(pruned.order(), 2);
+    assert_eq!(pruned.size(), 0);
+    assert_eq!(next.get_node_degree(&v1), 2);
+    // updating a weight leaves every other version untouched
+    let updated = next.with_edge_weight(&e0, Weight(100))?;
+    assert_eq!(updated.get_edge_weight(&e0)?, &Weight(100));
+    assert_eq!(next.get_edge_weight(&e0)?, &Weight(1));
+    // clones are cheap and share their structure
+    let snapshot = updated.snapshot();
+    assert!(snapshot.ptr_eq(&updated));
+    assert_eq!(snapshot, updated);
+    // adding an edge with missing vertices should fail
+    assert!(
+        graph
+            .with_edge([v0, v2.map(|i| i + 10)], Weight(0))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_persistent_hyper_map_conversion() -> Result<()> {
+    // initialize a new, undirected hypergraph
+    let mut graph = HyperMap::<usize, usize>::undirected();
+    let v0 = graph.add_vertex()?;
+    let v1 = graph.add_vertex()?;
+    let v2 = graph.add_vertex()?;
+    let e0 = graph.add_edge([v0, v1, v2], Weight(3))?;
+    // convert the graph into a persistent hypergraph
+    let persistent = UnPersistentHyperMap::from(&graph);
+    assert_eq!(persistent.order(), 3);
+    assert_eq!(persistent.get_domain(&e0)?, graph.get_domain(&e0)?);
+    // the trait methods should behave the same as the inherent methods
+    let mut next = persistent.clone();
+    let v3 = HyperGraph::add_node(&mut next, Weight(0))?;
+    assert!(next.contains_node(&v3) && !persistent.contains_node(&v3));
+    // convert back into a hypermap
+    let restored = next.to_hyper_map();
+    assert_eq!(restored.order(), 4);
+    assert_eq!(restored.history().nodes(), &vec![v0, v1, v2, v3]);
+    assert_eq!(restored.position(), next.position());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+/*
+    Appellation: property <module>
+    Contrib: @FL03
+*/
+use rshyper_core::{Error, Result, Weight};
+use rshyper_hmap::property::{UnPropertyHyperMap, Value, ValueKind};
+
+/// a type alias for the property graph used throughout the tests
+type Graph = UnPropertyHyperMap<usize, usize>;
+
+#[test]
+fn test_property_columns() -> Result<()> {
+    let mut graph = Graph::new();
+    let v0 = graph.add_vertex()?;
+    let v1 = graph.add_vertex()?;
+    let e0 = graph.add_edge([v0, v1], Weight(1))?;
+    // properties of any supported kind may be attached by name
+    graph.set_node_property(&v0, "age", 42i64)?;
+    graph.set_node_property(&v1, "age", 7i64)?;
+    graph.set_node_property(&v0, "name", "alice")?;
+    graph.set_edge_property(&e0, "score", 0.5)?;
+    graph.set_edge_property(&e0, "raw", vec![1u8, 2, 3])?;
+    assert_eq!(graph.get_node_property::<i64>(&v0, "age")?, Some(&42));
+    assert_eq!(graph.get_node_property::<String>(&v1, "name")?, None);
+    assert_eq!(graph.get_edge_property::<f64>(&e0, "score")?, Some(&0.5));
+    // the kind of a column is fixed once it is created
+    assert!(matches!(
+        graph.set_node_property(&v1, "age", true),
+        Err(Error::PropertyKindMismatch)
+    ));
+    assert!(matches!(
+        graph.get_node_property::<bool>(&v0, "age"),
+        Err(Error::PropertyKindMismatch)
+    ));
+    assert!(matches!(
+        graph.get_node_property::<bool>(&v0, "missing"),
+        Err(Error::PropertyNotFound)
+    ));
+    // columns may be iterated in bulk
+    let ages = graph.node_properties().values::<i64>("age")?;
+    assert_eq!(ages.values().sum::<i64>(), 49);
+    assert_eq!(
+        graph.node_properties().column("name")?.kind(),
+        ValueKind::String
+    );
+    assert_eq!(graph.node_properties().row(&v0).count(), 2);
+    assert_eq!(
+        graph.edge_properties().get_value("raw", &e0),
+        Some(Value::Bytes(vec![1, 2, 3]))
+    );
+    // removing an element removes its properties, alongside those of its edges
+    graph.remove_node(&v0)?;
+    assert_eq!(graph.node_properties().column("age")?.len(), 1);
+    assert!(graph.edge_properties().column("score")?.is_empty());
+    assert!(matches!(
+        graph.set_edge_property(&e0, "score", 1.0),
+        Err(Error::EdgeNotFound)
+    ));
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_property_serde() -> Result<()> {
+    use rshyper_hmap::property::NodeProperties;
+
+    let mut graph = Graph::new();
+    let v0 = graph.add_vertex()?;
+    graph.set_node_property(&v0, "age", 42i64)?;
+    graph.set_node_property(&v0, "active", true)?;
+    let json = serde_json::to_string(graph.node_properties())?;
+    let props: NodeProperties<usize> = serde_json::from_str(&json)?;
+    assert_eq!(props.get::<i64>("age", &v0)?, Some(&42));
+    assert_eq!(props.get::<bool>("active", &v0)?, Some(&true));
+    Ok(())
+}
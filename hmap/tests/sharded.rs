@@ -0,0 +1,105 @@
+/*
+    Appellation: sharded <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::{AtomicIndexFrame, IndexError};
+use rshyper_core::{Result, Weight};
+use rshyper_hmap::sharded::UnShardedHyperMap;
+
+#[test]
+fn test_atomic_index_frame() -> Result<()> {
+    // initialize a new allocator using a small index type
+    let frame = AtomicIndexFrame::<u8>::new(0, 254);
+    assert_eq!(*frame.next_edge()?, 0);
+    assert_eq!(*frame.next_node()?, 254);
+    // the largest index is handed out before the allocator is exhausted
+    assert_eq!(*frame.next_node()?, u8::MAX);
+    assert!(matches!(
+        frame.next_node(),
+        Err(IndexError::IndexOutOfBounds)
+    ));
+    // an exhausted position is reported explicitly, while the final position rests on the
+    // last index
+    assert!(frame.is_node_exhausted() && !frame.is_edge_exhausted());
+    assert!(matches!(frame.load(), Err(IndexError::IndexOutOfBounds)));
+    let last = frame.into_frame()?;
+    assert_eq!((**last.edge(), **last.node()), (1, u8::MAX));
+    // the same holds for indices spanning the entire range of positions
+    let frame = AtomicIndexFrame::<usize>::new(usize::MAX, 0);
+    assert_eq!(*frame.next_edge()?, usize::MAX);
+    assert!(matches!(
+        frame.next_edge(),
+        Err(IndexError::IndexOutOfBounds)
+    ));
+    assert!(frame.is_edge_exhausted() && frame.load().is_err());
+    assert_eq!(**frame.into_frame()?.edge(), usize::MAX);
+    // a graph that used up its index space may still be converted
+    let graph = UnShardedHyperMap::<usize, usize, u8>::with_shards(2);
+    for _ in 0..=u8::MAX {
+        graph.add_vertex()?;
+    }
+    assert!(graph.add_vertex().is_err());
+    assert_eq!(graph.into_hyper_map()?.order(), 256);
+    Ok(())
+}
+
+#[test]
+fn test_sharded_hyper_map() -> Result<()> {
+    // initialize a new, undirected sharded hypergraph
+    let graph = UnShardedHyperMap::<usize, usize>::with_shards(4);
+    let v0 = graph.add_node(Weight(1))?;
+    let v1 = graph.add_vertex()?;
+    let v2 = graph.add_vertex()?;
+    let e0 = graph.add_edge([v0, v1], Weight(10))?;
+    let e1 = graph.add_link([v0, v1, v2])?;
+    assert_eq!(graph.order(), 3);
+    assert_eq!(graph.size(), 2);
+    assert_eq!(graph.get_node_weight(&v0)?, Weight(1));
+    assert_eq!(graph.with_edge(&e1, |edge| edge.domain().len())?, 3);
+    // update the weight of an edge
+    assert_eq!(graph.set_edge_weight(&e0, Weight(20))?, Weight(10));
+    assert_eq!(graph.get_edge_weight(&e0)?, Weight(20));
+    // removing a node removes every edge containing it
+    assert_eq!(graph.get_node_degree(&v1), 2);
+    graph.remove_node(&v1)?;
+    assert_eq!(graph.size(), 0);
+    // edges may not reference missing vertices
+    assert!(graph.add_link([v0, v1]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_sharded_hyper_map_concurrent() -> Result<()> {
+    const THREADS: usize = 8;
+    const NODES: usize = 100;
+    // initialize a new, undirected sharded hypergraph
+    let graph = UnShardedHyperMap::<usize, usize>::new();
+    std::thread::scope(|scope| {
+        let handles = (0..THREADS)
+            .map(|t| {
+                let graph = &graph;
+                scope.spawn(move || -> Result<()> {
+                    let mut prev = graph.add_node(Weight(t))?;
+                    for i in 1..NODES {
+                        let next = graph.add_node(Weight(t * NODES + i))?;
+                        graph.add_edge([prev, next], Weight(i))?;
+                        assert!(graph.contains_node(&prev));
+                        prev = next;
+                    }
+                    Ok(())
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .try_for_each(|handle| handle.join().expect("thread panicked"))
+    })?;
+    assert_eq!(graph.order(), THREADS * NODES);
+    assert_eq!(graph.size(), THREADS * (NODES - 1));
+    // convert the graph into a hypermap
+    let hmap = graph.into_hyper_map()?;
+    assert_eq!(hmap.order(), THREADS * NODES);
+    assert_eq!(hmap.size(), THREADS * (NODES - 1));
+    assert_eq!(**hmap.position().node(), THREADS * NODES);
+    Ok(())
+}
@@ -0,0 +1,145 @@
+/*
+    Appellation: simplicial <module>
+    Contrib: @FL03
+*/
+use rshyper_core::{Result, VertexId};
+use rshyper_hmap::simplicial::SimplicialComplex;
+
+/// the minimal (6-vertex) triangulation of the real projective plane
+const RP2: [[usize; 3]; 10] = [
+    [0, 1, 2],
+    [0, 2, 3],
+    [0, 3, 4],
+    [0, 4, 5],
+    [0, 5, 1],
+    [1, 2, 4],
+    [2, 3, 5],
+    [3, 4, 1],
+    [4, 5, 2],
+    [5, 1, 3],
+];
+
+#[test]
+fn test_downward_closure() -> Result<()> {
+    // initialize a new complex
+    let mut complex = SimplicialComplex::<usize>::new();
+    let [v0, v1, v2] = [0, 1, 2].map(VertexId::new);
+    // inserting a triangle inserts its edges and vertices
+    let t = complex.insert_simplex([v2, v0, v1])?;
+    assert_eq!(complex.len(), 7);
+    assert_eq!(complex.dimension(), Some(2));
+    assert_eq!(complex.vertices(), vec![v0, v1, v2]);
+    assert!(complex.contains_simplex([v0, v2]));
+    // inserting an existing simplex returns its id
+    assert_eq!(complex.insert_simplex([v0, v1, v2])?, t);
+    assert_eq!(complex.len(), 7);
+    // verify the faces and cofaces of the simplices
+    let e01 = *complex.find_simplex([v0, v1]).unwrap();
+    let faces = complex.faces(&t)?;
+    assert_eq!(faces.len(), 3);
+    assert!(faces.contains(&e01));
+    assert_eq!(complex.cofaces(&e01)?, vec![t]);
+    assert!(complex.cofaces(&t)?.is_empty());
+    // new vertices never collide with existing ones
+    let v3 = complex.add_vertex()?;
+    assert_eq!(v3, VertexId::new(3));
+    // removing a simplex removes each of its cofaces
+    let e = *complex.find_simplex([v1]).unwrap();
+    let removed = complex.remove_simplex(&e)?;
+    assert_eq!(removed.len(), 4);
+    assert!(!complex.contains(&t));
+    assert!(complex.contains_simplex([v0, v2]));
+    assert_eq!(
+        complex.cofaces(complex.find_simplex([v0]).unwrap())?.len(),
+        1
+    );
+    // the next vertex index must remain representable
+    let mut complex = SimplicialComplex::<u8>::new();
+    assert!(complex.insert_simplex([VertexId::new(u8::MAX)]).is_err());
+    assert!(complex.is_empty());
+    complex.insert_simplex([VertexId::new(u8::MAX - 1)])?;
+    assert_eq!(complex.len(), 1);
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_skeleton() -> Result<()> {
+    // initialize a new complex with a tetrahedron
+    let mut complex = SimplicialComplex::<usize>::new();
+    complex.insert_simplex([0, 1, 2, 3].map(VertexId::new))?;
+    assert_eq!(complex.len(), 15);
+    // the 1-skeleton contains only the vertices and edges
+    let skeleton = complex.skeleton(1);
+    assert_eq!(skeleton.len(), 10);
+    assert_eq!(skeleton.dimension(), Some(1));
+    let e = skeleton.find_simplex([0, 1].map(VertexId::new)).unwrap();
+    assert!(skeleton.cofaces(e)?.is_empty());
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_boundary_matrix() -> Result<()> {
+    // initialize a new complex with a single triangle
+    let mut complex = SimplicialComplex::<usize>::new();
+    complex.insert_simplex([0, 1, 2].map(VertexId::new))?;
+    // the boundary of the triangle is `[1, 2] - [0, 2] + [0, 1]`
+    let d2 = complex.boundary_matrix(2);
+    assert_eq!(d2.shape(), (3, 1));
+    assert_eq!(d2.as_slice(), &[1, -1, 1]);
+    // the composition of two boundary operators vanishes
+    let d1 = complex.boundary_matrix(1);
+    assert_eq!(d1.shape(), (3, 3));
+    for r in 0..3 {
+        let sum = (0..3)
+            .map(|k| d1.get(r, k).unwrap() * d2.get(k, 0).unwrap())
+            .sum::<i64>();
+        assert_eq!(sum, 0);
+    }
+    assert_eq!(d1.rank()?, 2);
+    assert_eq!(complex.boundary_matrix_z2(1).rank(), 2);
+    assert_eq!(complex.boundary_matrix(0).shape(), (0, 3));
+    // finish
+    Ok(())
+}
+
+#[test]
+fn test_betti_numbers() -> Result<()> {
+    // a hollow triangle has a single loop
+    let mut circle = SimplicialComplex::<usize>::new();
+    for [a, b] in [[0, 1], [1, 2], [0, 2]] {
+        circle.insert_simplex([a, b].map(VertexId::new))?;
+    }
+    assert_eq!(circle.betti_numbers(), vec![1, 1]);
+    assert_eq!(circle.betti_number(1), 1);
+    // filling in the triangle removes the loop
+    circle.insert_simplex([0, 1, 2].map(VertexId::new))?;
+    assert_eq!(circle.betti_numbers(), vec![1, 0, 0]);
+    // the boundary of a tetrahedron is a sphere
+    let mut sphere = SimplicialComplex::<usize>::new();
+    for face in [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]] {
+        sphere.insert_simplex(face.map(VertexId::new))?;
+    }
+    assert_eq!(sphere.betti_numbers(), vec![1, 0, 1]);
+    assert_eq!(sphere.rational_betti_numbers()?, vec![1, 0, 1]);
+    assert_eq!(sphere.euler_characteristic(), 2);
+    // the projective plane distinguishes the coefficients due to its torsion
+    let mut rp2 = SimplicialComplex::<usize>::new();
+    for face in RP2 {
+        rp2.insert_simplex(face.map(VertexId::new))?;
+    }
+    assert_eq!(rp2.betti_numbers(), vec![1, 1, 1]);
+    assert_eq!(rp2.rational_betti_numbers()?, vec![1, 0, 0]);
+    assert_eq!(rp2.euler_characteristic(), 1);
+    // the boundary of a 4-simplex is a 3-sphere, eliminated exactly over the rationals
+    let mut sphere3 = SimplicialComplex::<usize>::new();
+    for skip in 0..5 {
+        let face = (0..5).filter(|&v| v != skip).map(VertexId::new);
+        sphere3.insert_simplex(face)?;
+    }
+    assert_eq!(sphere3.boundary_matrix(2).rank()?, 6);
+    assert_eq!(sphere3.rational_betti_numbers()?, vec![1, 0, 0, 1]);
+    // finish
+    Ok(())
+}
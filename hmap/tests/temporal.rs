@@ -0,0 +1,120 @@
+/*
+    Appellation: temporal <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::{EdgeId, VertexId};
+use rshyper_core::{Interval, Result, Weight};
+use rshyper_hmap::temporal::UnTemporalHyperMap;
+
+/// a type alias for the temporal graph used throughout the tests
+type Graph = UnTemporalHyperMap<usize, usize>;
+
+/// initializes a temporal graph with five vertices whose edges are active at various times
+fn setup() -> Result<(Graph, [VertexId; 5], [EdgeId; 5])> {
+    let mut graph = Graph::new();
+    let v = [(); 5].map(|_| graph.add_vertex().unwrap());
+    let e0 = graph.add_edge_at([v[0], v[1]], Weight(1), 1)?;
+    let e1 = graph.add_edge_at([v[1], v[2]], Weight(1), 3)?;
+    let e2 = graph.add_edge_at([v[2], v[3]], Weight(1), 2)?;
+    let e3 = graph.add_edge_during([v[3], v[4]], Weight(1), Interval::new(4, 6))?;
+    let e4 = graph.add_edge_at([v[1], v[3]], Weight(1), 5)?;
+    Ok((graph, v, [e0, e1, e2, e3, e4]))
+}
+
+#[test]
+fn test_temporal_views() -> Result<()> {
+    let (mut graph, v, [e0, e1, e2, e3, e4]) = setup()?;
+    assert_eq!(graph.span(), Some(Interval::new(1, 6)));
+    assert_eq!(graph.get_edge_interval(&e3)?, &Interval::new(4, 6));
+    // a snapshot only contains the edges active at the given instant
+    let snapshot = graph.snapshot(5);
+    assert_eq!(snapshot.size(), 2);
+    assert!(snapshot.contains_edge(&e3) && snapshot.contains_edge(&e4));
+    assert!(!snapshot.contains_edge(&e0));
+    assert_eq!(
+        snapshot.find_edges_with_node(&v[4]).collect::<Vec<_>>(),
+        [&e3]
+    );
+    // a window contains every edge active at some point within it
+    let window = graph.window(1, 2);
+    assert_eq!(window.size(), 2);
+    assert!(window.contains_edge(&e0) && window.contains_edge(&e2));
+    // materialize the snapshot as a standalone graph
+    let materialized = snapshot.to_hyper_map();
+    assert_eq!(materialized.order(), 5);
+    assert_eq!(materialized.size(), 2);
+    assert!(materialized.contains_edge(&e3));
+    // slide a half-open window of unit width across the activity of the graph
+    let windows = graph
+        .sliding_windows(1, 2)
+        .map(|view| (*view.window(), view.size()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        windows,
+        [
+            (Interval::new(1, 2), 1),
+            (Interval::new(3, 4), 1),
+            (Interval::new(5, 6), 2),
+        ]
+    );
+    // adjacent windows share their boundary, so an event upon it is only counted once
+    let windows = graph.sliding_windows(2, 2).collect::<Vec<_>>();
+    assert!(windows.iter().all(|view| view.is_half_open()));
+    assert_eq!(
+        windows.iter().map(|view| view.size()).collect::<Vec<_>>(),
+        [2, 2, 2]
+    );
+    assert_eq!(
+        windows
+            .iter()
+            .filter(|view| view.contains_edge(&e1))
+            .count(),
+        1
+    );
+    assert!(windows[1].contains_edge(&e1) && !windows[0].contains_edge(&e1));
+    // the windows end before their bounds overflow
+    assert_eq!(graph.sliding_windows(u64::MAX, 1).count(), 0);
+    assert_eq!(graph.sliding_windows(1, u64::MAX).count(), 1);
+    // removing a node removes the intervals of its edges
+    graph.remove_node(&v[3])?;
+    assert_eq!(graph.intervals().len(), 2);
+    assert!(graph.get_edge_interval(&e3).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_temporal_dijkstra() -> Result<()> {
+    let (graph, v, _) = setup()?;
+    // the edge between `v2` and `v3` occurs before `v2` may be reached
+    let mut dijkstra = graph.temporal_dijkstra();
+    let path = dijkstra.find_path(v[0], v[4])?;
+    assert_eq!(path, vec![v[0], v[1], v[3], v[4]]);
+    assert_eq!(dijkstra.arrival_time(&v[4]), Some(&5));
+    // departing after the first edge leaves the source stranded
+    let mut late = graph.temporal_dijkstra().depart_at(2);
+    assert!(late.find_path(v[0], v[4]).is_err());
+    // search for every vertex reachable from `v3`
+    let mut dijkstra = graph.temporal_dijkstra();
+    let reached = dijkstra.search(v[3])?;
+    assert_eq!(reached, vec![v[3], v[2], v[1], v[4]]);
+    assert_eq!(dijkstra.arrival_time(&v[1]), Some(&3));
+    assert_eq!(dijkstra.arrival_time(&v[0]), None);
+    Ok(())
+}
+
+#[test]
+fn test_temporal_bft() -> Result<()> {
+    let (graph, v, _) = setup()?;
+    let mut bft = graph.temporal_bft();
+    let path = bft.search(v[0])?;
+    assert_eq!(path.len(), 5);
+    assert_eq!(path[..2], [v[0], v[1]]);
+    assert_eq!(path[4], v[4]);
+    assert_eq!(bft.arrivals()[&v[3]], 5);
+    // the traversal only follows time-respecting paths
+    let mut bft = graph.temporal_bft();
+    let path = bft.search(v[2])?;
+    assert!(!path.contains(&v[0]));
+    assert!(path.contains(&v[4]));
+    Ok(())
+}
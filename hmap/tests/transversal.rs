@@ -0,0 +1,130 @@
+/*
+    Appellation: transversal <module>
+    Contrib: @FL03
+*/
+use rshyper_core::idx::VertexId;
+use rshyper_hmap::HyperMap;
+
+/// build an undirected hypergraph from the given edges over vertices `0..order`
+fn from_edges(order: usize, edges: &[&[usize]]) -> HyperMap<usize, usize> {
+    let mut graph = HyperMap::undirected();
+    let v = (0..order)
+        .map(|_| graph.add_vertex().unwrap())
+        .collect::<Vec<_>>();
+    for edge in edges {
+        graph.add_link(edge.iter().map(|&i| v[i])).unwrap();
+    }
+    graph
+}
+
+/// converts the given lists of raw indices into lists of vertices
+fn vertices(sets: &[&[usize]]) -> Vec<Vec<VertexId>> {
+    sets.iter()
+        .map(|s| s.iter().copied().map(VertexId::from).collect())
+        .collect()
+}
+
+#[test]
+fn test_minimal_transversals() {
+    // the transversals of a triangle are its pairs of vertices
+    let graph = from_edges(3, &[&[0, 1], &[1, 2], &[0, 2]]);
+    let expected = vertices(&[&[0, 1], &[0, 2], &[1, 2]]);
+    let transversals = graph.transversals();
+    assert_eq!(transversals.berge(), expected);
+    let mut lazy = transversals.iter().collect::<Vec<_>>();
+    lazy.sort();
+    assert_eq!(lazy, expected);
+    // a shared vertex hits every edge on its own
+    let graph = from_edges(5, &[&[0, 1, 2], &[0, 3], &[0, 4], &[1, 3, 4]]);
+    let expected = vertices(&[&[0, 1], &[0, 3], &[0, 4], &[1, 3, 4], &[2, 3, 4]]);
+    let transversals = graph.transversals();
+    assert_eq!(transversals.berge(), expected);
+    let mut lazy = transversals.iter().collect::<Vec<_>>();
+    lazy.sort();
+    assert_eq!(lazy, expected);
+}
+
+#[test]
+fn test_minimal_transversals_agree() {
+    // the lazy enumeration agrees with Berge's algorithm
+    let graph = from_edges(
+        8,
+        &[
+            &[0, 1],
+            &[2, 3],
+            &[4, 5],
+            &[0, 2, 4, 6],
+            &[1, 7],
+            &[3, 5, 7],
+        ],
+    );
+    let transversals = graph.transversals();
+    let berge = transversals.berge();
+    let mut lazy = transversals.iter().collect::<Vec<_>>();
+    lazy.sort();
+    assert_eq!(lazy, berge);
+    // the iterator is lazy and may be consumed partially
+    assert_eq!(transversals.iter().take(2).count(), 2);
+    // every transversal hits each edge and no vertex may be dropped
+    for t in &berge {
+        for (_, edge) in graph.edges() {
+            assert!(edge.domain().iter().any(|v| t.contains(v)));
+        }
+        for skip in 0..t.len() {
+            let rest = t
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != skip)
+                .map(|(_, v)| v)
+                .collect::<Vec<_>>();
+            assert!(
+                graph
+                    .edges()
+                    .values()
+                    .any(|e| e.domain().iter().all(|v| !rest.contains(&v)))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transversal_edge_cases() {
+    // a hypergraph without edges is hit by the empty set
+    let graph = from_edges(2, &[]);
+    let transversals = graph.transversals();
+    assert_eq!(transversals.berge(), vec![Vec::<VertexId>::new()]);
+    assert_eq!(
+        transversals.iter().collect::<Vec<_>>(),
+        vec![Vec::<VertexId>::new()]
+    );
+    assert_eq!(transversals.minimum_hitting_set(), Some(Vec::new()));
+    assert_eq!(transversals.greedy_hitting_set(), Some(Vec::new()));
+}
+
+#[test]
+fn test_hitting_sets() {
+    // greedily choosing the vertex of largest degree requires three vertices, while two suffice
+    let graph = from_edges(
+        9,
+        &[
+            &[0, 2, 6],
+            &[0, 3, 6],
+            &[0, 7],
+            &[1, 4, 6],
+            &[1, 5, 6],
+            &[1, 8],
+        ],
+    );
+    let transversals = graph.transversals();
+    let greedy = transversals.greedy_hitting_set().unwrap();
+    let minimum = transversals.minimum_hitting_set().unwrap();
+    assert_eq!(greedy.len(), 3);
+    assert_eq!(minimum, vec![VertexId::from(0), VertexId::from(1)]);
+    for (_, edge) in graph.edges() {
+        assert!(edge.domain().iter().any(|v| minimum.contains(v)));
+        assert!(edge.domain().iter().any(|v| greedy.contains(v)));
+    }
+    // the minimum is the smallest of the minimal transversals
+    let smallest = transversals.iter().map(|t| t.len()).min();
+    assert_eq!(smallest, Some(minimum.len()));
+}